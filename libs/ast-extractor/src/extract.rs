@@ -11,8 +11,58 @@ use proc_macro2::TokenStream;
 use solc_ast_rs_types::types::SourceUnit;
 use std::str::FromStr;
 
+/// Rewrites single-quoted string literals with double quotes, which are the only ones the Rust
+/// lexer accepts. The content keeps the same length so that spans still match the source.
+fn normalize_string_quotes(content: &str) -> String {
+    let mut normalized = String::with_capacity(content.len());
+    let mut chars = content.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '/' if chars.peek() == Some(&'/') => {
+                normalized.push(c);
+                while let Some(&next) = chars.peek() {
+                    if next == '\n' {
+                        break;
+                    }
+                    normalized.push(next);
+                    chars.next();
+                }
+            }
+            '/' if chars.peek() == Some(&'*') => {
+                normalized.push(c);
+                normalized.push(chars.next().unwrap());
+                let mut previous = ' ';
+                for next in chars.by_ref() {
+                    normalized.push(next);
+                    if previous == '*' && next == '/' {
+                        break;
+                    }
+                    previous = next;
+                }
+            }
+            '"' | '\'' => {
+                normalized.push('"');
+                let mut escaped = false;
+                for next in chars.by_ref() {
+                    if next == c && !escaped {
+                        break;
+                    }
+                    escaped = next == '\\' && !escaped;
+                    // A double quote in a single-quoted string would end the rewritten literal
+                    normalized.push(if c == '\'' && next == '"' { '\'' } else { next });
+                }
+                normalized.push('"');
+            }
+            _ => normalized.push(c),
+        }
+    }
+    normalized
+}
+
 pub fn extract_ast_from_content(content: &str) -> Result<syn_solidity::File, ExtractError> {
-    let tokens = TokenStream::from_str(content).map_err(|e| ExtractError::Alloy(e.to_string()))?;
+    let tokens = TokenStream::from_str(&normalize_string_quotes(content))
+        .map_err(|e| ExtractError::Alloy(e.to_string()))?;
     let ast = syn_solidity::parse2(tokens).map_err(|e| ExtractError::Alloy(e.to_string()))?;
     Ok(ast)
 }
@@ -64,6 +114,7 @@ mod tests {
     use super::*;
     use std::fs;
     use std::path::PathBuf;
+    use syn_solidity::Spanned;
 
     #[test]
     fn test_extract_ast_from_content_good() {
@@ -97,4 +148,57 @@ mod tests {
             "Alloy extraction error: expected `;`"
         );
     }
+
+    #[test]
+    fn test_normalize_string_quotes() {
+        let source = "string s = 'a\\'b\"c'; // it's\n/* 'x' */ string t = \"d'e\";";
+        let normalized = normalize_string_quotes(source);
+        assert_eq!(
+            normalized,
+            "string s = \"a\\'b'c\"; // it's\n/* 'x' */ string t = \"d'e\";"
+        );
+        assert_eq!(normalized.len(), source.len());
+    }
+
+    #[test]
+    fn test_extract_ast_from_content_single_quotes() {
+        let source = "contract Test { function f() public { require(true, 'reason'); } }";
+        assert!(extract_ast_from_content(source).is_ok());
+    }
+
+    #[test]
+    fn test_normalize_string_quotes_prefixed_literals() {
+        let source = "bytes b = hex'00ff'; string s = unicode'h\u{e9}llo';";
+        let normalized = normalize_string_quotes(source);
+        assert_eq!(
+            normalized,
+            "bytes b = hex\"00ff\"; string s = unicode\"h\u{e9}llo\";"
+        );
+        assert_eq!(normalized.len(), source.len());
+    }
+
+    #[test]
+    fn test_extract_ast_from_content_prefixed_single_quotes() {
+        let source = "contract Test { bytes b = hex'00ff'; string s = unicode'h\u{e9}llo'; }";
+        let file = extract_ast_from_content(source).unwrap();
+        let syn_solidity::Item::Contract(contract) = &file.items[0] else {
+            panic!("Expected a contract");
+        };
+        let syn_solidity::Item::Variable(var) = &contract.body[1] else {
+            panic!("Expected a state variable");
+        };
+        // The spans following a rewritten literal still match the source
+        assert_eq!(var.name.span().start().column, source.find("s =").unwrap());
+    }
+
+    #[test]
+    fn test_extract_ast_from_content_quotes_in_single_quotes() {
+        // Double quotes are turned into single quotes, escaped single quotes are kept
+        let source = "contract Test { string a = 'say \"hi\"'; string b = 'it\\'s'; }";
+        assert_eq!(
+            normalize_string_quotes(source),
+            "contract Test { string a = \"say 'hi'\"; string b = \"it\\'s\"; }"
+        );
+        assert!(extract_ast_from_content(source).is_ok());
+    }
 }
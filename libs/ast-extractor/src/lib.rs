@@ -1,5 +1,6 @@
 pub mod errors;
 pub mod extract;
+pub mod natspec;
 pub mod retriever;
 pub mod types;
 
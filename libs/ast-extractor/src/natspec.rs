@@ -0,0 +1,395 @@
+/**
+ * natspec.rs
+ * Extract doc comments and their NatSpec tags from solidity source code
+ */
+use proc_macro2::LineColumn;
+
+/// A line of a doc comment, without the comment markers.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DocLine {
+    pub text: String,
+    /// Location of the first character of `text`.
+    pub start: LineColumn,
+}
+
+/// A `/** */` block or a group of consecutive `///` lines.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DocComment {
+    pub lines: Vec<DocLine>,
+    pub start: LineColumn,
+    pub end: LineColumn,
+    /// Location of the first token following the comment, the item it documents.
+    pub next_token: Option<LineColumn>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NatSpecKind {
+    Title,
+    Author,
+    Notice,
+    Dev,
+    Param,
+    Return,
+    Inheritdoc,
+    Custom(String),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NatSpecTag {
+    pub kind: NatSpecKind,
+    /// The documented parameter of `@param` tags.
+    pub name: Option<String>,
+    pub description: String,
+    /// Location of the tag, from the `@` to the end of its name for `@param` tags.
+    pub start: LineColumn,
+    pub end: LineColumn,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct NatSpec {
+    pub tags: Vec<NatSpecTag>,
+}
+
+impl NatSpec {
+    pub fn has(&self, kind: &NatSpecKind) -> bool {
+        self.tags.iter().any(|tag| tag.kind == *kind)
+    }
+
+    pub fn tags_of<'a>(&'a self, kind: &'a NatSpecKind) -> impl Iterator<Item = &'a NatSpecTag> {
+        self.tags.iter().filter(move |tag| tag.kind == *kind)
+    }
+}
+
+// Walks the source by character, tracking the location the same way as proc_macro2
+struct Cursor<'a> {
+    chars: std::iter::Peekable<std::str::Chars<'a>>,
+    location: LineColumn,
+}
+
+impl Cursor<'_> {
+    fn next(&mut self) -> Option<char> {
+        let c = self.chars.next()?;
+        if c == '\n' {
+            self.location.line += 1;
+            self.location.column = 0;
+        } else {
+            self.location.column += 1;
+        }
+        Some(c)
+    }
+
+    fn peek(&mut self) -> Option<char> {
+        self.chars.peek().copied()
+    }
+
+    fn peek_second(&self) -> Option<char> {
+        self.chars.clone().nth(1)
+    }
+
+    // Consumes the rest of the line, without the line break
+    fn rest_of_line(&mut self) -> String {
+        let mut text = String::new();
+        while let Some(c) = self.peek() {
+            if c == '\n' {
+                break;
+            }
+            text.push(c);
+            self.next();
+        }
+        text
+    }
+}
+
+fn doc_line(text: &str, start: LineColumn) -> DocLine {
+    let trimmed = text.trim_start();
+    let skipped = text.chars().count() - trimmed.chars().count();
+    DocLine {
+        text: trimmed.trim_end().to_string(),
+        start: LineColumn {
+            line: start.line,
+            column: start.column + skipped,
+        },
+    }
+}
+
+// Splits the content of a `/** */` block in lines, without the leading `*` of each line
+fn block_lines(text: &str, start: LineColumn) -> Vec<DocLine> {
+    text.split('\n')
+        .enumerate()
+        .map(|(index, line)| {
+            let mut column = if index == 0 { start.column } else { 0 };
+            let mut line = line;
+            if index > 0 {
+                if let Some(rest) = line.trim_start().strip_prefix('*') {
+                    column += line.chars().count() - rest.chars().count();
+                    line = rest;
+                }
+            }
+            doc_line(
+                line,
+                LineColumn {
+                    line: start.line + index,
+                    column,
+                },
+            )
+        })
+        .filter(|line| !line.text.is_empty())
+        .collect()
+}
+
+/// Extracts the doc comments of a source, the `TokenStream` used to parse it drops the comments.
+pub fn extract_doc_comments(content: &str) -> Vec<DocComment> {
+    let mut comments: Vec<DocComment> = Vec::new();
+    let mut cursor = Cursor {
+        chars: content.chars().peekable(),
+        location: LineColumn { line: 1, column: 0 },
+    };
+    // Comments waiting for the next token
+    let mut pending = 0;
+    // Whether the last `///` comment can be continued by the next one
+    let mut open_line_comment = false;
+    let mut line_breaks = 0;
+
+    while let Some(c) = cursor.peek() {
+        let start = cursor.location;
+        match (c, cursor.peek_second()) {
+            ('/', Some('/')) => {
+                cursor.next();
+                cursor.next();
+                let is_doc = cursor.peek() == Some('/') && cursor.peek_second() != Some('/');
+                if !is_doc {
+                    cursor.rest_of_line();
+                    open_line_comment = false;
+                    continue;
+                }
+                cursor.next();
+                let text_start = cursor.location;
+                let line = doc_line(&cursor.rest_of_line(), text_start);
+                line_breaks = 0;
+                let lines: Vec<DocLine> = Some(line)
+                    .filter(|line| !line.text.is_empty())
+                    .into_iter()
+                    .collect();
+                match comments.last_mut() {
+                    Some(comment) if open_line_comment => {
+                        comment.lines.extend(lines);
+                        comment.end = cursor.location;
+                    }
+                    _ => {
+                        comments.push(DocComment {
+                            lines,
+                            start,
+                            end: cursor.location,
+                            next_token: None,
+                        });
+                        pending += 1;
+                        open_line_comment = true;
+                    }
+                }
+            }
+            ('/', Some('*')) => {
+                cursor.next();
+                cursor.next();
+                let is_doc = cursor.peek() == Some('*') && cursor.peek_second() != Some('/');
+                if is_doc {
+                    cursor.next();
+                }
+                let text_start = cursor.location;
+                let mut text = String::new();
+                while let Some(c) = cursor.next() {
+                    if c == '*' && cursor.peek() == Some('/') {
+                        cursor.next();
+                        break;
+                    }
+                    text.push(c);
+                }
+                open_line_comment = false;
+                if is_doc {
+                    comments.push(DocComment {
+                        lines: block_lines(&text, text_start),
+                        start,
+                        end: cursor.location,
+                        next_token: None,
+                    });
+                    pending += 1;
+                }
+            }
+            (c, _) if c.is_whitespace() => {
+                // A blank line ends a group of `///` lines
+                if c == '\n' {
+                    line_breaks += 1;
+                    open_line_comment &= line_breaks < 2;
+                }
+                cursor.next();
+            }
+            _ => {
+                let len = comments.len();
+                for comment in &mut comments[len - pending..] {
+                    comment.next_token = Some(start);
+                }
+                pending = 0;
+                open_line_comment = false;
+                if c == '"' || c == '\'' {
+                    cursor.next();
+                    let mut escaped = false;
+                    while let Some(next) = cursor.next() {
+                        if next == c && !escaped {
+                            break;
+                        }
+                        escaped = next == '\\' && !escaped;
+                    }
+                } else {
+                    cursor.next();
+                }
+            }
+        }
+    }
+    comments
+}
+
+/// Retrieves the doc comment of an item from the location of its first token, when several
+/// comments precede the item the closest one is used.
+pub fn retrieve_doc_comment(comments: &[DocComment], location: LineColumn) -> Option<&DocComment> {
+    comments
+        .iter()
+        .rev()
+        .find(|comment| comment.next_token == Some(location))
+}
+
+fn parse_kind(tag: &str) -> NatSpecKind {
+    match tag {
+        "title" => NatSpecKind::Title,
+        "author" => NatSpecKind::Author,
+        "notice" => NatSpecKind::Notice,
+        "dev" => NatSpecKind::Dev,
+        "param" => NatSpecKind::Param,
+        "return" => NatSpecKind::Return,
+        "inheritdoc" => NatSpecKind::Inheritdoc,
+        _ => NatSpecKind::Custom(tag.to_string()),
+    }
+}
+
+/// Parses the tags of a doc comment. Text before the first tag is an implicit `@notice`, and
+/// lines without tag continue the description of the previous one.
+pub fn parse_natspec(comment: &DocComment) -> NatSpec {
+    let mut natspec = NatSpec::default();
+
+    for line in &comment.lines {
+        let Some(tagged) = line.text.strip_prefix('@') else {
+            match natspec.tags.last_mut() {
+                Some(tag) => {
+                    if !tag.description.is_empty() {
+                        tag.description.push(' ');
+                    }
+                    tag.description.push_str(&line.text);
+                }
+                None => natspec.tags.push(NatSpecTag {
+                    kind: NatSpecKind::Notice,
+                    name: None,
+                    description: line.text.clone(),
+                    start: line.start,
+                    end: line.start,
+                }),
+            }
+            continue;
+        };
+
+        let tag_len = tagged.find(char::is_whitespace).unwrap_or(tagged.len());
+        let kind = parse_kind(&tagged[..tag_len]);
+        let mut rest = tagged[tag_len..].trim_start();
+        let mut end = LineColumn {
+            line: line.start.line,
+            column: line.start.column + 1 + tagged[..tag_len].chars().count(),
+        };
+        let mut name = None;
+
+        if kind == NatSpecKind::Param {
+            let name_len = rest.find(char::is_whitespace).unwrap_or(rest.len());
+            if name_len > 0 {
+                let offset = line.text.chars().count() - rest.chars().count();
+                name = Some(rest[..name_len].to_string());
+                end.column = line.start.column + offset + rest[..name_len].chars().count();
+                rest = rest[name_len..].trim_start();
+            }
+        }
+        natspec.tags.push(NatSpecTag {
+            kind,
+            name,
+            description: rest.to_string(),
+            start: line.start,
+            end,
+        });
+    }
+    natspec
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn location(line: usize, column: usize) -> LineColumn {
+        LineColumn { line, column }
+    }
+
+    #[test]
+    fn test_extract_line_comments_are_grouped() {
+        let source = "/// @title Token\n/// @author Osmium\ncontract Token {}\n";
+        let comments = extract_doc_comments(source);
+
+        assert_eq!(comments.len(), 1);
+        assert_eq!(comments[0].lines.len(), 2);
+        assert_eq!(comments[0].lines[1].text, "@author Osmium");
+        assert_eq!(comments[0].lines[1].start, location(2, 4));
+        assert_eq!(comments[0].next_token, Some(location(3, 0)));
+    }
+
+    #[test]
+    fn test_extract_block_comment() {
+        let source = "contract Token {\n    /**\n     * @notice Mint tokens\n     * @param to The receiver\n     */\n    function mint(address to) public {}\n}\n";
+        let comments = extract_doc_comments(source);
+
+        assert_eq!(comments.len(), 1);
+        let texts: Vec<&str> = comments[0].lines.iter().map(|l| l.text.as_str()).collect();
+        assert_eq!(texts, vec!["@notice Mint tokens", "@param to The receiver"]);
+        assert_eq!(comments[0].lines[0].start, location(3, 7));
+        assert_eq!(comments[0].next_token, Some(location(6, 4)));
+    }
+
+    #[test]
+    fn test_extract_ignores_plain_comments_and_strings() {
+        let source = "// @notice plain\n/* @notice block */\n//// separator\nstring constant s = \"/// not a comment\";\n";
+        assert!(extract_doc_comments(source).is_empty());
+    }
+
+    #[test]
+    fn test_retrieve_doc_comment() {
+        let source = "/// @notice First\n\n/// @notice Second\nfunction f() {}\n";
+        let comments = extract_doc_comments(source);
+
+        assert_eq!(comments.len(), 2);
+        let comment = retrieve_doc_comment(&comments, location(4, 0)).unwrap();
+        assert_eq!(comment.lines[0].text, "@notice Second");
+        assert!(retrieve_doc_comment(&comments, location(1, 0)).is_none());
+    }
+
+    #[test]
+    fn test_parse_natspec() {
+        let source = "/// Transfers tokens\n/// to someone\n/// @param to The\n///   receiver\n/// @return Whether it succeeded\n/// @custom:security none\nfunction f() {}\n";
+        let comments = extract_doc_comments(source);
+        let natspec = parse_natspec(&comments[0]);
+
+        assert_eq!(natspec.tags.len(), 4);
+        assert_eq!(natspec.tags[0].kind, NatSpecKind::Notice);
+        assert_eq!(natspec.tags[0].description, "Transfers tokens to someone");
+        assert_eq!(natspec.tags[1].kind, NatSpecKind::Param);
+        assert_eq!(natspec.tags[1].name, Some("to".to_string()));
+        assert_eq!(natspec.tags[1].description, "The receiver");
+        assert_eq!(natspec.tags[1].start, location(3, 4));
+        assert_eq!(natspec.tags[1].end, location(3, 13));
+        assert_eq!(natspec.tags[2].kind, NatSpecKind::Return);
+        assert_eq!(
+            natspec.tags[3].kind,
+            NatSpecKind::Custom("custom:security".to_string())
+        );
+    }
+}
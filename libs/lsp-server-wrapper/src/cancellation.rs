@@ -0,0 +1,61 @@
+//! Cancellation of the requests handled by the worker pool.
+
+use std::cell::RefCell;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+thread_local! {
+    static CURRENT_TOKEN: RefCell<Option<CancellationToken>> = const { RefCell::new(None) };
+}
+
+/// Flag raised when the client sends a [`$/cancelRequest`] notification for a request.
+///
+/// [`$/cancelRequest`]: https://microsoft.github.io/language-server-protocol/specification#cancelRequest
+///
+/// Long running handlers should check it regularly and return
+/// [`Error::request_cancelled`](crate::Error::request_cancelled) once it is raised. The response
+/// of a cancelled request is replaced by a `RequestCancelled` error anyway, so the work left is
+/// wasted.
+#[derive(Clone, Debug, Default)]
+pub struct CancellationToken(Arc<AtomicBool>);
+
+impl CancellationToken {
+    pub fn new() -> Self {
+        CancellationToken::default()
+    }
+
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::SeqCst);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::SeqCst)
+    }
+
+    /// Runs `f` with this token as the token of the current request.
+    pub(crate) fn scope<T>(&self, f: impl FnOnce() -> T) -> T {
+        let _restore =
+            RestoreToken(CURRENT_TOKEN.with(|current| current.replace(Some(self.clone()))));
+        f()
+    }
+}
+
+/// Puts the previous token back, even if the handler panics.
+struct RestoreToken(Option<CancellationToken>);
+
+impl Drop for RestoreToken {
+    fn drop(&mut self) {
+        CURRENT_TOKEN.with(|current| current.replace(self.0.take()));
+    }
+}
+
+/// Returns the cancellation token of the request being handled on this thread.
+///
+/// Outside of a request handled by [`LspStdioServer::serve_concurrent`], like in notification
+/// handlers or with [`LspStdioServer::serve`], the token is never cancelled.
+///
+/// [`LspStdioServer::serve`]: crate::LspStdioServer::serve
+/// [`LspStdioServer::serve_concurrent`]: crate::LspStdioServer::serve_concurrent
+pub fn cancellation_token() -> CancellationToken {
+    CURRENT_TOKEN.with(|current| current.borrow().clone().unwrap_or_default())
+}
@@ -1,21 +1,34 @@
 use crate::jsonrpc::{self};
-use lsp_server::{Message, RequestId};
+use crossbeam_channel::Sender;
+use lsp_server::{Message, RequestId, Response};
 use lsp_types::notification::*;
 use lsp_types::request::*;
 use lsp_types::*;
 use serde::Serialize;
 use serde_json::Value;
-use std::cell::RefCell;
+use std::any::Any;
 use std::fmt::Display;
-use std::rc::Weak;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
 use tracing::error;
 
-use crate::server::LspServer;
+mod pending;
+mod scheduled;
+
+pub use self::pending::ResponseFuture;
+use self::pending::{parse_result, Callback, PendingRequest, PendingRequests};
+use self::scheduled::{ScheduledTasks, Task};
+
+const DEFAULT_REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
 
 #[derive(Clone)]
 pub(crate) struct ClientInner {
-    server: Option<Weak<dyn LspServer>>,
-    id: RefCell<u32>,
+    sender: Option<Sender<Message>>,
+    id: Arc<AtomicU32>,
+    pending: PendingRequests,
+    scheduled: ScheduledTasks,
+    timeout: Duration,
 }
 
 /// Handle for communicating with the language client.
@@ -23,8 +36,10 @@ pub(crate) struct ClientInner {
 /// This type provides a very cheap implementation of [`Clone`] so API consumers can cheaply clone
 /// and pass it around as needed.
 ///
-/// It also implements [`tower::Service`] in order to remain independent from the underlying
-/// transport and to facilitate further abstraction with middleware.
+/// Requests sent with [`send_request`](Self::send_request) return a [`ResponseFuture`] resolved
+/// with the typed response of the client, or with an error once the request timed out. Backends
+/// which cannot wait for it use [`send_request_with`](Self::send_request_with) instead, whose
+/// callback runs on the thread serving the messages.
 #[derive(Clone)]
 pub struct Client {
     inner: ClientInner,
@@ -34,14 +49,80 @@ impl Client {
     pub(super) fn new() -> Self {
         Client {
             inner: ClientInner {
-                server: None,
-                id: RefCell::new(0),
+                sender: None,
+                id: Arc::new(AtomicU32::new(0)),
+                pending: PendingRequests::default(),
+                scheduled: ScheduledTasks::default(),
+                timeout: DEFAULT_REQUEST_TIMEOUT,
             },
         }
     }
 
-    pub(super) fn set_server(&mut self, server: Weak<dyn LspServer>) {
-        self.inner.server = Some(server);
+    pub(super) fn set_sender(&mut self, sender: Sender<Message>) {
+        self.inner.sender = Some(sender);
+    }
+
+    /// Sets how long the requests sent from this handle wait for the client to answer, 30 seconds
+    /// by default. Once elapsed, the request is cancelled and fails with a `RequestCancelled`
+    /// error.
+    pub fn set_request_timeout(&mut self, timeout: Duration) {
+        self.inner.timeout = timeout;
+    }
+
+    /// Runs `task` on the thread serving the messages once `delay` has elapsed, with the backend
+    /// `S` of the server.
+    ///
+    /// Like the callbacks of [`send_request_with`](Self::send_request_with), this lets servers
+    /// which are not thread-safe defer some work, to debounce it for instance. The task does not
+    /// run if `S` is not the backend of the server, or if the server exits before.
+    pub fn schedule<S: 'static>(&self, delay: Duration, task: impl FnOnce(&S) + Send + 'static) {
+        let task: Task = Box::new(move |backend: &dyn Any| match backend.downcast_ref::<S>() {
+            Some(backend) => task(backend),
+            None => error!("a scheduled task expects another backend"),
+        });
+        self.inner.scheduled.push(Instant::now() + delay, task);
+    }
+
+    /// Runs the scheduled tasks which are due.
+    pub(crate) fn run_scheduled(&self, backend: &dyn Any) {
+        for task in self.inner.scheduled.take_due(Instant::now()) {
+            task(backend);
+        }
+    }
+
+    /// When the next scheduled task is due, if any.
+    pub(crate) fn next_scheduled(&self) -> Option<Instant> {
+        self.inner.scheduled.next_due()
+    }
+
+    /// Resolves the request answered by `resp`, the response is given back if no request is
+    /// waiting for it.
+    pub(crate) fn complete_request(&self, resp: Response, backend: &dyn Any) -> Option<Response> {
+        self.inner.pending.complete(resp, backend)
+    }
+
+    /// Cancels the requests which timed out and fails them.
+    pub(crate) fn expire_requests(&self, backend: &dyn Any) {
+        for (id, request) in self.inner.pending.remove_expired(Instant::now()) {
+            self.send_cancel(id);
+            let method = request.method;
+            request.resolve(backend, Err(jsonrpc::request_timeout_error(method)));
+        }
+    }
+
+    /// Cancels the request `id` if it is still waiting for its response.
+    pub(crate) fn cancel_pending(&self, id: &RequestId) -> Option<PendingRequest> {
+        let request = self.inner.pending.remove(id)?;
+        self.send_cancel(id.clone());
+        Some(request)
+    }
+
+    fn send_cancel(&self, id: RequestId) {
+        let id = match serde_json::from_value(serde_json::to_value(id).unwrap_or_default()) {
+            Ok(id) => id,
+            Err(_) => return,
+        };
+        self.send_notification_unchecked::<Cancel>(CancelParams { id });
     }
 }
 
@@ -63,7 +144,7 @@ impl Client {
     pub fn register_capability(
         &self,
         registrations: Vec<Registration>,
-    ) -> jsonrpc::Result<RequestId> {
+    ) -> jsonrpc::Result<ResponseFuture<()>> {
         self.send_request::<RegisterCapability>(RegistrationParams { registrations })
     }
 
@@ -82,7 +163,7 @@ impl Client {
     pub fn unregister_capability(
         &self,
         unregisterations: Vec<Unregistration>,
-    ) -> jsonrpc::Result<RequestId> {
+    ) -> jsonrpc::Result<ResponseFuture<()>> {
         self.send_request::<UnregisterCapability>(UnregistrationParams { unregisterations })
     }
 
@@ -113,7 +194,7 @@ impl Client {
         typ: MessageType,
         message: M,
         actions: Option<Vec<MessageActionItem>>,
-    ) -> jsonrpc::Result<RequestId> {
+    ) -> jsonrpc::Result<ResponseFuture<Option<MessageActionItem>>> {
         self.send_request_unchecked::<ShowMessageRequest>(ShowMessageRequestParams {
             typ,
             message: message.to_string(),
@@ -151,7 +232,10 @@ impl Client {
     /// # Compatibility
     ///
     /// This request was introduced in specification version 3.16.0.
-    pub fn show_document(&self, params: ShowDocumentParams) -> jsonrpc::Result<RequestId> {
+    pub fn show_document(
+        &self,
+        params: ShowDocumentParams,
+    ) -> jsonrpc::Result<ResponseFuture<ShowDocumentResult>> {
         self.send_request::<ShowDocument>(params)
     }
 
@@ -198,7 +282,7 @@ impl Client {
     /// # Compatibility
     ///
     /// This request was introduced in specification version 3.16.0.
-    pub fn code_lens_refresh(&self) -> jsonrpc::Result<RequestId> {
+    pub fn code_lens_refresh(&self) -> jsonrpc::Result<ResponseFuture<()>> {
         self.send_request::<CodeLensRefresh>(())
     }
 
@@ -224,7 +308,7 @@ impl Client {
     /// # Compatibility
     ///
     /// This request was introduced in specification version 3.16.0.
-    pub fn semantic_tokens_refresh(&self) -> jsonrpc::Result<RequestId> {
+    pub fn semantic_tokens_refresh(&self) -> jsonrpc::Result<ResponseFuture<()>> {
         self.send_request::<SemanticTokensRefresh>(())
     }
 
@@ -249,7 +333,7 @@ impl Client {
     /// # Compatibility
     ///
     /// This request was introduced in specification version 3.17.0.
-    pub fn inline_value_refresh(&self) -> jsonrpc::Result<RequestId> {
+    pub fn inline_value_refresh(&self) -> jsonrpc::Result<ResponseFuture<()>> {
         self.send_request::<InlineValueRefreshRequest>(())
     }
 
@@ -274,7 +358,7 @@ impl Client {
     /// # Compatibility
     ///
     /// This request was introduced in specification version 3.17.0.
-    pub fn inlay_hint_refresh(&self) -> jsonrpc::Result<RequestId> {
+    pub fn inlay_hint_refresh(&self) -> jsonrpc::Result<ResponseFuture<()>> {
         self.send_request::<InlayHintRefreshRequest>(())
     }
 
@@ -297,7 +381,7 @@ impl Client {
     /// # Compatibility
     ///
     /// This request was introduced in specification version 3.17.0.
-    pub fn workspace_diagnostic_refresh(&self) -> jsonrpc::Result<RequestId> {
+    pub fn workspace_diagnostic_refresh(&self) -> jsonrpc::Result<ResponseFuture<()>> {
         self.send_request::<WorkspaceDiagnosticRefresh>(())
     }
 
@@ -339,7 +423,10 @@ impl Client {
     /// # Compatibility
     ///
     /// This request was introduced in specification version 3.6.0.
-    pub fn configuration(&self, items: Vec<ConfigurationItem>) -> jsonrpc::Result<RequestId> {
+    pub fn configuration(
+        &self,
+        items: Vec<ConfigurationItem>,
+    ) -> jsonrpc::Result<ResponseFuture<Vec<Value>>> {
         self.send_request::<WorkspaceConfiguration>(ConfigurationParams { items })
     }
 
@@ -362,7 +449,9 @@ impl Client {
     /// # Compatibility
     ///
     /// This request was introduced in specification version 3.6.0.
-    pub fn workspace_folders(&self) -> jsonrpc::Result<RequestId> {
+    pub fn workspace_folders(
+        &self,
+    ) -> jsonrpc::Result<ResponseFuture<Option<Vec<WorkspaceFolder>>>> {
         self.send_request::<WorkspaceFoldersRequest>(())
     }
 
@@ -379,7 +468,10 @@ impl Client {
     /// immediately return `Err` with JSON-RPC error code `-32002` ([read more]).
     ///
     /// [read more]: https://microsoft.github.io/language-server-protocol/specification#initialize
-    pub fn apply_edit(&self, edit: WorkspaceEdit) -> jsonrpc::Result<RequestId> {
+    pub fn apply_edit(
+        &self,
+        edit: WorkspaceEdit,
+    ) -> jsonrpc::Result<ResponseFuture<ApplyWorkspaceEditResponse>> {
         self.send_request::<ApplyWorkspaceEdit>(ApplyWorkspaceEditParams { edit, label: None })
     }
 
@@ -399,12 +491,15 @@ impl Client {
     where
         N: lsp_types::notification::Notification,
     {
-        match self.inner.server.clone().unwrap().upgrade() {
-            Some(server) => {
-                server.send(Message::Notification(lsp_server::Notification::new(
-                    N::METHOD.to_string(),
-                    params,
-                )));
+        match &self.inner.sender {
+            Some(sender) => {
+                send(
+                    sender,
+                    Message::Notification(lsp_server::Notification::new(
+                        N::METHOD.to_string(),
+                        params,
+                    )),
+                );
             }
             None => {
                 eprintln!("Cannot send notification, server is not initialized");
@@ -414,32 +509,97 @@ impl Client {
 
     /// Sends a custom request to the client.
     ///
+    /// The response is read by the server loop and resolves the returned [`ResponseFuture`].
+    ///
     /// # Initialization
     ///
     /// If the request is sent to the client before the server has been initialized, this will
     /// immediately return `Err` with JSON-RPC error code `-32002` ([read more]).
     ///
     /// [read more]: https://microsoft.github.io/language-server-protocol/specification#initialize
-    pub fn send_request<R>(&self, params: R::Params) -> jsonrpc::Result<RequestId>
+    pub fn send_request<R>(&self, params: R::Params) -> jsonrpc::Result<ResponseFuture<R::Result>>
     where
         R: lsp_types::request::Request,
     {
         self.send_request_unchecked::<R>(params)
     }
 
-    fn send_request_unchecked<R>(&self, params: R::Params) -> jsonrpc::Result<RequestId>
+    /// Sends a custom request to the client and calls `callback` with its response.
+    ///
+    /// The callback runs on the thread serving the messages and is given the backend `S` of the
+    /// server, so that servers which are not thread-safe can handle the response too:
+    ///
+    /// ```ignore
+    /// client.send_request_with::<ContentRequest, Backend>(params, |backend, result| {
+    ///     backend.load_content(result)
+    /// })?;
+    /// ```
+    ///
+    /// The callback is not called if `S` is not the backend of the server.
+    ///
+    /// # Initialization
+    ///
+    /// If the request is sent to the client before the server has been initialized, this will
+    /// immediately return `Err` with JSON-RPC error code `-32002` ([read more]).
+    ///
+    /// [read more]: https://microsoft.github.io/language-server-protocol/specification#initialize
+    pub fn send_request_with<R, S>(
+        &self,
+        params: R::Params,
+        callback: impl FnOnce(&S, jsonrpc::Result<R::Result>) + Send + 'static,
+    ) -> jsonrpc::Result<RequestId>
+    where
+        R: lsp_types::request::Request,
+        S: 'static,
+    {
+        let callback: Callback =
+            Box::new(
+                move |backend: &dyn Any, result| match backend.downcast_ref::<S>() {
+                    Some(backend) => callback(backend, parse_result(R::METHOD, result)),
+                    None => error!("the response to {} expects another backend", R::METHOD),
+                },
+            );
+        self.send_request_callback::<R>(params, callback)
+            .map(|(id, _)| id)
+    }
+
+    fn send_request_unchecked<R>(
+        &self,
+        params: R::Params,
+    ) -> jsonrpc::Result<ResponseFuture<R::Result>>
     where
         R: lsp_types::request::Request,
     {
-        match self.inner.server.clone().unwrap().upgrade() {
-            Some(server) => {
+        let (slot, callback) = ResponseFuture::channel(R::METHOD);
+        let (id, deadline) = self.send_request_callback::<R>(params, callback)?;
+        Ok(ResponseFuture::new(id, deadline, slot, self.clone()))
+    }
+
+    fn send_request_callback<R>(
+        &self,
+        params: R::Params,
+        callback: Callback,
+    ) -> jsonrpc::Result<(RequestId, Instant)>
+    where
+        R: lsp_types::request::Request,
+    {
+        match &self.inner.sender {
+            Some(sender) => {
                 let id = RequestId::from(self.next_request_id().to_string());
-                server.send(Message::Request(lsp_server::Request::new(
-                    id.clone(),
-                    R::METHOD.to_string(),
-                    params,
-                )));
-                Ok(id)
+                let deadline = Instant::now() + self.inner.timeout;
+                // Registered first, the response may come before `send` returns
+                self.inner
+                    .pending
+                    .insert(id.clone(), R::METHOD, deadline, callback);
+                send(
+                    sender,
+                    Message::Request(lsp_server::Request::new(
+                        id.clone(),
+                        R::METHOD.to_string(),
+                        params,
+                    )),
+                );
+                Ok((id, deadline))
             }
             None => {
                 eprintln!("Cannot send request, server is not initialized");
@@ -451,12 +611,13 @@ impl Client {
 
 impl Client {
     fn next_request_id(&self) -> u32 {
-        let id = *self.inner.id.borrow_mut();
-        if id == u32::MAX {
-            *self.inner.id.borrow_mut() = 0;
-        } else {
-            *self.inner.id.borrow_mut() += 1;
-        }
-        id
+        // Wraps around to 0 after u32::MAX
+        self.inner.id.fetch_add(1, Ordering::Relaxed)
     }
 }
+
+fn send(sender: &Sender<Message>, msg: Message) {
+    let _ = sender.send(msg).map_err(|e| {
+        eprintln!("Error: {}", e);
+    });
+}
@@ -0,0 +1,201 @@
+//! Requests sent to the client which are waiting for their response.
+
+use crate::jsonrpc;
+use crate::Client;
+use lsp_server::{RequestId, Response};
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+use std::any::Any;
+use std::collections::HashMap;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Arc, Condvar, Mutex};
+use std::task::{Context, Poll, Waker};
+use std::time::Instant;
+
+/// Called with the backend of the server and the response, on the thread reading the messages.
+pub(crate) type Callback = Box<dyn FnOnce(&dyn Any, jsonrpc::Result<Value>) + Send>;
+
+pub(crate) struct PendingRequest {
+    pub method: &'static str,
+    deadline: Instant,
+    callback: Callback,
+}
+
+impl PendingRequest {
+    pub fn resolve(self, backend: &dyn Any, result: jsonrpc::Result<Value>) {
+        (self.callback)(backend, result)
+    }
+}
+
+#[derive(Clone, Default)]
+pub(crate) struct PendingRequests(Arc<Mutex<HashMap<RequestId, PendingRequest>>>);
+
+impl PendingRequests {
+    pub fn insert(
+        &self,
+        id: RequestId,
+        method: &'static str,
+        deadline: Instant,
+        callback: Callback,
+    ) {
+        self.0.lock().unwrap().insert(
+            id,
+            PendingRequest {
+                method,
+                deadline,
+                callback,
+            },
+        );
+    }
+
+    pub fn remove(&self, id: &RequestId) -> Option<PendingRequest> {
+        self.0.lock().unwrap().remove(id)
+    }
+
+    /// Removes the requests whose deadline has passed.
+    pub fn remove_expired(&self, now: Instant) -> Vec<(RequestId, PendingRequest)> {
+        let mut requests = self.0.lock().unwrap();
+        let expired: Vec<RequestId> = requests
+            .iter()
+            .filter(|(_, request)| request.deadline <= now)
+            .map(|(id, _)| id.clone())
+            .collect();
+        expired
+            .into_iter()
+            .filter_map(|id| requests.remove(&id).map(|request| (id, request)))
+            .collect()
+    }
+
+    /// Resolves the request answered by `resp`. The response is given back if no request is
+    /// waiting for it.
+    pub fn complete(&self, resp: Response, backend: &dyn Any) -> Option<Response> {
+        let Some(request) = self.remove(&resp.id) else {
+            return Some(resp);
+        };
+        let result = match resp.error {
+            Some(error) => Err(error.into()),
+            None => Ok(resp.result.unwrap_or(Value::Null)),
+        };
+        request.resolve(backend, result);
+        None
+    }
+}
+
+/// Decodes the result of a `method` request.
+pub(crate) fn parse_result<T: DeserializeOwned>(
+    method: &str,
+    result: jsonrpc::Result<Value>,
+) -> jsonrpc::Result<T> {
+    serde_json::from_value(result?).map_err(|e| jsonrpc::invalid_response_error(method, e))
+}
+
+pub(crate) struct Slot<T> {
+    result: Option<jsonrpc::Result<T>>,
+    waker: Option<Waker>,
+}
+
+pub(crate) type SharedSlot<T> = Arc<(Mutex<Slot<T>>, Condvar)>;
+
+/// Response to a request sent to the client.
+///
+/// The response can be awaited as a [`Future`], or waited for with [`wait`](Self::wait). Either
+/// way, the request fails with a `RequestCancelled` error once its timeout has elapsed, see
+/// [`Client::set_request_timeout`].
+///
+/// The responses are read by the thread serving the messages, so `wait` must not be called from a
+/// handler of [`LspStdioServer::serve`] which would wait for its own thread until the timeout.
+/// Handlers of [`LspStdioServer::serve_concurrent`] run on worker threads and can wait.
+///
+/// [`LspStdioServer::serve`]: crate::LspStdioServer::serve
+/// [`LspStdioServer::serve_concurrent`]: crate::LspStdioServer::serve_concurrent
+#[must_use = "the request is sent anyway, but its response is ignored"]
+pub struct ResponseFuture<T> {
+    id: RequestId,
+    deadline: Instant,
+    slot: SharedSlot<T>,
+    client: Client,
+}
+
+impl<T: DeserializeOwned + Send + 'static> ResponseFuture<T> {
+    /// Returns the future and the callback resolving it.
+    pub(crate) fn channel(method: &'static str) -> (SharedSlot<T>, Callback) {
+        let slot: SharedSlot<T> = Arc::new((
+            Mutex::new(Slot {
+                result: None,
+                waker: None,
+            }),
+            Condvar::new(),
+        ));
+        let callback = {
+            let slot = slot.clone();
+            Box::new(move |_: &dyn Any, result| {
+                let (lock, ready) = &*slot;
+                let mut slot = lock.lock().unwrap();
+                slot.result = Some(parse_result(method, result));
+                if let Some(waker) = slot.waker.take() {
+                    waker.wake();
+                }
+                ready.notify_all();
+            })
+        };
+        (slot, callback)
+    }
+}
+
+impl<T> ResponseFuture<T> {
+    pub(crate) fn new(
+        id: RequestId,
+        deadline: Instant,
+        slot: SharedSlot<T>,
+        client: Client,
+    ) -> Self {
+        ResponseFuture {
+            id,
+            deadline,
+            slot,
+            client,
+        }
+    }
+
+    /// Id of the request sent to the client.
+    pub fn id(&self) -> &RequestId {
+        &self.id
+    }
+
+    /// Blocks until the client answers or the request times out.
+    pub fn wait(self) -> jsonrpc::Result<T> {
+        let (lock, ready) = &*self.slot;
+        let mut slot = lock.lock().unwrap();
+        loop {
+            if let Some(result) = slot.result.take() {
+                return result;
+            }
+            let now = Instant::now();
+            if now >= self.deadline {
+                // Unless the response is being resolved right now
+                if let Some(request) = self.client.cancel_pending(&self.id) {
+                    return Err(jsonrpc::request_timeout_error(request.method));
+                }
+                slot = ready.wait(slot).unwrap();
+            } else {
+                slot = ready.wait_timeout(slot, self.deadline - now).unwrap().0;
+            }
+        }
+    }
+}
+
+impl<T> Future for ResponseFuture<T> {
+    type Output = jsonrpc::Result<T>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut slot = self.slot.0.lock().unwrap();
+        match slot.result.take() {
+            Some(result) => Poll::Ready(result),
+            None => {
+                slot.waker = Some(cx.waker().clone());
+                Poll::Pending
+            }
+        }
+    }
+}
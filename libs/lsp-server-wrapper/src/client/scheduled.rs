@@ -0,0 +1,31 @@
+//! Tasks deferred by the backend, which run on the thread serving the messages.
+
+use std::any::Any;
+use std::sync::{Arc, Mutex};
+use std::time::Instant;
+
+/// Called with the backend of the server.
+pub(crate) type Task = Box<dyn FnOnce(&dyn Any) + Send>;
+
+#[derive(Clone, Default)]
+pub(crate) struct ScheduledTasks(Arc<Mutex<Vec<(Instant, Task)>>>);
+
+impl ScheduledTasks {
+    pub fn push(&self, at: Instant, task: Task) {
+        self.0.lock().unwrap().push((at, task));
+    }
+
+    /// Removes the tasks which are due, in the order they are due.
+    pub fn take_due(&self, now: Instant) -> Vec<Task> {
+        let mut tasks = self.0.lock().unwrap();
+        let (mut due, later): (Vec<_>, Vec<_>) = tasks.drain(..).partition(|(at, _)| *at <= now);
+        *tasks = later;
+        // Stable, tasks due at the same time keep the order they were scheduled in
+        due.sort_by_key(|(at, _)| *at);
+        due.into_iter().map(|(_, task)| task).collect()
+    }
+
+    pub fn next_due(&self) -> Option<Instant> {
+        self.0.lock().unwrap().iter().map(|(at, _)| *at).min()
+    }
+}
@@ -1,4 +1,6 @@
 mod error;
 
-pub(crate) use self::error::not_initialized_error;
+pub(crate) use self::error::{
+    invalid_response_error, not_initialized_error, request_timeout_error,
+};
 pub use self::error::{Error, Result};
@@ -181,6 +181,45 @@ impl Display for Error {
 
 impl std::error::Error for Error {}
 
+impl From<Error> for lsp_server::ResponseError {
+    fn from(error: Error) -> Self {
+        lsp_server::ResponseError {
+            code: error.code.code() as i32,
+            message: error.message.into_owned(),
+            data: error.data,
+        }
+    }
+}
+
+impl From<lsp_server::ResponseError> for Error {
+    fn from(error: lsp_server::ResponseError) -> Self {
+        Error {
+            code: ErrorCode::from(error.code as i64),
+            message: Cow::Owned(error.message),
+            data: error.data,
+        }
+    }
+}
+
+/// Error returned for a request sent to the client which was not answered in time. The request is
+/// cancelled on the client side as well.
+pub(crate) fn request_timeout_error(method: &str) -> Error {
+    Error {
+        code: ErrorCode::RequestCancelled,
+        message: Cow::Owned(format!("Request {} timed out", method)),
+        data: None,
+    }
+}
+
+/// Error returned for a response of the client which does not match the result of the request.
+pub(crate) fn invalid_response_error(method: &str, error: serde_json::Error) -> Error {
+    Error {
+        code: ErrorCode::InternalError,
+        message: Cow::Owned(format!("Invalid {} response: {}", method, error)),
+        data: None,
+    }
+}
+
 /// Error response returned for every request received before the server is initialized.
 ///
 /// See [here](https://microsoft.github.io/language-server-protocol/specification#initialize)
@@ -1,11 +1,15 @@
+mod cancellation;
 mod client;
 mod jsonrpc;
 mod server;
 mod service;
+mod test_client;
+mod worker_pool;
 
 pub use crate::jsonrpc::{Error, Result};
-pub use client::Client;
-pub use lsp_server::RequestId;
+pub use cancellation::{cancellation_token, CancellationToken};
+pub use client::{Client, ResponseFuture};
+pub use lsp_server::{Connection, RequestId, ResponseError};
 pub use lsp_types;
 use lsp_types::request::{
     GotoDeclarationParams, GotoDeclarationResponse, GotoImplementationParams,
@@ -15,6 +19,7 @@ use lsp_types::*;
 use serde_json::Value;
 pub use server::LspStdioServer;
 pub use service::LspService;
+pub use test_client::LspTestClient;
 
 /// Trait implemented by language server backends.
 ///
@@ -239,7 +244,6 @@ pub trait LanguageServer {
     /// project-wide references for the symbol denoted by the given text document position.
     ///
     /// [`textDocument/references`]: https://microsoft.github.io/language-server-protocol/specification#textDocument_references
-
     fn references(&self, params: ReferenceParams) -> Result<Option<Vec<Location>>> {
         let _ = params;
         eprintln!("Got a textDocument/references request, but it is not implemented");
@@ -452,7 +456,6 @@ pub trait LanguageServer {
     /// lenses for a given text document.
     ///
     /// [`textDocument/codeLens`]: https://microsoft.github.io/language-server-protocol/specification#textDocument_codeLens
-
     fn code_lens(&self, params: CodeLensParams) -> Result<Option<Vec<CodeLens>>> {
         let _ = params;
         eprintln!("Got a textDocument/codeLens request, but it is not implemented");
@@ -463,7 +466,6 @@ pub trait LanguageServer {
     /// command for a given code lens item.
     ///
     /// [`codeLens/resolve`]: https://microsoft.github.io/language-server-protocol/specification#codeLens_resolve
-
     fn code_lens_resolve(&self, params: CodeLens) -> Result<CodeLens> {
         let _ = params;
         eprintln!("Got a codeLens/resolve request, but it is not implemented");
@@ -478,7 +480,6 @@ pub trait LanguageServer {
     /// # Compatibility
     ///
     /// This request was introduced in specification version 3.10.0.
-
     fn folding_range(&self, params: FoldingRangeParams) -> Result<Option<Vec<FoldingRange>>> {
         let _ = params;
         eprintln!("Got a textDocument/foldingRange request, but it is not implemented");
@@ -497,7 +498,6 @@ pub trait LanguageServer {
     /// # Compatibility
     ///
     /// This request was introduced in specification version 3.15.0.
-
     fn selection_range(&self, params: SelectionRangeParams) -> Result<Option<Vec<SelectionRange>>> {
         let _ = params;
         eprintln!("Got a textDocument/selectionRange request, but it is not implemented");
@@ -516,7 +516,6 @@ pub trait LanguageServer {
     ///   should be used to infer a hierarchy.
     /// * [`DocumentSymbolResponse::Nested`] which is a hierarchy of symbols found in a given text
     ///   document.
-
     fn document_symbol(
         &self,
         params: DocumentSymbolParams,
@@ -541,7 +540,6 @@ pub trait LanguageServer {
     /// # Compatibility
     ///
     /// This request was introduced in specification version 3.16.0.
-
     fn semantic_tokens_full(
         &self,
         params: SemanticTokensParams,
@@ -562,7 +560,6 @@ pub trait LanguageServer {
     /// # Compatibility
     ///
     /// This request was introduced in specification version 3.16.0.
-
     fn semantic_tokens_full_delta(
         &self,
         params: SemanticTokensDeltaParams,
@@ -590,7 +587,6 @@ pub trait LanguageServer {
     /// # Compatibility
     ///
     /// This request was introduced in specification version 3.16.0.
-
     fn semantic_tokens_range(
         &self,
         params: SemanticTokensRangeParams,
@@ -609,7 +605,6 @@ pub trait LanguageServer {
     /// # Compatibility
     ///
     /// This request was introduced in specification version 3.17.0.
-
     fn inline_value(&self, params: InlineValueParams) -> Result<Option<Vec<InlineValue>>> {
         let _ = params;
         eprintln!("Got a textDocument/inlineValue request, but it is not implemented");
@@ -625,7 +620,6 @@ pub trait LanguageServer {
     /// # Compatibility
     ///
     /// This request was introduced in specification version 3.17.0
-
     fn inlay_hint(&self, params: InlayHintParams) -> Result<Option<Vec<InlayHint>>> {
         let _ = params;
         eprintln!("Got a textDocument/inlayHint request, but it is not implemented");
@@ -654,7 +648,6 @@ pub trait LanguageServer {
     /// # Compatibility
     ///
     /// This request was introduced in specification version 3.17.0
-
     fn inlay_hint_resolve(&self, params: InlayHint) -> Result<InlayHint> {
         let _ = params;
         eprintln!("Got a inlayHint/resolve request, but it is not implemented");
@@ -684,7 +677,6 @@ pub trait LanguageServer {
     /// # Compatibility
     ///
     /// This request was introduced in specification version 3.16.0.
-
     fn moniker(&self, params: MonikerParams) -> Result<Option<Vec<Moniker>>> {
         let _ = params;
         eprintln!("Got a textDocument/moniker request, but it is not implemented");
@@ -709,7 +701,6 @@ pub trait LanguageServer {
     /// All other properties (usually `sort_text`, `filter_text`, `insert_text`, and `text_edit`)
     /// must be provided in the `textDocument/completion` response and must not be changed during
     /// resolve.
-
     fn completion(&self, params: CompletionParams) -> Result<Option<CompletionResponse>> {
         let _ = params;
         eprintln!("Got a textDocument/completion request, but it is not implemented");
@@ -720,7 +711,6 @@ pub trait LanguageServer {
     /// additional information for a given completion item.
     ///
     /// [`completionItem/resolve`]: https://microsoft.github.io/language-server-protocol/specification#completionItem_resolve
-
     fn completion_resolve(&self, params: CompletionItem) -> Result<CompletionItem> {
         let _ = params;
         eprintln!("Got a completionItem/resolve request, but it is not implemented");
@@ -741,7 +731,6 @@ pub trait LanguageServer {
     /// # Compatibility
     ///
     /// This request was introduced in specification version 3.17.0.
-
     fn diagnostic(
         &self,
         params: DocumentDiagnosticParams,
@@ -779,7 +768,6 @@ pub trait LanguageServer {
     /// # Compatibility
     ///
     /// This request was introduced in specification version 3.17.0.
-
     fn workspace_diagnostic(
         &self,
         params: WorkspaceDiagnosticParams,
@@ -793,7 +781,6 @@ pub trait LanguageServer {
     /// signature information at a given cursor position.
     ///
     /// [`textDocument/signatureHelp`]: https://microsoft.github.io/language-server-protocol/specification#textDocument_signatureHelp
-
     fn signature_help(&self, params: SignatureHelpParams) -> Result<Option<SignatureHelp>> {
         let _ = params;
         eprintln!("Got a textDocument/signatureHelp request, but it is not implemented");
@@ -853,7 +840,6 @@ pub trait LanguageServer {
     /// * The ability to group code actions using a kind. Clients are allowed to ignore that
     ///   information. However it allows them to better group code action, for example, into
     ///   corresponding menus (e.g. all refactor code actions into a refactor menu).
-
     fn code_action(&self, params: CodeActionParams) -> Result<Option<CodeActionResponse>> {
         let _ = params;
         eprintln!("Got a textDocument/codeAction request, but it is not implemented");
@@ -871,7 +857,6 @@ pub trait LanguageServer {
     /// # Compatibility
     ///
     /// This request was introduced in specification version 3.16.0.
-
     fn code_action_resolve(&self, params: CodeAction) -> Result<CodeAction> {
         let _ = params;
         eprintln!("Got a codeAction/resolve request, but it is not implemented");
@@ -892,7 +877,6 @@ pub trait LanguageServer {
     /// # Compatibility
     ///
     /// This request was introduced in specification version 3.6.0.
-
     fn document_color(&self, params: DocumentColorParams) -> Result<Vec<ColorInformation>> {
         let _ = params;
         eprintln!("Got a textDocument/documentColor request, but it is not implemented");
@@ -915,7 +899,6 @@ pub trait LanguageServer {
     ///
     /// This request has no special capabilities and registration options since it is sent as a
     /// resolve request for the [`textDocument/documentColor`](Self::document_color) request.
-
     fn color_presentation(
         &self,
         params: ColorPresentationParams,
@@ -929,7 +912,6 @@ pub trait LanguageServer {
     /// whole document.
     ///
     /// [`textDocument/formatting`]: https://microsoft.github.io/language-server-protocol/specification#textDocument_formatting
-
     fn formatting(&self, params: DocumentFormattingParams) -> Result<Option<Vec<TextEdit>>> {
         let _ = params;
         eprintln!("Got a textDocument/formatting request, but it is not implemented");
@@ -940,7 +922,6 @@ pub trait LanguageServer {
     /// format a given range in a document.
     ///
     /// [`textDocument/rangeFormatting`]: https://microsoft.github.io/language-server-protocol/specification#textDocument_rangeFormatting
-
     fn range_formatting(
         &self,
         params: DocumentRangeFormattingParams,
@@ -954,7 +935,6 @@ pub trait LanguageServer {
     /// format parts of the document during typing.
     ///
     /// [`textDocument/onTypeFormatting`]: https://microsoft.github.io/language-server-protocol/specification#textDocument_onTypeFormatting
-
     fn on_type_formatting(
         &self,
         params: DocumentOnTypeFormattingParams,
@@ -969,7 +949,6 @@ pub trait LanguageServer {
     /// symbol.
     ///
     /// [`textDocument/rename`]: https://microsoft.github.io/language-server-protocol/specification#textDocument_rename
-
     fn rename(&self, params: RenameParams) -> Result<Option<WorkspaceEdit>> {
         let _ = params;
         eprintln!("Got a textDocument/rename request, but it is not implemented");
@@ -984,7 +963,6 @@ pub trait LanguageServer {
     /// # Compatibility
     ///
     /// This request was introduced in specification version 3.12.0.
-
     fn prepare_rename(
         &self,
         params: TextDocumentPositionParams,
@@ -1009,7 +987,6 @@ pub trait LanguageServer {
     /// # Compatibility
     ///
     /// This request was introduced in specification version 3.16.0.
-
     fn linked_editing_range(
         &self,
         params: LinkedEditingRangeParams,
@@ -1037,7 +1014,6 @@ pub trait LanguageServer {
     ///
     /// Servers can only use this new model if clients advertise support for it via the
     /// `workspace.symbol.resolve_support` capability.
-
     fn symbol(&self, params: WorkspaceSymbolParams) -> Result<Option<Vec<SymbolInformation>>> {
         let _ = params;
         eprintln!("Got a workspace/symbol request, but it is not implemented");
@@ -1054,7 +1030,6 @@ pub trait LanguageServer {
     /// # Compatibility
     ///
     /// This request was introduced in specification version 3.17.0.
-
     fn symbol_resolve(&self, params: WorkspaceSymbol) -> Result<WorkspaceSymbol> {
         let _ = params;
         eprintln!("Got a workspaceSymbol/resolve request, but it is not implemented");
@@ -1065,7 +1040,6 @@ pub trait LanguageServer {
     /// to signal the change of configuration settings.
     ///
     /// [`workspace/didChangeConfiguration`]: https://microsoft.github.io/language-server-protocol/specification#workspace_didChangeConfiguration
-
     fn did_change_configuration(&self, params: DidChangeConfigurationParams) {
         let _ = params;
         eprintln!("Got a workspace/didChangeConfiguration notification, but it is not implemented");
@@ -1084,7 +1058,6 @@ pub trait LanguageServer {
     ///
     /// This notification is also sent if the server has registered itself to receive this
     /// notification.
-
     fn did_change_workspace_folders(&self, params: DidChangeWorkspaceFoldersParams) {
         let _ = params;
         eprintln!(
@@ -1105,7 +1078,6 @@ pub trait LanguageServer {
     /// # Compatibility
     ///
     /// This request was introduced in specification version 3.16.0.
-
     fn will_create_files(&self, params: CreateFilesParams) -> Result<Option<WorkspaceEdit>> {
         let _ = params;
         eprintln!("Got a workspace/willCreateFiles request, but it is not implemented");
@@ -1116,7 +1088,6 @@ pub trait LanguageServer {
     /// were created from within the client.
     ///
     /// [`workspace/didCreateFiles`]: https://microsoft.github.io/language-server-protocol/specification#workspace_didCreateFiles
-
     fn did_create_files(&self, params: CreateFilesParams) {
         let _ = params;
         eprintln!("Got a workspace/didCreateFiles notification, but it is not implemented");
@@ -1135,7 +1106,6 @@ pub trait LanguageServer {
     /// # Compatibility
     ///
     /// This request was introduced in specification version 3.16.0.
-
     fn will_rename_files(&self, params: RenameFilesParams) -> Result<Option<WorkspaceEdit>> {
         let _ = params;
         eprintln!("Got a workspace/willRenameFiles request, but it is not implemented");
@@ -1146,7 +1116,6 @@ pub trait LanguageServer {
     /// files were renamed from within the client.
     ///
     /// [`workspace/didRenameFiles`]: https://microsoft.github.io/language-server-protocol/specification#workspace_didRenameFiles
-
     fn did_rename_files(&self, params: RenameFilesParams) {
         let _ = params;
         eprintln!("Got a workspace/didRenameFiles notification, but it is not implemented");
@@ -1166,7 +1135,6 @@ pub trait LanguageServer {
     /// # Compatibility
     ///
     /// This request was introduced in specification version 3.16.0.
-
     fn will_delete_files(&self, params: DeleteFilesParams) -> Result<Option<WorkspaceEdit>> {
         let _ = params;
         eprintln!("Got a workspace/willDeleteFiles request, but it is not implemented");
@@ -1177,7 +1145,6 @@ pub trait LanguageServer {
     /// files were deleted from within the client.
     ///
     /// [`workspace/didDeleteFiles`]: https://microsoft.github.io/language-server-protocol/specification#workspace_didDeleteFiles
-
     fn did_delete_files(&self, params: DeleteFilesParams) {
         let _ = params;
         eprintln!("Got a workspace/didDeleteFiles notification, but it is not implemented");
@@ -1191,7 +1158,6 @@ pub trait LanguageServer {
     /// It is recommended that servers register for these file events using the registration
     /// mechanism. This can be done here or in the [`initialized`](Self::initialized) method using
     /// [`Client::register_capability`](crate::Client::register_capability).
-
     fn did_change_watched_files(&self, params: DidChangeWatchedFilesParams) {
         let _ = params;
         eprintln!("Got a workspace/didChangeWatchedFiles notification, but it is not implemented");
@@ -1204,13 +1170,17 @@ pub trait LanguageServer {
     ///
     /// In most cases, the server creates a [`WorkspaceEdit`] structure and applies the changes to
     /// the workspace using `Client::apply_edit()` before returning from this function.
-
     fn execute_command(&self, params: ExecuteCommandParams) -> Result<Option<Value>> {
         let _ = params;
         eprintln!("Got a workspace/executeCommand request, but it is not implemented");
         Err(Error::method_not_found())
     }
 
+    /// Called with the responses of the client which no request is waiting for anymore, like the
+    /// late responses of requests which timed out.
+    ///
+    /// The responses to requests sent with the [`Client`] are given to their [`ResponseFuture`]
+    /// or callback instead.
     fn on_response(&self, id: RequestId, response: Option<serde_json::Value>) {
         let _ = id;
         let _ = response;
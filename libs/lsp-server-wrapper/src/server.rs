@@ -1,37 +1,79 @@
 use std::cell::RefCell;
+use std::collections::HashMap;
 use std::error::Error;
+use std::io;
+use std::net::ToSocketAddrs;
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::rc::Rc;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
 
-use std::rc::{Rc, Weak};
-
+use crate::cancellation::CancellationToken;
 use crate::service::LspService;
-use crate::{Client, LanguageServer};
-use lsp_server::{Connection, IoThreads, Message, Response};
+use crate::worker_pool::WorkerPool;
+use crate::{jsonrpc, Client, LanguageServer};
+use crossbeam_channel::{RecvTimeoutError, Sender};
+use lsp_server::{Connection, IoThreads, Message, RequestId, Response};
+use lsp_types::{CancelParams, NumberOrString};
+use serde_json::Value;
 
-pub(crate) trait LspServer {
-    fn send(&self, msg: Message);
-}
+const EXIT_METHOD: &str = "exit";
+const SHUTDOWN_METHOD: &str = "shutdown";
+const CANCEL_METHOD: &str = "$/cancelRequest";
+// How often the requests sent to the client are checked for timeouts while no message comes
+const EXPIRE_INTERVAL: Duration = Duration::from_millis(100);
+
+type PendingRequests = Arc<Mutex<HashMap<RequestId, CancellationToken>>>;
 
 struct InnerLspStdioServer {
     connection: Connection,
-    io_threads: IoThreads,
+    io_threads: Option<IoThreads>,
     client: Rc<RefCell<Client>>,
-    self_ref: Weak<LspStdioServer>,
 }
 
 pub struct LspStdioServer {
     inner: InnerLspStdioServer,
 }
 
-impl<'a> LspStdioServer {
+impl LspStdioServer {
     pub fn new() -> Rc<LspStdioServer> {
         let (connection, io_threads) = Connection::stdio();
-        let client = Rc::new(RefCell::new(Client::new()));
-        Rc::new_cyclic(|me| LspStdioServer {
+        Self::with_connection(connection, Some(io_threads))
+    }
+
+    /// Creates a server over an in-memory connection, the returned connection is the client side.
+    ///
+    /// Mostly useful in tests, see [`LspTestClient`](crate::LspTestClient).
+    pub fn memory() -> (Rc<LspStdioServer>, Connection) {
+        let (server, client) = Connection::memory();
+        (Self::with_connection(server, None), client)
+    }
+
+    /// Creates a server listening on `addr`, it blocks until a client connects.
+    pub fn listen<A: ToSocketAddrs>(addr: A) -> io::Result<Rc<LspStdioServer>> {
+        let (connection, io_threads) = Connection::listen(addr)?;
+        Ok(Self::with_connection(connection, Some(io_threads)))
+    }
+
+    /// Creates a server connecting to a client listening on `addr`.
+    pub fn connect<A: ToSocketAddrs>(addr: A) -> io::Result<Rc<LspStdioServer>> {
+        let (connection, io_threads) = Connection::connect(addr)?;
+        Ok(Self::with_connection(connection, Some(io_threads)))
+    }
+
+    /// Creates a server talking over an existing connection. The io threads, if any, are joined
+    /// once the server exits.
+    pub fn with_connection(
+        connection: Connection,
+        io_threads: Option<IoThreads>,
+    ) -> Rc<LspStdioServer> {
+        let mut client = Client::new();
+        client.set_sender(connection.sender.clone());
+        Rc::new(LspStdioServer {
             inner: InnerLspStdioServer {
                 connection,
                 io_threads,
-                client,
-                self_ref: me.clone(),
+                client: Rc::new(RefCell::new(client)),
             },
         })
     }
@@ -40,15 +82,20 @@ impl<'a> LspStdioServer {
         &self,
         service: &LspService<S>,
     ) -> Result<(), Box<dyn Error>> {
-        let (initialize_id, initialize_params) = self.inner.connection.initialize_start()?;
-        let res = service.call_request("initialize", initialize_params)?;
-        let res = match res {
-            Some(res) => res,
-            None => return Ok(()),
-        };
-
-        let resp = Response::new_ok(initialize_id, res);
-        self.inner.connection.sender.send(resp.into()).unwrap();
+        // Requests sent before `initialize` are answered by `initialize_start`
+        loop {
+            let (initialize_id, initialize_params) = self.inner.connection.initialize_start()?;
+            match service.call_request("initialize", initialize_params) {
+                Ok(result) => {
+                    send_response(&self.inner.connection.sender, initialize_id, Ok(result));
+                    break;
+                }
+                Err(e) => {
+                    eprintln!("Initialize Error: {}", e);
+                    send_response(&self.inner.connection.sender, initialize_id, Err(e));
+                }
+            }
+        }
         match &self.inner.connection.receiver.recv() {
             Ok(Message::Notification(n)) => service
                 .call_notification(&n.method, n.params.clone())
@@ -75,77 +122,225 @@ impl<'a> LspStdioServer {
         Ok(())
     }
 
-    pub fn serve<S: LanguageServer, F>(this: Rc<Self>, init: F) -> Result<(), Box<dyn Error>>
+    pub fn serve<S: LanguageServer + 'static, F>(
+        this: Rc<Self>,
+        init: F,
+    ) -> Result<(), Box<dyn Error>>
     where
-        F: FnOnce(Rc<RefCell<Client>>) -> S + 'a,
+        F: FnOnce(Rc<RefCell<Client>>) -> S,
     {
-        this.inner
-            .client
-            .borrow_mut()
-            .set_server(this.inner.self_ref.clone());
         let client = this.inner.client.clone();
         let service = LspService::new(client, init);
         this.run_initialization(&service)?;
         this.serve_loop(service)?;
-        Rc::into_inner(this).unwrap().inner.io_threads.join()?;
+        Self::join(this)
+    }
+
+    /// Serves like [`serve`](Self::serve), but runs the handlers on a pool of `workers` threads
+    /// so that a slow handler does not hold back the other messages.
+    ///
+    /// Requests concerning a text document and the notifications of this document are handled in
+    /// the order they were received. Other requests are handled by the first free worker.
+    ///
+    /// When the client cancels a request, it is answered right away with a `RequestCancelled`
+    /// error and the handler can stop early by checking [`cancellation_token`].
+    ///
+    /// [`cancellation_token`]: crate::cancellation_token
+    pub fn serve_concurrent<S, F>(
+        this: Rc<Self>,
+        workers: usize,
+        init: F,
+    ) -> Result<(), Box<dyn Error>>
+    where
+        S: LanguageServer + Send + Sync + 'static,
+        F: FnOnce(Client) -> S,
+    {
+        let client = this.inner.client.borrow().clone();
+        let service = Arc::new(LspService::with_backend(init(client)));
+        this.run_initialization(&service)?;
+        let pool = WorkerPool::new(workers);
+        this.serve_concurrent_loop(&service, &pool);
+        pool.join();
+        drop(service);
+        Self::join(this)
+    }
+
+    fn join(this: Rc<Self>) -> Result<(), Box<dyn Error>> {
+        // The writer thread stops once every sender of the connection is dropped
+        let InnerLspStdioServer { io_threads, .. } = Rc::into_inner(this).unwrap().inner;
+        if let Some(io_threads) = io_threads {
+            io_threads.join()?;
+        }
         Ok(())
     }
 
-    fn serve_loop<S: LanguageServer>(&self, service: LspService<S>) -> Result<(), Box<dyn Error>> {
-        for msg in &self.inner.connection.receiver {
+    fn serve_loop<S: LanguageServer + 'static>(
+        &self,
+        service: LspService<S>,
+    ) -> Result<(), Box<dyn Error>> {
+        let client = self.inner.client.borrow().clone();
+        while let Some(msg) = self.next_message(&service, &client) {
             match msg {
                 Message::Request(req) => {
-                    let id = req.id.clone();
-                    let is_shutdown = self.inner.connection.handle_shutdown(&req);
-                    let is_shutdown = match is_shutdown {
-                        Ok(is_shutdown) => is_shutdown,
-                        Err(e) => {
-                            eprintln!("Handle shutdown Error: {}", e);
-                            continue;
-                        }
-                    };
                     let result = service.call_request(&req.method, req.params);
-                    let result = match result {
-                        Ok(result) => result,
-                        Err(e) => {
-                            eprintln!("Call request Error: {}", e);
-                            continue;
-                        }
-                    };
-                    let resp = Response {
-                        id,
-                        result,
-                        error: None,
-                    };
-                    if is_shutdown {
-                        eprintln!("Shutting down");
-                        return Ok(());
-                    }
-                    let status = self.inner.connection.sender.send(Message::Response(resp));
-                    if status.is_err() {
-                        eprintln!("Error sending request's response: {status:?}");
+                    if let Err(e) = &result {
+                        eprintln!("Call request Error ({}): {}", req.method, e);
                     }
-                    continue;
+                    send_response(&self.inner.connection.sender, req.id, result);
                 }
-                Message::Response(resp) => service.call_response(resp.id, resp.result),
+                Message::Response(resp) => service.handle_response(&client, resp),
                 Message::Notification(not) => {
                     let status = service.call_notification(&not.method, not.params);
                     if status.is_err() {
                         eprintln!("Error calling notification ({}): {status:?}", not.method);
                     }
-                    continue;
+                    if not.method == EXIT_METHOD {
+                        eprintln!("Shutting down");
+                        return Ok(());
+                    }
                 }
             }
         }
         eprintln!("Out of loop");
         Ok(())
     }
+
+    fn serve_concurrent_loop<S>(&self, service: &Arc<LspService<S>>, pool: &WorkerPool)
+    where
+        S: LanguageServer + Send + Sync + 'static,
+    {
+        let sender = &self.inner.connection.sender;
+        let client = self.inner.client.borrow().clone();
+        let pending = PendingRequests::default();
+
+        while let Some(msg) = self.next_message(service, &client) {
+            match msg {
+                // Lifecycle requests change the state seen by the next messages
+                Message::Request(req) if req.method == SHUTDOWN_METHOD => {
+                    let result = service.call_request(&req.method, req.params);
+                    send_response(sender, req.id, result);
+                }
+                Message::Request(req) => {
+                    let token = CancellationToken::new();
+                    pending
+                        .lock()
+                        .unwrap()
+                        .insert(req.id.clone(), token.clone());
+                    let document = document_uri(&req.params);
+                    let job = {
+                        let service = service.clone();
+                        let pending = pending.clone();
+                        let sender = sender.clone();
+                        move || {
+                            let result = if token.is_cancelled() {
+                                Err(jsonrpc::Error::request_cancelled())
+                            } else {
+                                token.scope(|| {
+                                    catch_unwind(AssertUnwindSafe(|| {
+                                        service.call_request(&req.method, req.params)
+                                    }))
+                                    .unwrap_or_else(|_| Err(jsonrpc::Error::internal_error()))
+                                })
+                            };
+                            if let Err(e) = &result {
+                                eprintln!("Call request Error ({}): {}", req.method, e);
+                            }
+                            // A cancelled request was already answered
+                            if pending.lock().unwrap().remove(&req.id).is_some() {
+                                send_response(&sender, req.id, result);
+                            }
+                        }
+                    };
+                    match document {
+                        Some(document) => pool.execute_ordered(&document, job),
+                        None => pool.execute(job),
+                    }
+                }
+                // Callbacks of the requests sent to the client run on this thread
+                Message::Response(resp) => service.handle_response(&client, resp),
+                Message::Notification(not) if not.method == CANCEL_METHOD => {
+                    let Ok(params) = serde_json::from_value::<CancelParams>(not.params) else {
+                        eprintln!("Invalid {} params", CANCEL_METHOD);
+                        continue;
+                    };
+                    let id = match params.id {
+                        NumberOrString::Number(id) => RequestId::from(id),
+                        NumberOrString::String(id) => RequestId::from(id),
+                    };
+                    if let Some(token) = pending.lock().unwrap().remove(&id) {
+                        token.cancel();
+                        send_response(sender, id, Err(jsonrpc::Error::request_cancelled()));
+                    }
+                }
+                Message::Notification(not) if not.method == EXIT_METHOD => {
+                    let _ = service.call_notification(&not.method, not.params);
+                    eprintln!("Shutting down");
+                    return;
+                }
+                Message::Notification(not) => {
+                    // Notifications without document are kept in order between themselves
+                    let document = document_uri(&not.params).unwrap_or_default();
+                    let service = service.clone();
+                    pool.execute_ordered(&document, move || {
+                        let status = service.call_notification(&not.method, not.params);
+                        if status.is_err() {
+                            eprintln!("Error calling notification ({}): {status:?}", not.method);
+                        }
+                    });
+                }
+            }
+        }
+        eprintln!("Out of loop");
+    }
+
+    /// Waits for the next message, failing the requests sent to the client that time out and
+    /// running the scheduled tasks in the meantime. Returns `None` once the connection is closed.
+    fn next_message<S: LanguageServer + 'static>(
+        &self,
+        service: &LspService<S>,
+        client: &Client,
+    ) -> Option<Message> {
+        loop {
+            service.run_timers(client);
+            let timeout = match client.next_scheduled() {
+                Some(due) => due
+                    .saturating_duration_since(Instant::now())
+                    .min(EXPIRE_INTERVAL),
+                None => EXPIRE_INTERVAL,
+            };
+            match self.inner.connection.receiver.recv_timeout(timeout) {
+                Ok(msg) => return Some(msg),
+                Err(RecvTimeoutError::Timeout) => continue,
+                Err(RecvTimeoutError::Disconnected) => return None,
+            }
+        }
+    }
+}
+
+fn document_uri(params: &Value) -> Option<String> {
+    params
+        .get("textDocument")?
+        .get("uri")?
+        .as_str()
+        .map(str::to_string)
 }
 
-impl LspServer for LspStdioServer {
-    fn send(&self, msg: Message) {
-        let _ = self.inner.connection.sender.send(msg).map_err(|e| {
-            eprintln!("Error: {}", e);
-        });
+/// Answers a request, handler errors are sent back as JSON-RPC error responses so that the
+/// client never waits for a response that will not come.
+fn send_response(sender: &Sender<Message>, id: RequestId, result: jsonrpc::Result<Option<Value>>) {
+    let resp = match result {
+        Ok(result) => Response {
+            id,
+            result: Some(result.unwrap_or(Value::Null)),
+            error: None,
+        },
+        Err(e) => Response {
+            id,
+            result: None,
+            error: Some(e.into()),
+        },
+    };
+    if let Err(e) = sender.send(Message::Response(resp)) {
+        eprintln!("Error sending request's response: {}", e);
     }
 }
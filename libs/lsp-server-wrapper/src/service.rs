@@ -3,9 +3,10 @@ mod state;
 pub(crate) use self::state::{ServerState, State};
 pub use crate::client::Client;
 use crate::{jsonrpc, LanguageServer};
-use lsp_server::RequestId;
+use lsp_server::{RequestId, Response};
 use lsp_types::request::*;
 use lsp_types::*;
+use serde::de::DeserializeOwned;
 use std::cell::RefCell;
 use std::error::Error;
 use std::rc::Rc;
@@ -26,7 +27,10 @@ impl<S: LanguageServer> LspService<S> {
     where
         F: FnOnce(Rc<RefCell<Client>>) -> S,
     {
-        let backend = init(client);
+        Self::with_backend(init(client))
+    }
+
+    pub(crate) fn with_backend(backend: S) -> Self {
         LspService {
             state: Arc::new(ServerState::new()),
             inner: InnerService {
@@ -39,227 +43,238 @@ impl<S: LanguageServer> LspService<S> {
         &self,
         method: &str,
         params: serde_json::Value,
-    ) -> Result<Option<serde_json::Value>, Box<dyn Error>> {
+    ) -> jsonrpc::Result<Option<serde_json::Value>> {
+        match (method, self.state.get()) {
+            ("initialize", State::Uninitialized) => {}
+            ("initialize", _) => return Err(jsonrpc::Error::invalid_request()),
+            (_, State::Uninitialized | State::Initializing) => {
+                return Err(jsonrpc::not_initialized_error())
+            }
+            (_, State::ShutDown | State::Exited) => return Err(jsonrpc::Error::invalid_request()),
+            (_, State::Initialized) => {}
+        }
+
         let ret = match method {
             "initialize" => {
                 self.state.set(State::Initializing);
-                let params: InitializeParams = serde_json::from_value(params).unwrap();
-                serde_json::to_value(self.inner.backend.initialize(params)?)
+                let params: InitializeParams = parse_params(params)?;
+                // A failed initialization can be retried by the client
+                let result = self.inner.backend.initialize(params).map_err(|e| {
+                    self.state.set(State::Uninitialized);
+                    e
+                })?;
+                serde_json::to_value(result)
             }
             "shutdown" => {
                 self.state.set(State::ShutDown);
                 serde_json::to_value(self.inner.backend.shutdown()?)
             }
             "textDocument/willSaveWaitUntil" => {
-                let params: WillSaveTextDocumentParams = serde_json::from_value(params).unwrap();
+                let params: WillSaveTextDocumentParams = parse_params(params)?;
                 serde_json::to_value(self.inner.backend.will_save_wait_until(params)?)
             }
             "textDocument/declaration" => {
-                let params: GotoDeclarationParams = serde_json::from_value(params).unwrap();
+                let params: GotoDeclarationParams = parse_params(params)?;
                 serde_json::to_value(self.inner.backend.goto_declaration(params)?)
             }
             "textDocument/definition" => {
-                let params: GotoDefinitionParams = serde_json::from_value(params).unwrap();
+                let params: GotoDefinitionParams = parse_params(params)?;
                 serde_json::to_value(self.inner.backend.goto_definition(params)?)
             }
             "textDocument/typeDefinition" => {
-                let params: GotoTypeDefinitionParams = serde_json::from_value(params).unwrap();
+                let params: GotoTypeDefinitionParams = parse_params(params)?;
                 serde_json::to_value(self.inner.backend.goto_type_definition(params)?)
             }
             "textDocument/implementation" => {
-                let params: GotoImplementationParams = serde_json::from_value(params).unwrap();
+                let params: GotoImplementationParams = parse_params(params)?;
                 serde_json::to_value(self.inner.backend.goto_implementation(params)?)
             }
             "textDocument/references" => {
-                let params: ReferenceParams = serde_json::from_value(params).unwrap();
+                let params: ReferenceParams = parse_params(params)?;
                 serde_json::to_value(self.inner.backend.references(params)?)
             }
             "textDocument/prepareCallHierarchy" => {
-                let params: CallHierarchyPrepareParams = serde_json::from_value(params).unwrap();
+                let params: CallHierarchyPrepareParams = parse_params(params)?;
                 serde_json::to_value(self.inner.backend.prepare_call_hierarchy(params)?)
             }
             "textDocument/incomingCalls" => {
-                let params: CallHierarchyIncomingCallsParams =
-                    serde_json::from_value(params).unwrap();
+                let params: CallHierarchyIncomingCallsParams = parse_params(params)?;
                 serde_json::to_value(self.inner.backend.incoming_calls(params)?)
             }
             "textDocument/outgoingCalls" => {
-                let params: CallHierarchyOutgoingCallsParams =
-                    serde_json::from_value(params).unwrap();
+                let params: CallHierarchyOutgoingCallsParams = parse_params(params)?;
                 serde_json::to_value(self.inner.backend.outgoing_calls(params)?)
             }
             "textDocument/prepareTypeHierarchy" => {
-                let params: TypeHierarchyPrepareParams = serde_json::from_value(params).unwrap();
+                let params: TypeHierarchyPrepareParams = parse_params(params)?;
                 serde_json::to_value(self.inner.backend.prepare_type_hierarchy(params)?)
             }
             "textDocument/supertypes" => {
-                let params: TypeHierarchySupertypesParams = serde_json::from_value(params).unwrap();
+                let params: TypeHierarchySupertypesParams = parse_params(params)?;
                 serde_json::to_value(self.inner.backend.supertypes(params)?)
             }
             "textDocument/subtypes" => {
-                let params: TypeHierarchySubtypesParams = serde_json::from_value(params).unwrap();
+                let params: TypeHierarchySubtypesParams = parse_params(params)?;
                 serde_json::to_value(self.inner.backend.subtypes(params)?)
             }
             "textDocument/documentHighlight" => {
-                let params: DocumentHighlightParams = serde_json::from_value(params).unwrap();
+                let params: DocumentHighlightParams = parse_params(params)?;
                 serde_json::to_value(self.inner.backend.document_highlight(params)?)
             }
             "textDocument/documentLink" => {
-                let params: DocumentLinkParams = serde_json::from_value(params).unwrap();
+                let params: DocumentLinkParams = parse_params(params)?;
                 serde_json::to_value(self.inner.backend.document_link(params)?)
             }
             "documentLink/resolve" => {
-                let params: DocumentLink = serde_json::from_value(params).unwrap();
+                let params: DocumentLink = parse_params(params)?;
                 serde_json::to_value(self.inner.backend.document_link_resolve(params)?)
             }
             "textDocument/hover" => {
-                let params: HoverParams = serde_json::from_value(params).unwrap();
+                let params: HoverParams = parse_params(params)?;
                 serde_json::to_value(self.inner.backend.hover(params)?)
             }
             "textDocument/codeLens" => {
-                let params: CodeLensParams = serde_json::from_value(params).unwrap();
+                let params: CodeLensParams = parse_params(params)?;
                 serde_json::to_value(self.inner.backend.code_lens(params)?)
             }
             "codeLens/resolve" => {
-                let params: CodeLens = serde_json::from_value(params).unwrap();
+                let params: CodeLens = parse_params(params)?;
                 serde_json::to_value(self.inner.backend.code_lens_resolve(params)?)
             }
             "textDocument/foldingRange" => {
-                let params: FoldingRangeParams = serde_json::from_value(params).unwrap();
+                let params: FoldingRangeParams = parse_params(params)?;
                 serde_json::to_value(self.inner.backend.folding_range(params)?)
             }
             "textDocument/selectionRange" => {
-                let params: SelectionRangeParams = serde_json::from_value(params).unwrap();
+                let params: SelectionRangeParams = parse_params(params)?;
                 serde_json::to_value(self.inner.backend.selection_range(params)?)
             }
             "textDocument/documentSymbol" => {
-                let params: DocumentSymbolParams = serde_json::from_value(params).unwrap();
+                let params: DocumentSymbolParams = parse_params(params)?;
                 serde_json::to_value(self.inner.backend.document_symbol(params)?)
             }
             "textDocument/sementicTokens/full" => {
-                let params: SemanticTokensParams = serde_json::from_value(params).unwrap();
+                let params: SemanticTokensParams = parse_params(params)?;
                 serde_json::to_value(self.inner.backend.semantic_tokens_full(params)?)
             }
             "textDocument/sementicTokens/full/delta" => {
-                let params: SemanticTokensDeltaParams = serde_json::from_value(params).unwrap();
+                let params: SemanticTokensDeltaParams = parse_params(params)?;
                 serde_json::to_value(self.inner.backend.semantic_tokens_full_delta(params)?)
             }
             "textDocument/sementicTokens/range" => {
-                let params: SemanticTokensRangeParams = serde_json::from_value(params).unwrap();
+                let params: SemanticTokensRangeParams = parse_params(params)?;
                 serde_json::to_value(self.inner.backend.semantic_tokens_range(params)?)
             }
             "textDocument/inlineValue" => {
-                let params: InlineValueParams = serde_json::from_value(params).unwrap();
+                let params: InlineValueParams = parse_params(params)?;
                 serde_json::to_value(self.inner.backend.inline_value(params)?)
             }
             "textDocument/inlayHint" => {
-                let params: InlayHintParams = serde_json::from_value(params).unwrap();
+                let params: InlayHintParams = parse_params(params)?;
                 serde_json::to_value(self.inner.backend.inlay_hint(params)?)
             }
             "inlayHint/resolve" => {
-                let params: InlayHint = serde_json::from_value(params).unwrap();
+                let params: InlayHint = parse_params(params)?;
                 serde_json::to_value(self.inner.backend.inlay_hint_resolve(params)?)
             }
             "textDocument/moniker" => {
-                let params: MonikerParams = serde_json::from_value(params).unwrap();
+                let params: MonikerParams = parse_params(params)?;
                 serde_json::to_value(self.inner.backend.moniker(params)?)
             }
             "textDocument/completion" => {
-                let params: CompletionParams = serde_json::from_value(params).unwrap();
+                let params: CompletionParams = parse_params(params)?;
                 serde_json::to_value(self.inner.backend.completion(params)?)
             }
             "completionItem/resolve" => {
-                let params: CompletionItem = serde_json::from_value(params).unwrap();
+                let params: CompletionItem = parse_params(params)?;
                 serde_json::to_value(self.inner.backend.completion_resolve(params)?)
             }
             "textDocument/diagnostic" => {
-                let params: DocumentDiagnosticParams = serde_json::from_value(params).unwrap();
+                let params: DocumentDiagnosticParams = parse_params(params)?;
                 serde_json::to_value(self.inner.backend.diagnostic(params)?)
             }
             "workspace/diagnostic" => {
-                let params: WorkspaceDiagnosticParams = serde_json::from_value(params).unwrap();
+                let params: WorkspaceDiagnosticParams = parse_params(params)?;
                 serde_json::to_value(self.inner.backend.workspace_diagnostic(params)?)
             }
             "textDocument/signatureHelp" => {
-                let params: SignatureHelpParams = serde_json::from_value(params).unwrap();
+                let params: SignatureHelpParams = parse_params(params)?;
                 serde_json::to_value(self.inner.backend.signature_help(params)?)
             }
             "textDocument/codeAction" => {
-                let params: CodeActionParams = serde_json::from_value(params).unwrap();
+                let params: CodeActionParams = parse_params(params)?;
                 serde_json::to_value(self.inner.backend.code_action(params)?)
             }
             "codeAction/resolve" => {
-                let params: CodeAction = serde_json::from_value(params).unwrap();
+                let params: CodeAction = parse_params(params)?;
                 serde_json::to_value(self.inner.backend.code_action_resolve(params)?)
             }
             "textDocument/documentColor" => {
-                let params: DocumentColorParams = serde_json::from_value(params).unwrap();
+                let params: DocumentColorParams = parse_params(params)?;
                 serde_json::to_value(self.inner.backend.document_color(params)?)
             }
             "textDocument/colorPresentation" => {
-                let params: ColorPresentationParams = serde_json::from_value(params).unwrap();
+                let params: ColorPresentationParams = parse_params(params)?;
                 serde_json::to_value(self.inner.backend.color_presentation(params)?)
             }
             "textDocument/formatting" => {
-                let params: DocumentFormattingParams = serde_json::from_value(params).unwrap();
+                let params: DocumentFormattingParams = parse_params(params)?;
                 serde_json::to_value(self.inner.backend.formatting(params)?)
             }
             "textDocument/rangeFormatting" => {
-                let params: DocumentRangeFormattingParams = serde_json::from_value(params).unwrap();
+                let params: DocumentRangeFormattingParams = parse_params(params)?;
                 serde_json::to_value(self.inner.backend.range_formatting(params)?)
             }
             "textDocument/onTypeFormatting" => {
-                let params: DocumentOnTypeFormattingParams =
-                    serde_json::from_value(params).unwrap();
+                let params: DocumentOnTypeFormattingParams = parse_params(params)?;
                 serde_json::to_value(self.inner.backend.on_type_formatting(params)?)
             }
             "textDocument/rename" => {
-                let params: RenameParams = serde_json::from_value(params).unwrap();
+                let params: RenameParams = parse_params(params)?;
                 serde_json::to_value(self.inner.backend.rename(params)?)
             }
             "textDocument/prepareRename" => {
-                let params: TextDocumentPositionParams = serde_json::from_value(params).unwrap();
+                let params: TextDocumentPositionParams = parse_params(params)?;
                 serde_json::to_value(self.inner.backend.prepare_rename(params)?)
             }
             "textDocument/linkedEditingRange" => {
-                let params: LinkedEditingRangeParams = serde_json::from_value(params).unwrap();
+                let params: LinkedEditingRangeParams = parse_params(params)?;
                 serde_json::to_value(self.inner.backend.linked_editing_range(params)?)
             }
             "workspace/symbol" => {
-                let params: WorkspaceSymbolParams = serde_json::from_value(params).unwrap();
+                let params: WorkspaceSymbolParams = parse_params(params)?;
                 serde_json::to_value(self.inner.backend.symbol(params)?)
             }
             "workspaceSymbol/resolve" => {
-                let params: WorkspaceSymbol = serde_json::from_value(params).unwrap();
+                let params: WorkspaceSymbol = parse_params(params)?;
                 serde_json::to_value(self.inner.backend.symbol_resolve(params)?)
             }
             "workspace/willCreateFiles" => {
-                let params: CreateFilesParams = serde_json::from_value(params).unwrap();
+                let params: CreateFilesParams = parse_params(params)?;
                 serde_json::to_value(self.inner.backend.will_create_files(params)?)
             }
             "workspace/willRenameFiles" => {
-                let params: RenameFilesParams = serde_json::from_value(params).unwrap();
+                let params: RenameFilesParams = parse_params(params)?;
                 serde_json::to_value(self.inner.backend.will_rename_files(params)?)
             }
             "workspace/willDeleteFiles" => {
-                let params: DeleteFilesParams = serde_json::from_value(params).unwrap();
+                let params: DeleteFilesParams = parse_params(params)?;
                 serde_json::to_value(self.inner.backend.will_delete_files(params)?)
             }
             "workspace/executeCommand" => {
-                let params: ExecuteCommandParams = serde_json::from_value(params).unwrap();
+                let params: ExecuteCommandParams = parse_params(params)?;
                 serde_json::to_value(self.inner.backend.execute_command(params)?)
             }
-            "exit" => {
-                self.state.set(State::Exited);
-                return Ok(None);
-            }
             _ => {
-                return Err(Box::new(jsonrpc::Error::method_not_found()).into());
+                return Err(jsonrpc::Error::method_not_found());
             }
         }
         .map_err(|e| {
             eprintln!("Error: {}", e);
-            Box::new(e)
+            jsonrpc::Error {
+                message: e.to_string().into(),
+                ..jsonrpc::Error::internal_error()
+            }
         })?;
         Ok(Some(ret))
     }
@@ -270,6 +285,9 @@ impl<S: LanguageServer> LspService<S> {
         params: serde_json::Value,
     ) -> Result<(), Box<dyn Error>> {
         match method {
+            "exit" => {
+                self.state.set(State::Exited);
+            }
             "initialized" => {
                 self.state.set(State::Initialized);
                 let params: InitializedParams = serde_json::from_value(params).unwrap();
@@ -320,6 +338,8 @@ impl<S: LanguageServer> LspService<S> {
                 let params: DidChangeWatchedFilesParams = serde_json::from_value(params).unwrap();
                 self.inner.backend.did_change_watched_files(params)
             }
+            // Protocol notifications like `$/cancelRequest` can be ignored
+            method if method.starts_with("$/") => {}
             _ => {
                 return Err("Method not found".to_owned().into());
             }
@@ -331,3 +351,24 @@ impl<S: LanguageServer> LspService<S> {
         self.inner.backend.on_response(id, result)
     }
 }
+
+impl<S: LanguageServer + 'static> LspService<S> {
+    /// Resolves the request of `client` answered by `resp`, other responses are passed to
+    /// [`LanguageServer::on_response`].
+    pub(crate) fn handle_response(&self, client: &Client, resp: Response) {
+        if let Some(resp) = client.complete_request(resp, &*self.inner.backend) {
+            self.call_response(resp.id, resp.result);
+        }
+    }
+
+    /// Fails the requests of `client` which were not answered in time and runs the tasks which
+    /// are due.
+    pub(crate) fn run_timers(&self, client: &Client) {
+        client.expire_requests(&*self.inner.backend);
+        client.run_scheduled(&*self.inner.backend);
+    }
+}
+
+fn parse_params<P: DeserializeOwned>(params: serde_json::Value) -> jsonrpc::Result<P> {
+    serde_json::from_value(params).map_err(|e| jsonrpc::Error::invalid_params(e.to_string()))
+}
@@ -0,0 +1,244 @@
+//! A language client driving a server end-to-end, for tests.
+
+use crate::LspStdioServer;
+use lsp_server::{Connection, ErrorCode, Message, RequestId, Response, ResponseError};
+use lsp_types::notification::{Cancel, Exit, Initialized, Notification, PublishDiagnostics};
+use lsp_types::request::{Initialize, Request, Shutdown};
+use lsp_types::*;
+use serde_json::Value;
+use std::collections::{HashMap, VecDeque};
+use std::error::Error;
+use std::rc::Rc;
+use std::thread::{self, JoinHandle};
+use std::time::Duration;
+
+const DEFAULT_TIMEOUT: Duration = Duration::from_secs(10);
+
+type RequestHandler = Box<dyn Fn(Value) -> Result<Value, ResponseError>>;
+
+/// Client side of a connection to a language server.
+///
+/// Requests and notifications are sent with their `lsp_types` definitions. While waiting for a
+/// response, the messages sent by the server are recorded: notifications are kept, published
+/// diagnostics are queued for [`wait_for_diagnostics`](Self::wait_for_diagnostics) and the
+/// server's requests are answered by the handlers registered with
+/// [`on_request`](Self::on_request).
+///
+/// Every method panics when the server does not answer in time, like an assertion would.
+pub struct LspTestClient {
+    connection: Connection,
+    server: Option<JoinHandle<bool>>,
+    next_id: i32,
+    timeout: Duration,
+    responses: HashMap<RequestId, Response>,
+    notifications: Vec<lsp_server::Notification>,
+    diagnostics: VecDeque<PublishDiagnosticsParams>,
+    request_handlers: HashMap<String, RequestHandler>,
+}
+
+impl LspTestClient {
+    /// Creates a client over the client side of a connection, the server runs elsewhere.
+    pub fn new(connection: Connection) -> Self {
+        LspTestClient {
+            connection,
+            server: None,
+            next_id: 0,
+            timeout: DEFAULT_TIMEOUT,
+            responses: HashMap::new(),
+            notifications: Vec::new(),
+            diagnostics: VecDeque::new(),
+            request_handlers: HashMap::new(),
+        }
+    }
+
+    /// Runs `serve` on a new thread with a server over an in-memory connection, and returns the
+    /// client connected to it.
+    ///
+    /// ```ignore
+    /// let client = LspTestClient::start(|server| LspStdioServer::serve(server, Backend::new));
+    /// ```
+    pub fn start<F>(serve: F) -> Self
+    where
+        F: FnOnce(Rc<LspStdioServer>) -> Result<(), Box<dyn Error>> + Send + 'static,
+    {
+        let (server, client) = Connection::memory();
+        let mut test_client = LspTestClient::new(client);
+        test_client.server = Some(thread::spawn(move || {
+            serve(LspStdioServer::with_connection(server, None))
+                .map_err(|e| eprintln!("Server error: {}", e))
+                .is_ok()
+        }));
+        test_client
+    }
+
+    pub fn set_timeout(&mut self, timeout: Duration) {
+        self.timeout = timeout;
+    }
+
+    /// Performs the initialize handshake.
+    pub fn initialize(&mut self, params: InitializeParams) -> InitializeResult {
+        let result = self
+            .request::<Initialize>(params)
+            .unwrap_or_else(|e| panic!("initialize failed: {}", e.message));
+        self.notify::<Initialized>(InitializedParams {});
+        result
+    }
+
+    /// Sends a request and waits for its response.
+    pub fn request<R: Request>(&mut self, params: R::Params) -> Result<R::Result, ResponseError> {
+        let id = self.send_request::<R>(params);
+        let resp = self.recv_response(&id);
+        match resp.error {
+            Some(error) => Err(error),
+            None => Ok(
+                serde_json::from_value(resp.result.unwrap_or(Value::Null)).unwrap_or_else(|e| {
+                    panic!("invalid {} result: {}", R::METHOD, e);
+                }),
+            ),
+        }
+    }
+
+    pub fn send_request<R: Request>(&mut self, params: R::Params) -> RequestId {
+        self.send_raw_request(R::METHOD, serde_json::to_value(params).unwrap())
+    }
+
+    /// Sends a request with untyped params, to test how the server handles invalid ones.
+    pub fn send_raw_request(&mut self, method: &str, params: Value) -> RequestId {
+        self.next_id += 1;
+        let id = RequestId::from(self.next_id);
+        self.send(Message::Request(lsp_server::Request::new(
+            id.clone(),
+            method.to_string(),
+            params,
+        )));
+        id
+    }
+
+    /// Waits for the response to the request `id`.
+    pub fn recv_response(&mut self, id: &RequestId) -> Response {
+        loop {
+            if let Some(resp) = self.responses.remove(id) {
+                return resp;
+            }
+            self.recv();
+        }
+    }
+
+    /// Sends a `$/cancelRequest` notification for the request `id`.
+    pub fn cancel(&mut self, id: &RequestId) {
+        let id = serde_json::from_value(serde_json::to_value(id).unwrap()).unwrap();
+        self.notify::<Cancel>(CancelParams { id });
+    }
+
+    pub fn notify<N: Notification>(&mut self, params: N::Params) {
+        self.send_raw_notification(N::METHOD, serde_json::to_value(params).unwrap());
+    }
+
+    pub fn send_raw_notification(&mut self, method: &str, params: Value) {
+        self.send(Message::Notification(lsp_server::Notification::new(
+            method.to_string(),
+            params,
+        )));
+    }
+
+    /// Answers the requests `R` sent by the server with `handler`. Requests without handler are
+    /// answered with a `MethodNotFound` error.
+    pub fn on_request<R: Request>(&mut self, handler: impl Fn(R::Params) -> R::Result + 'static) {
+        self.request_handlers.insert(
+            R::METHOD.to_string(),
+            Box::new(move |params| {
+                let params = serde_json::from_value(params).map_err(|e| ResponseError {
+                    code: ErrorCode::InvalidParams as i32,
+                    message: e.to_string(),
+                    data: None,
+                })?;
+                Ok(serde_json::to_value(handler(params)).unwrap())
+            }),
+        );
+    }
+
+    /// The notifications `N` received so far.
+    pub fn notifications<N: Notification>(&self) -> Vec<N::Params> {
+        self.notifications
+            .iter()
+            .filter(|not| not.method == N::METHOD)
+            .filter_map(|not| serde_json::from_value(not.params.clone()).ok())
+            .collect()
+    }
+
+    /// Waits for the next diagnostics published for `uri`.
+    pub fn wait_for_diagnostics(&mut self, uri: &Url) -> PublishDiagnosticsParams {
+        loop {
+            if let Some(index) = self.diagnostics.iter().position(|diags| &diags.uri == uri) {
+                return self.diagnostics.remove(index).unwrap();
+            }
+            self.recv();
+        }
+    }
+
+    /// Sends the `shutdown` request and the `exit` notification, and returns whether the server
+    /// exited cleanly.
+    pub fn shutdown(mut self) -> bool {
+        if let Err(e) = self.request::<Shutdown>(()) {
+            panic!("shutdown failed: {}", e.message);
+        }
+        self.exit()
+    }
+
+    /// Sends the `exit` notification, and returns whether the server exited cleanly.
+    pub fn exit(mut self) -> bool {
+        self.notify::<Exit>(());
+        match self.server.take() {
+            Some(server) => server.join().unwrap_or(false),
+            None => true,
+        }
+    }
+
+    fn send(&self, msg: Message) {
+        self.connection
+            .sender
+            .send(msg)
+            .expect("the server closed the connection");
+    }
+
+    fn recv(&mut self) {
+        let msg = self
+            .connection
+            .receiver
+            .recv_timeout(self.timeout)
+            .unwrap_or_else(|e| panic!("no message from the server: {}", e));
+
+        match msg {
+            Message::Response(resp) => {
+                self.responses.insert(resp.id.clone(), resp);
+            }
+            Message::Notification(not) => {
+                if not.method == PublishDiagnostics::METHOD {
+                    if let Ok(diags) = serde_json::from_value(not.params.clone()) {
+                        self.diagnostics.push_back(diags);
+                    }
+                }
+                self.notifications.push(not);
+            }
+            Message::Request(req) => {
+                let result = match self.request_handlers.get(&req.method) {
+                    Some(handler) => handler(req.params),
+                    None => Err(ResponseError {
+                        code: ErrorCode::MethodNotFound as i32,
+                        message: format!("no handler for {}", req.method),
+                        data: None,
+                    }),
+                };
+                let resp = match result {
+                    Ok(result) => Response::new_ok(req.id, result),
+                    Err(error) => Response {
+                        id: req.id,
+                        result: None,
+                        error: Some(error),
+                    },
+                };
+                self.send(Message::Response(resp));
+            }
+        }
+    }
+}
@@ -0,0 +1,84 @@
+//! Threads running the handlers of [`LspStdioServer::serve_concurrent`].
+//!
+//! [`LspStdioServer::serve_concurrent`]: crate::LspStdioServer::serve_concurrent
+
+use crossbeam_channel::{select, unbounded, Receiver, Sender};
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::thread::{self, JoinHandle};
+
+type Job = Box<dyn FnOnce() + Send>;
+
+/// Each worker owns a lane and shares a common queue with the others. Jobs pushed to the same
+/// lane run one after the other, in order, while the jobs of the common queue run on the first
+/// free worker.
+pub(crate) struct WorkerPool {
+    lanes: Vec<Sender<Job>>,
+    shared: Sender<Job>,
+    workers: Vec<JoinHandle<()>>,
+}
+
+impl WorkerPool {
+    pub fn new(size: usize) -> Self {
+        let size = size.max(1);
+        let (shared, shared_receiver) = unbounded::<Job>();
+        let mut lanes = Vec::with_capacity(size);
+        let mut workers = Vec::with_capacity(size);
+
+        for i in 0..size {
+            let (lane, lane_receiver) = unbounded::<Job>();
+            let shared_receiver = shared_receiver.clone();
+            lanes.push(lane);
+            workers.push(
+                thread::Builder::new()
+                    .name(format!("lsp-worker-{}", i))
+                    .spawn(move || work(lane_receiver, shared_receiver))
+                    .expect("failed to spawn a lsp worker"),
+            );
+        }
+        WorkerPool {
+            lanes,
+            shared,
+            workers,
+        }
+    }
+
+    /// Runs the job on the first free worker.
+    pub fn execute(&self, job: impl FnOnce() + Send + 'static) {
+        let _ = self.shared.send(Box::new(job));
+    }
+
+    /// Runs the job after every job previously pushed with the same key.
+    pub fn execute_ordered(&self, key: &str, job: impl FnOnce() + Send + 'static) {
+        let mut hasher = DefaultHasher::new();
+        key.hash(&mut hasher);
+        let lane = hasher.finish() as usize % self.lanes.len();
+        let _ = self.lanes[lane].send(Box::new(job));
+    }
+
+    /// Waits for the queued jobs to complete.
+    pub fn join(self) {
+        drop(self.lanes);
+        drop(self.shared);
+        for worker in self.workers {
+            let _ = worker.join();
+        }
+    }
+}
+
+fn work(lane: Receiver<Job>, shared: Receiver<Job>) {
+    loop {
+        // Once one of the queues is closed, the other one is drained before stopping
+        let job = select! {
+            recv(lane) -> job => job.or_else(|_| shared.recv()),
+            recv(shared) -> job => job.or_else(|_| lane.recv()),
+        };
+        let Ok(job) = job else {
+            break;
+        };
+        if catch_unwind(AssertUnwindSafe(job)).is_err() {
+            eprintln!("A lsp handler panicked");
+        }
+    }
+}
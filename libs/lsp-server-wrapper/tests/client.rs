@@ -0,0 +1,216 @@
+use osmium_libs_lsp_server_wrapper::lsp_types::notification::*;
+use osmium_libs_lsp_server_wrapper::lsp_types::request::*;
+use osmium_libs_lsp_server_wrapper::lsp_types::*;
+use osmium_libs_lsp_server_wrapper::{
+    Client, LanguageServer, LspStdioServer, LspTestClient, Result,
+};
+use serde_json::{json, Value};
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::thread;
+use std::time::Duration;
+
+const METHOD_NOT_FOUND: i32 = -32601;
+const REQUEST_CANCELLED: i32 = -32800;
+
+struct ContentRequest;
+
+impl Request for ContentRequest {
+    type Params = TextDocumentIdentifier;
+    type Result = String;
+    const METHOD: &'static str = "osmium/getContent";
+}
+
+// Reports the responses of the client as diagnostics of the opened document
+struct Backend {
+    client: Rc<RefCell<Client>>,
+}
+
+impl Backend {
+    fn report(&self, uri: Url, message: String) {
+        let diag = Diagnostic::new_simple(Range::default(), message);
+        self.client
+            .borrow()
+            .publish_diagnostics(uri, vec![diag], None);
+    }
+}
+
+impl LanguageServer for Backend {
+    fn initialize(&self, _: InitializeParams) -> Result<InitializeResult> {
+        Ok(InitializeResult::default())
+    }
+
+    fn shutdown(&self) -> Result<()> {
+        Ok(())
+    }
+
+    fn did_open(&self, params: DidOpenTextDocumentParams) {
+        let uri = params.text_document.uri;
+        let client = self.client.borrow();
+        if params.text_document.language_id == "scheduled" {
+            client.schedule::<Backend>(Duration::from_millis(50), move |backend| {
+                backend.report(uri, "scheduled".to_string())
+            });
+            return;
+        }
+        let sent = match params.text_document.language_id.as_str() {
+            "configuration" => client.send_request_with::<WorkspaceConfiguration, Backend>(
+                ConfigurationParams {
+                    items: vec![ConfigurationItem {
+                        scope_uri: None,
+                        section: Some("osmium".to_string()),
+                    }],
+                },
+                move |backend, result| {
+                    let message = match result {
+                        Ok(values) => Value::Array(values).to_string(),
+                        Err(e) => e.to_string(),
+                    };
+                    backend.report(uri, message)
+                },
+            ),
+            _ => client.send_request_with::<ContentRequest, Backend>(
+                TextDocumentIdentifier { uri: uri.clone() },
+                move |backend, result| {
+                    let message = match result {
+                        Ok(content) => content,
+                        Err(e) => e.code.to_string(),
+                    };
+                    backend.report(uri, message)
+                },
+            ),
+        };
+        assert!(sent.is_ok());
+    }
+}
+
+fn start(timeout: Duration) -> LspTestClient {
+    let mut client = LspTestClient::start(move |server| {
+        LspStdioServer::serve(server, |client: Rc<RefCell<Client>>| {
+            client.borrow_mut().set_request_timeout(timeout);
+            Backend { client }
+        })
+    });
+    client.initialize(InitializeParams::default());
+    client
+}
+
+fn uri() -> Url {
+    Url::parse("file:///project/a.sol").unwrap()
+}
+
+fn open(client: &mut LspTestClient, language_id: &str) -> String {
+    client.notify::<DidOpenTextDocument>(DidOpenTextDocumentParams {
+        text_document: TextDocumentItem::new(uri(), language_id.to_string(), 1, String::new()),
+    });
+    client.wait_for_diagnostics(&uri()).diagnostics[0]
+        .message
+        .clone()
+}
+
+#[test]
+fn responses_are_given_to_their_callback() {
+    let mut client = start(Duration::from_secs(10));
+    client.on_request::<WorkspaceConfiguration>(|params| {
+        assert_eq!(params.items[0].section.as_deref(), Some("osmium"));
+        vec![json!({ "linter": true })]
+    });
+    client.on_request::<ContentRequest>(|params| params.uri.path().to_string());
+
+    assert_eq!(open(&mut client, "configuration"), r#"[{"linter":true}]"#);
+    assert_eq!(open(&mut client, "solidity"), "/project/a.sol");
+
+    assert!(client.shutdown());
+}
+
+#[test]
+fn error_responses_fail_the_request() {
+    let mut client = start(Duration::from_secs(10));
+
+    assert_eq!(open(&mut client, "solidity"), METHOD_NOT_FOUND.to_string());
+
+    assert!(client.shutdown());
+}
+
+#[test]
+fn unanswered_requests_time_out() {
+    let mut client = start(Duration::from_millis(100));
+    client.on_request::<ContentRequest>(|_| {
+        thread::sleep(Duration::from_millis(500));
+        String::new()
+    });
+
+    assert_eq!(open(&mut client, "solidity"), REQUEST_CANCELLED.to_string());
+    // The request is cancelled on the client side too
+    let cancelled = client.notifications::<Cancel>();
+    assert_eq!(cancelled.len(), 1);
+
+    assert!(client.shutdown());
+}
+
+#[test]
+fn scheduled_tasks_run_with_the_backend() {
+    let mut client = start(Duration::from_secs(10));
+
+    assert_eq!(open(&mut client, "scheduled"), "scheduled");
+
+    assert!(client.shutdown());
+}
+
+struct ConcurrentBackend {
+    client: Client,
+}
+
+impl LanguageServer for ConcurrentBackend {
+    fn initialize(&self, _: InitializeParams) -> Result<InitializeResult> {
+        Ok(InitializeResult::default())
+    }
+
+    fn shutdown(&self) -> Result<()> {
+        Ok(())
+    }
+
+    // Asks the user which action to run, and answers with it
+    fn hover(&self, _: HoverParams) -> Result<Option<Hover>> {
+        let actions = ["Fix", "Ignore"].map(|title| MessageActionItem {
+            title: title.to_string(),
+            properties: Default::default(),
+        });
+        let action = self
+            .client
+            .show_message_request(MessageType::INFO, "Fix it?", Some(actions.to_vec()))?
+            .wait()?;
+        Ok(action.map(|action| Hover {
+            contents: HoverContents::Scalar(MarkedString::String(action.title)),
+            range: None,
+        }))
+    }
+}
+
+#[test]
+fn handlers_can_wait_for_responses_on_workers() {
+    let mut client = LspTestClient::start(|server| {
+        LspStdioServer::serve_concurrent(server, 2, |client| ConcurrentBackend { client })
+    });
+    client.initialize(InitializeParams::default());
+    client.on_request::<ShowMessageRequest>(|params| {
+        params.actions.and_then(|actions| actions.last().cloned())
+    });
+
+    let hover = client
+        .request::<HoverRequest>(HoverParams {
+            text_document_position_params: TextDocumentPositionParams {
+                text_document: TextDocumentIdentifier { uri: uri() },
+                position: Position::default(),
+            },
+            work_done_progress_params: WorkDoneProgressParams::default(),
+        })
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        hover.contents,
+        HoverContents::Scalar(MarkedString::String("Ignore".to_string()))
+    );
+
+    assert!(client.shutdown());
+}
@@ -0,0 +1,166 @@
+use osmium_libs_lsp_server_wrapper::lsp_types::notification::*;
+use osmium_libs_lsp_server_wrapper::lsp_types::request::*;
+use osmium_libs_lsp_server_wrapper::lsp_types::*;
+use osmium_libs_lsp_server_wrapper::{
+    cancellation_token, Client, Error, LanguageServer, LspStdioServer, LspTestClient, Result,
+};
+use std::sync::Mutex;
+use std::thread;
+use std::time::{Duration, Instant};
+
+const WORKERS: usize = 4;
+const REQUEST_CANCELLED: i32 = -32800;
+
+#[derive(Default)]
+struct Backend {
+    log: Mutex<Vec<String>>,
+}
+
+impl LanguageServer for Backend {
+    fn initialize(&self, _: InitializeParams) -> Result<InitializeResult> {
+        Ok(InitializeResult::default())
+    }
+
+    fn shutdown(&self) -> Result<()> {
+        Ok(())
+    }
+
+    fn did_open(&self, params: DidOpenTextDocumentParams) {
+        thread::sleep(Duration::from_millis(100));
+        self.log
+            .lock()
+            .unwrap()
+            .push(format!("open {}", params.text_document.version));
+    }
+
+    fn did_change(&self, params: DidChangeTextDocumentParams) {
+        self.log
+            .lock()
+            .unwrap()
+            .push(format!("change {}", params.text_document.version));
+    }
+
+    // Runs until it is cancelled
+    fn goto_definition(&self, _: GotoDefinitionParams) -> Result<Option<GotoDefinitionResponse>> {
+        let start = Instant::now();
+        while start.elapsed() < Duration::from_secs(5) {
+            if cancellation_token().is_cancelled() {
+                return Err(Error::request_cancelled());
+            }
+            thread::sleep(Duration::from_millis(10));
+        }
+        Ok(None)
+    }
+
+    fn hover(&self, _: HoverParams) -> Result<Option<Hover>> {
+        Ok(Some(Hover {
+            contents: HoverContents::Markup(MarkupContent {
+                kind: MarkupKind::PlainText,
+                value: self.log.lock().unwrap().join(", "),
+            }),
+            range: None,
+        }))
+    }
+
+    fn symbol(&self, _: WorkspaceSymbolParams) -> Result<Option<Vec<SymbolInformation>>> {
+        Ok(Some(vec![]))
+    }
+}
+
+fn start() -> LspTestClient {
+    let mut client = LspTestClient::start(|server| {
+        LspStdioServer::serve_concurrent(server, WORKERS, |_: Client| Backend::default())
+    });
+    client.initialize(InitializeParams::default());
+    client
+}
+
+fn uri() -> Url {
+    Url::parse("file:///project/a.sol").unwrap()
+}
+
+fn position_params() -> TextDocumentPositionParams {
+    TextDocumentPositionParams {
+        text_document: TextDocumentIdentifier { uri: uri() },
+        position: Position::default(),
+    }
+}
+
+fn definition_params() -> GotoDefinitionParams {
+    GotoDefinitionParams {
+        text_document_position_params: position_params(),
+        work_done_progress_params: WorkDoneProgressParams::default(),
+        partial_result_params: PartialResultParams::default(),
+    }
+}
+
+fn symbol_params() -> WorkspaceSymbolParams {
+    WorkspaceSymbolParams {
+        query: String::new(),
+        work_done_progress_params: WorkDoneProgressParams::default(),
+        partial_result_params: PartialResultParams::default(),
+    }
+}
+
+#[test]
+fn slow_requests_do_not_block_other_requests() {
+    let mut client = start();
+
+    let slow = client.send_request::<GotoDefinition>(definition_params());
+    let symbols = client.request::<WorkspaceSymbolRequest>(symbol_params());
+    assert!(symbols.is_ok());
+
+    client.cancel(&slow);
+    let error = client.recv_response(&slow).error.unwrap();
+    assert_eq!(error.code, REQUEST_CANCELLED);
+
+    assert!(client.shutdown());
+}
+
+#[test]
+fn cancelled_requests_are_answered_once() {
+    let mut client = start();
+
+    let slow = client.send_request::<GotoDefinition>(definition_params());
+    client.cancel(&slow);
+    let error = client.recv_response(&slow).error.unwrap();
+    assert_eq!(error.code, REQUEST_CANCELLED);
+
+    // Cancelling an answered request does nothing
+    client.cancel(&slow);
+    let symbols = client.request::<WorkspaceSymbolRequest>(symbol_params());
+    assert!(symbols.is_ok());
+
+    assert!(client.shutdown());
+}
+
+#[test]
+fn notifications_stay_ordered_per_document() {
+    let mut client = start();
+
+    client.notify::<DidOpenTextDocument>(DidOpenTextDocumentParams {
+        text_document: TextDocumentItem::new(uri(), "solidity".to_string(), 1, String::new()),
+    });
+    for version in 2..5 {
+        client.notify::<DidChangeTextDocument>(DidChangeTextDocumentParams {
+            text_document: VersionedTextDocumentIdentifier::new(uri(), version),
+            content_changes: vec![],
+        });
+    }
+
+    let hover = client
+        .request::<HoverRequest>(HoverParams {
+            text_document_position_params: position_params(),
+            work_done_progress_params: WorkDoneProgressParams::default(),
+        })
+        .unwrap()
+        .unwrap();
+    match hover.contents {
+        HoverContents::Markup(content) => {
+            assert_eq!(content.value, "open 1, change 2, change 3, change 4")
+        }
+        contents => panic!("unexpected hover {:?}", contents),
+    }
+
+    assert!(client.shutdown());
+}
@@ -0,0 +1,176 @@
+use osmium_libs_lsp_server_wrapper::lsp_types::request::*;
+use osmium_libs_lsp_server_wrapper::lsp_types::*;
+use osmium_libs_lsp_server_wrapper::{
+    Client, Connection, Error, LanguageServer, LspStdioServer, LspTestClient, Result,
+};
+use serde_json::{json, Value};
+use std::cell::RefCell;
+use std::net::TcpListener;
+use std::rc::Rc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+const METHOD_NOT_FOUND: i32 = -32601;
+const INVALID_REQUEST: i32 = -32600;
+const INVALID_PARAMS: i32 = -32602;
+const SERVER_NOT_INITIALIZED: i32 = -32002;
+
+struct Backend;
+
+impl LanguageServer for Backend {
+    fn initialize(&self, params: InitializeParams) -> Result<InitializeResult> {
+        match params.workspace_folders {
+            Some(_) => Ok(InitializeResult::default()),
+            None => Err(Error::invalid_params("a workspace folder is required")),
+        }
+    }
+
+    fn shutdown(&self) -> Result<()> {
+        Ok(())
+    }
+
+    fn hover(&self, _: HoverParams) -> Result<Option<Hover>> {
+        Err(Error::invalid_params("nothing to hover"))
+    }
+}
+
+fn start() -> LspTestClient {
+    LspTestClient::start(|server| LspStdioServer::serve(server, |_: Rc<RefCell<Client>>| Backend))
+}
+
+fn initialize_params() -> InitializeParams {
+    InitializeParams {
+        workspace_folders: Some(vec![WorkspaceFolder {
+            uri: Url::parse("file:///project").unwrap(),
+            name: "project".to_string(),
+        }]),
+        ..InitializeParams::default()
+    }
+}
+
+fn position_params() -> TextDocumentPositionParams {
+    TextDocumentPositionParams {
+        text_document: TextDocumentIdentifier {
+            uri: Url::parse("file:///project/file.sol").unwrap(),
+        },
+        position: Position::default(),
+    }
+}
+
+fn hover_params() -> HoverParams {
+    HoverParams {
+        text_document_position_params: position_params(),
+        work_done_progress_params: WorkDoneProgressParams::default(),
+    }
+}
+
+#[test]
+fn requests_before_initialize_are_rejected() {
+    let mut client = start();
+
+    let error = client.request::<HoverRequest>(hover_params()).unwrap_err();
+    assert_eq!(error.code, SERVER_NOT_INITIALIZED);
+
+    client.initialize(initialize_params());
+    assert!(client.shutdown());
+}
+
+#[test]
+fn failed_initialize_can_be_retried() {
+    let mut client = start();
+
+    let error = client
+        .request::<Initialize>(InitializeParams::default())
+        .unwrap_err();
+    assert_eq!(error.code, INVALID_PARAMS);
+    assert_eq!(error.message, "a workspace folder is required");
+
+    client.initialize(initialize_params());
+    assert!(client.shutdown());
+}
+
+#[test]
+fn handler_errors_are_returned() {
+    let mut client = start();
+    client.initialize(initialize_params());
+
+    let error = client.request::<HoverRequest>(hover_params()).unwrap_err();
+    assert_eq!(error.code, INVALID_PARAMS);
+    assert_eq!(error.message, "nothing to hover");
+
+    let id = client.send_raw_request(HoverRequest::METHOD, json!({ "position": "start" }));
+    let error = client.recv_response(&id).error.unwrap();
+    assert_eq!(error.code, INVALID_PARAMS);
+
+    assert!(client.shutdown());
+}
+
+#[test]
+fn unimplemented_methods_are_not_found() {
+    let mut client = start();
+    client.initialize(initialize_params());
+
+    let error = client
+        .request::<Completion>(CompletionParams {
+            text_document_position: position_params(),
+            work_done_progress_params: WorkDoneProgressParams::default(),
+            partial_result_params: PartialResultParams::default(),
+            context: None,
+        })
+        .unwrap_err();
+    assert_eq!(error.code, METHOD_NOT_FOUND);
+
+    let id = client.send_raw_request("osmium/unknown", Value::Null);
+    let error = client.recv_response(&id).error.unwrap();
+    assert_eq!(error.code, METHOD_NOT_FOUND);
+
+    assert!(client.shutdown());
+}
+
+#[test]
+fn lifecycle_requests_are_checked_against_the_server_state() {
+    let mut client = start();
+    client.initialize(initialize_params());
+
+    let error = client
+        .request::<Initialize>(initialize_params())
+        .unwrap_err();
+    assert_eq!(error.code, INVALID_REQUEST);
+
+    assert!(client.request::<Shutdown>(()).is_ok());
+
+    let error = client.request::<HoverRequest>(hover_params()).unwrap_err();
+    assert_eq!(error.code, INVALID_REQUEST);
+    let error = client.request::<Shutdown>(()).unwrap_err();
+    assert_eq!(error.code, INVALID_REQUEST);
+
+    assert!(client.exit());
+}
+
+#[test]
+fn serves_over_tcp() {
+    // Reserves a free port for the client to listen on
+    let addr = TcpListener::bind("127.0.0.1:0")
+        .unwrap()
+        .local_addr()
+        .unwrap();
+    let server = thread::spawn(move || {
+        let start = Instant::now();
+        let server = loop {
+            match LspStdioServer::connect(addr) {
+                Ok(server) => break server,
+                Err(e) if start.elapsed() > Duration::from_secs(5) => panic!("{}", e),
+                Err(_) => thread::sleep(Duration::from_millis(10)),
+            }
+        };
+        LspStdioServer::serve(server, |_: Rc<RefCell<Client>>| Backend).is_ok()
+    });
+    let (connection, _io_threads) = Connection::listen(addr).unwrap();
+    let mut client = LspTestClient::new(connection);
+
+    client.initialize(initialize_params());
+    let error = client.request::<HoverRequest>(hover_params()).unwrap_err();
+    assert_eq!(error.message, "nothing to hover");
+    assert!(client.shutdown());
+    assert!(server.join().unwrap());
+}
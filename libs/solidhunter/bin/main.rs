@@ -1,9 +1,52 @@
-use clap::{arg, Parser};
-use solidhunter::aggregate::aggregate_diags;
+use clap::{Parser, ValueEnum};
+use similar::TextDiff;
+use solidhunter::baseline::{Baseline, DEFAULT_BASELINE_FILE};
+use solidhunter::cache::{LintCache, DEFAULT_CACHE_FILE};
 use solidhunter::errors::SolidHunterError;
+use solidhunter::fix::FixedFile;
 use solidhunter::linter::SolidLinter;
+use solidhunter::metrics::format_metrics;
+use solidhunter::reporters::{
+    CheckstyleReporter, CompactReporter, GithubReporter, JsonReporter, JunitReporter, Reporter,
+    SarifReporter, TextReporter,
+};
+use solidhunter::rules::factory::RuleFactory;
 use solidhunter::rules::rule_impl::create_rules_file;
-use solidhunter::types::{FileDiags, LintResult};
+use solidhunter::types::{LintResult, Severity};
+use std::process::ExitCode;
+
+#[derive(ValueEnum, Clone, Copy, Debug, PartialEq)]
+enum OutputFormat {
+    Text,
+    Json,
+    Sarif,
+    Junit,
+    Checkstyle,
+    Github,
+    Compact,
+}
+
+#[derive(ValueEnum, Clone, Copy, Debug, PartialEq)]
+enum Deny {
+    Warnings,
+    Info,
+    Hints,
+}
+
+impl Deny {
+    fn is_denied(&self, severity: Severity) -> bool {
+        match self {
+            Deny::Warnings => severity == Severity::WARNING,
+            Deny::Info => matches!(severity, Severity::WARNING | Severity::INFO),
+            Deny::Hints => severity != Severity::ERROR,
+        }
+    }
+}
+
+// Exit codes
+const EXIT_SUCCESS: u8 = 0;
+const EXIT_LINT_FAILURE: u8 = 1;
+const EXIT_CONFIG_ERROR: u8 = 2;
 
 #[derive(Parser, Debug)]
 #[command(author, version, about, long_about = None)]
@@ -27,6 +70,15 @@ struct Args {
     )]
     to_json: bool,
 
+    #[arg(
+        short = 'f',
+        long = "format",
+        value_enum,
+        default_value_t = OutputFormat::Text,
+        help = "Output format"
+    )]
+    format: OutputFormat,
+
     #[arg(
         short = 'v',
         long = "verbose",
@@ -53,30 +105,211 @@ struct Args {
         help = "exposes rules documentation"
     )]
     documentation: bool,
+
+    #[arg(
+        long = "fix",
+        default_value = "false",
+        help = "Automatically fix problems when possible"
+    )]
+    fix: bool,
+
+    #[arg(
+        long = "fix-dry-run",
+        default_value = "false",
+        conflicts_with = "fix",
+        help = "Print the fixes as a diff without writing them"
+    )]
+    fix_dry_run: bool,
+
+    #[arg(
+        long = "max-warnings",
+        help = "Number of warnings allowed before exiting with a non-zero code"
+    )]
+    max_warnings: Option<usize>,
+
+    #[arg(
+        long = "deny",
+        value_enum,
+        help = "Exit with a non-zero code on diagnostics of this severity and above"
+    )]
+    deny: Option<Deny>,
+
+    #[arg(
+        short = 'b',
+        long = "baseline",
+        default_value = DEFAULT_BASELINE_FILE,
+        help = "Specify baseline file, findings recorded in it are not reported"
+    )]
+    baseline_file: String,
+
+    #[arg(
+        long = "cache",
+        default_value = "false",
+        help = "Only lint the files changed since the previous run"
+    )]
+    cache: bool,
+
+    #[arg(
+        long = "cache-location",
+        default_value = DEFAULT_CACHE_FILE,
+        help = "Specify cache file"
+    )]
+    cache_location: String,
+
+    #[arg(
+        long = "clear-cache",
+        default_value = "false",
+        help = "Delete the cache file before linting"
+    )]
+    clear_cache: bool,
+
+    #[arg(
+        long = "write-baseline",
+        default_value = "false",
+        help = "Record the current findings in the baseline file"
+    )]
+    write_baseline: bool,
+
+    #[arg(
+        long = "metrics",
+        default_value = "false",
+        help = "Print the complexity metrics of each contract and function instead of linting, as json with the json format"
+    )]
+    metrics: bool,
 }
 
-fn print_result(results: &Vec<LintResult>) {
-    for result in results {
-        match result {
-            Ok(diags) => {
-                let aggregated = aggregate_diags(diags.diags.clone());
-                let new_file = FileDiags::new(diags.source_file_content.clone(), aggregated);
-                print!("{}", &new_file);
+fn create_reporter(format: OutputFormat, linter: &SolidLinter) -> Box<dyn Reporter> {
+    match format {
+        OutputFormat::Text => Box::new(TextReporter),
+        OutputFormat::Json => Box::new(JsonReporter),
+        OutputFormat::Sarif => Box::new(SarifReporter::new(linter.get_documentation())),
+        OutputFormat::Junit => Box::new(JunitReporter),
+        OutputFormat::Checkstyle => Box::new(CheckstyleReporter),
+        OutputFormat::Github => Box::new(GithubReporter),
+        OutputFormat::Compact => Box::new(CompactReporter),
+    }
+}
+
+fn print_fix_diff(fixed: &FixedFile) {
+    let diff = TextDiff::from_lines(&fixed.original_content, &fixed.diags.source_file_content);
+    print!(
+        "{}",
+        diff.unified_diff()
+            .header(&format!("a/{}", fixed.path), &format!("b/{}", fixed.path))
+    );
+}
+
+fn fix_paths(linter: &mut SolidLinter, paths: &[String], dry_run: bool) -> Vec<LintResult> {
+    let mut results = vec![];
+    for fixed in linter.fix_paths(paths) {
+        match fixed {
+            Ok(fixed) => {
+                if !fixed.applied.is_empty() {
+                    if dry_run {
+                        print_fix_diff(&fixed);
+                    } else if let Err(e) =
+                        std::fs::write(&fixed.path, &fixed.diags.source_file_content)
+                    {
+                        eprintln!("Failed to write fixes to {}: {}", fixed.path, e);
+                    } else {
+                        eprintln!("Fixed {} problem(s) in {}", fixed.applied.len(), fixed.path);
+                    }
+                }
+                results.push(Ok(fixed.diags));
             }
+            Err(e) => results.push(Err(e)),
+        }
+    }
+    results
+}
+
+fn print_metrics(linter: &SolidLinter, paths: &[String], format: OutputFormat) -> u8 {
+    let mut metrics = vec![];
+    let mut exit_code = EXIT_SUCCESS;
+
+    for result in linter.metrics_paths(paths) {
+        match result {
+            Ok(file_metrics) => metrics.push(file_metrics),
             Err(e) => {
-                println!("{}", e);
+                eprintln!("{}", e);
+                exit_code = EXIT_CONFIG_ERROR;
             }
         }
     }
+    if format == OutputFormat::Json {
+        match serde_json::to_string_pretty(&metrics) {
+            Ok(json) => println!("{}", json),
+            Err(e) => eprintln!("{}", e),
+        }
+    } else {
+        print!("{}", format_metrics(&metrics));
+    }
+    exit_code
 }
 
-fn main() -> Result<(), SolidHunterError> {
+fn compute_exit_code(args: &Args, results: &[LintResult]) -> u8 {
+    let mut errors = 0;
+    let mut warnings = 0;
+    let mut denied = 0;
+
+    for result in results {
+        let diags = match result {
+            Ok(file_diags) => &file_diags.diags,
+            Err(_) => return EXIT_CONFIG_ERROR,
+        };
+        for diag in diags {
+            if diag.severity == Severity::ERROR {
+                errors += 1;
+            }
+            if diag.severity == Severity::WARNING {
+                warnings += 1;
+            }
+            if args.deny.is_some_and(|deny| deny.is_denied(diag.severity)) {
+                denied += 1;
+            }
+        }
+    }
+
+    if let Some(max_warnings) = args.max_warnings {
+        if warnings > max_warnings {
+            eprintln!(
+                "SolidHunter found too many warnings ({}). Maximum allowed is {}.",
+                warnings, max_warnings
+            );
+            return EXIT_LINT_FAILURE;
+        }
+    }
+    if errors > 0 || denied > 0 {
+        return EXIT_LINT_FAILURE;
+    }
+    EXIT_SUCCESS
+}
+
+fn main() -> ExitCode {
     let args = Args::parse();
 
-    if args.documentation {
-        let linter: SolidLinter = SolidLinter::new_fileless();
+    match run(args) {
+        Ok(code) => ExitCode::from(code),
+        Err(e @ SolidHunterError::RulesConfigError { .. }) => {
+            eprintln!("Error: {}", e);
+            ExitCode::from(EXIT_CONFIG_ERROR)
+        }
+        Err(e) => {
+            eprintln!("Error: {:?}", e);
+            ExitCode::from(EXIT_CONFIG_ERROR)
+        }
+    }
+}
 
-        let json = serde_json::to_string_pretty(&linter.get_documentation());
+fn run(args: Args) -> Result<u8, SolidHunterError> {
+    let format = if args.to_json {
+        OutputFormat::Json
+    } else {
+        args.format
+    };
+
+    if args.documentation {
+        let json = serde_json::to_string_pretty(&RuleFactory::default().get_documentation());
         match json {
             Ok(j) => {
                 println!("{}", j);
@@ -85,10 +318,10 @@ fn main() -> Result<(), SolidHunterError> {
                 println!("{}", e);
             }
         }
-        return Ok(());
+        return Ok(EXIT_SUCCESS);
     }
 
-    if !args.to_json {
+    if format == OutputFormat::Text && !args.fix_dry_run {
         println!();
         println!("SolidHunter: Fast and efficient Solidity linter");
         println!(
@@ -121,55 +354,69 @@ fn main() -> Result<(), SolidHunterError> {
             }
         }
         println!("Done!");
-        return Ok(());
+        return Ok(EXIT_SUCCESS);
     }
 
+    // If no path is specified, we use the current directory
+    let paths = if args.paths.is_empty() {
+        vec![".".to_string()]
+    } else {
+        args.paths.clone()
+    };
+
     let mut linter: SolidLinter = SolidLinter::new();
-    if !args.paths.is_empty() {
+    // Metrics do not depend on the rules, the project may not have a rules file
+    if args.metrics {
+        linter.initialize_excluded_files(args.exclude.as_ref(), &args.paths)?;
+        return Ok(print_metrics(&linter, &paths, format));
+    }
+    let (baseline_file, cache_file) = if !args.paths.is_empty() {
         linter.initialize_rules(
             &(args.paths[0].as_str().to_owned() + "/" + args.rules_file.as_str()),
         )?;
+        (
+            args.paths[0].as_str().to_owned() + "/" + args.baseline_file.as_str(),
+            args.paths[0].as_str().to_owned() + "/" + args.cache_location.as_str(),
+        )
     } else {
         linter.initialize_rules(&args.rules_file)?;
-    }
+        (args.baseline_file.clone(), args.cache_location.clone())
+    };
     linter.initialize_excluded_files(args.exclude.as_ref(), &args.paths)?;
 
-    let mut results = vec![];
-    for path in &args.paths {
-        let result = linter.parse_path(path);
-        results.push(result);
+    if args.write_baseline {
+        let results = linter.parse_paths(&paths);
+        let baseline = Baseline::from_results(&baseline_file, &results);
+        baseline.save(&baseline_file)?;
+        eprintln!(
+            "Baseline written to {} with {} finding(s)",
+            baseline_file,
+            baseline.entries.len()
+        );
+        return Ok(EXIT_SUCCESS);
     }
-    // If no path is specified, we use the current directory
-    if args.paths.is_empty() {
-        let result = linter.parse_path(".");
-        results.push(result);
-    }
-    for (index, path_result) in results.iter().enumerate() {
-        if !args.to_json {
-            print_result(path_result);
-            if index == results.len() - 1 {
-                println!();
-            }
-        } else {
-            for res in path_result {
-                match res {
-                    Ok(diags) => {
-                        let json = serde_json::to_string_pretty(&diags);
-                        match json {
-                            Ok(j) => {
-                                println!("{}", j);
-                            }
-                            Err(e) => {
-                                println!("{}", e);
-                            }
-                        }
-                    }
-                    Err(e) => {
-                        println!("{}", e);
-                    }
-                }
-            }
+    if std::path::Path::new(&baseline_file).is_file() {
+        linter.initialize_baseline(&baseline_file)?;
+    }
+
+    if args.fix_dry_run {
+        fix_paths(&mut linter, &paths, true);
+        return Ok(EXIT_SUCCESS);
+    }
+
+    if args.clear_cache {
+        LintCache::clear(&cache_file)?;
+    }
+    let mut results = vec![];
+    if args.fix {
+        results = fix_paths(&mut linter, &paths, false);
+    } else {
+        if args.cache {
+            linter.initialize_cache(&cache_file);
         }
+        results = linter.parse_paths(&paths);
+        linter.save_cache()?;
     }
-    Ok(())
+    print!("{}", create_reporter(format, &linter).report(&results));
+    Ok(compute_exit_code(&args, &results))
 }
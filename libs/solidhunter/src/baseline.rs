@@ -0,0 +1,142 @@
+use crate::errors::SolidHunterError;
+use crate::types::{FileDiags, LintDiag, LintResult};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+pub const DEFAULT_BASELINE_FILE: &str = ".solidhunter-baseline.json";
+const BASELINE_VERSION: u32 = 1;
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BaselineEntry {
+    pub rule: String,
+    pub file: String,
+    pub fingerprint: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Baseline {
+    pub version: u32,
+    pub entries: Vec<BaselineEntry>,
+
+    // Directory the file paths of the entries are relative to
+    #[serde(skip)]
+    root: PathBuf,
+}
+
+// Fingerprint a diagnostic from its rule and the code it points to, without the line number so
+// that it survives code being added or removed above it
+pub fn fingerprint(diag: &LintDiag, content: &str) -> String {
+    let lines: Vec<&str> = content
+        .lines()
+        .skip(diag.range.start.line.saturating_sub(1))
+        .take(diag.range.end.line + 1 - diag.range.start.line)
+        .map(|line| line.trim())
+        .collect();
+
+    let mut hasher = Sha256::new();
+    hasher.update(diag.id.as_bytes());
+    hasher.update(b"\n");
+    hasher.update(lines.join("\n").as_bytes());
+    hasher.update(b"\n");
+    hasher.update(diag.range.text(content).unwrap_or_default().as_bytes());
+    hasher
+        .finalize()
+        .iter()
+        .take(16)
+        .map(|byte| format!("{:02x}", byte))
+        .collect()
+}
+
+impl Baseline {
+    pub fn new(root: &Path) -> Self {
+        Baseline {
+            version: BASELINE_VERSION,
+            entries: Vec::new(),
+            root: root.to_path_buf(),
+        }
+    }
+
+    pub fn from_results(path: &str, results: &[LintResult]) -> Self {
+        let mut baseline = Baseline::new(&baseline_root(path));
+
+        for file_diags in results.iter().flatten() {
+            for diag in &file_diags.diags {
+                baseline.entries.push(BaselineEntry {
+                    rule: diag.id.clone(),
+                    file: baseline.relative_path(&diag.uri),
+                    fingerprint: fingerprint(diag, &file_diags.source_file_content),
+                });
+            }
+        }
+        baseline.entries.sort_by(|a, b| {
+            (&a.file, &a.rule, &a.fingerprint).cmp(&(&b.file, &b.rule, &b.fingerprint))
+        });
+        baseline
+    }
+
+    pub fn load(path: &str) -> Result<Self, SolidHunterError> {
+        let content = std::fs::read_to_string(path)?;
+        let mut baseline: Baseline = serde_json::from_str(&content)?;
+
+        baseline.root = baseline_root(path);
+        Ok(baseline)
+    }
+
+    pub fn save(&self, path: &str) -> Result<(), SolidHunterError> {
+        let serialized = serde_json::to_string_pretty(self)?;
+
+        std::fs::write(path, serialized + "\n")?;
+        Ok(())
+    }
+
+    // Express a linted file path relative to the baseline, the same file can be linted from
+    // different working directories or through an absolute path by the language server
+    fn relative_path(&self, path: &str) -> String {
+        let path = Path::new(path);
+        let absolute = std::fs::canonicalize(path).unwrap_or(path.to_path_buf());
+        let relative = absolute.strip_prefix(&self.root).unwrap_or(&absolute);
+
+        relative.to_string_lossy().replace('\\', "/")
+    }
+
+    /// Removes the diagnostics already recorded in the baseline, each entry suppresses one
+    /// diagnostic so duplicated findings on identical lines are still reported once new.
+    pub fn filter(&self, mut file_diags: FileDiags) -> FileDiags {
+        let file = match file_diags.diags.first() {
+            Some(diag) => self.relative_path(&diag.uri),
+            None => return file_diags,
+        };
+        let mut remaining: HashMap<(String, String), usize> = HashMap::new();
+        for entry in self.entries.iter().filter(|entry| entry.file == file) {
+            *remaining
+                .entry((entry.rule.clone(), entry.fingerprint.clone()))
+                .or_default() += 1;
+        }
+        if remaining.is_empty() {
+            return file_diags;
+        }
+
+        let content = &file_diags.source_file_content;
+        file_diags.diags.retain(|diag| {
+            let key = (diag.id.clone(), fingerprint(diag, content));
+            match remaining.get_mut(&key) {
+                Some(count) if *count > 0 => {
+                    *count -= 1;
+                    false
+                }
+                _ => true,
+            }
+        });
+        file_diags
+    }
+}
+
+fn baseline_root(path: &str) -> PathBuf {
+    let parent = match Path::new(path).parent() {
+        Some(parent) if !parent.as_os_str().is_empty() => parent.to_path_buf(),
+        _ => PathBuf::from("."),
+    };
+    std::fs::canonicalize(&parent).unwrap_or(parent)
+}
@@ -0,0 +1,99 @@
+use crate::errors::SolidHunterError;
+use crate::rules::imports::Remappings;
+use crate::types::LintDiag;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
+
+pub const DEFAULT_CACHE_FILE: &str = ".solidhunter-cache";
+const CACHE_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+pub fn hash(content: &str) -> String {
+    Sha256::digest(content.as_bytes())
+        .iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect()
+}
+
+/// Hash of a file for the cache. The remappings applying to the file are part of it, as the
+/// diagnostics of import-path-style depend on them.
+pub fn file_hash(filepath: &str, content: &str) -> String {
+    match Remappings::sources(filepath) {
+        Some((_, remappings, foundry)) => hash(&format!("{}{}{}", content, remappings, foundry)),
+        None => hash(content),
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+struct CacheEntry {
+    hash: String,
+    diags: Vec<LintDiag>,
+}
+
+/// Diagnostics of previously linted files, keyed by file path and invalidated when the content
+/// of the file or its remappings, the configuration or the solidhunter version changes.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct LintCache {
+    version: String,
+    config: String,
+    files: HashMap<String, CacheEntry>,
+
+    #[serde(skip)]
+    path: String,
+}
+
+impl LintCache {
+    pub fn new(path: &str, config_hash: &str) -> Self {
+        LintCache {
+            version: CACHE_VERSION.to_string(),
+            config: config_hash.to_string(),
+            files: HashMap::new(),
+            path: path.to_string(),
+        }
+    }
+
+    /// Loads the cache at `path`, starting from an empty one if it is missing, unreadable or
+    /// was written by another solidhunter version or configuration.
+    pub fn load(path: &str, config_hash: &str) -> Self {
+        let cache = std::fs::read_to_string(path)
+            .ok()
+            .and_then(|content| serde_json::from_str::<LintCache>(&content).ok());
+
+        match cache {
+            Some(mut cache) if cache.version == CACHE_VERSION && cache.config == config_hash => {
+                cache.path = path.to_string();
+                cache
+            }
+            _ => LintCache::new(path, config_hash),
+        }
+    }
+
+    pub fn save(&self) -> Result<(), SolidHunterError> {
+        std::fs::write(&self.path, serde_json::to_string(self)?)?;
+        Ok(())
+    }
+
+    pub fn clear(path: &str) -> Result<(), SolidHunterError> {
+        if std::path::Path::new(path).is_file() {
+            std::fs::remove_file(path)?;
+        }
+        Ok(())
+    }
+
+    pub fn get(&self, file: &str, content_hash: &str) -> Option<&Vec<LintDiag>> {
+        self.files
+            .get(file)
+            .filter(|entry| entry.hash == content_hash)
+            .map(|entry| &entry.diags)
+    }
+
+    pub fn insert(&mut self, file: &str, content_hash: String, diags: Vec<LintDiag>) {
+        self.files.insert(
+            file.to_string(),
+            CacheEntry {
+                hash: content_hash,
+                diags,
+            },
+        );
+    }
+}
@@ -1,3 +1,4 @@
+use std::fmt;
 use thiserror::Error;
 
 #[derive(Error, Debug)]
@@ -15,4 +16,87 @@ pub enum SolidHunterError {
     // RulesError
     #[error("SolidHunterError: IO error occured with Rules")]
     IoError(std::io::Error),
+    #[error("SolidHunterError: Invalid rules configuration{}\n{}", format_config(.config), format_rule_config_errors(.errors))]
+    RulesConfigError {
+        config: Option<String>,
+        errors: Vec<RuleConfigError>,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum RuleConfigError {
+    UnknownRule {
+        path: String,
+        id: String,
+        suggestion: Option<String>,
+    },
+    InvalidOption {
+        path: String,
+        id: String,
+        expected: String,
+        found: String,
+    },
+    InvalidExtends {
+        path: String,
+        extends: String,
+        reason: String,
+    },
+    InvalidPattern {
+        path: String,
+        pattern: String,
+        reason: String,
+    },
+}
+
+impl fmt::Display for RuleConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RuleConfigError::UnknownRule {
+                path,
+                id,
+                suggestion,
+            } => {
+                write!(f, "{}: unknown rule \"{}\"", path, id)?;
+                if let Some(suggestion) = suggestion {
+                    write!(f, ", did you mean \"{}\"?", suggestion)?;
+                }
+                Ok(())
+            }
+            RuleConfigError::InvalidOption {
+                path,
+                id,
+                expected,
+                found,
+            } => write!(
+                f,
+                "{}: invalid option for rule \"{}\", expected {} but found {}",
+                path, id, expected, found
+            ),
+            RuleConfigError::InvalidExtends {
+                path,
+                extends,
+                reason,
+            } => write!(f, "{}: cannot extend \"{}\", {}", path, extends, reason),
+            RuleConfigError::InvalidPattern {
+                path,
+                pattern,
+                reason,
+            } => write!(f, "{}: invalid pattern \"{}\", {}", path, pattern, reason),
+        }
+    }
+}
+
+fn format_config(config: &Option<String>) -> String {
+    match config {
+        Some(config) => format!(" in {}", config),
+        None => String::new(),
+    }
+}
+
+fn format_rule_config_errors(errors: &[RuleConfigError]) -> String {
+    errors
+        .iter()
+        .map(|error| format!("  - {}", error))
+        .collect::<Vec<String>>()
+        .join("\n")
 }
@@ -0,0 +1,108 @@
+use crate::types::{FileDiags, Fix, LintDiag, TextEdit};
+
+#[derive(Debug, Clone)]
+pub struct FixedFile {
+    pub path: String,
+    pub original_content: String,
+    pub diags: FileDiags,
+    pub applied: Vec<Fix>,
+}
+
+#[derive(Debug, Clone)]
+pub struct FixResult {
+    pub content: String,
+    pub applied: Vec<Fix>,
+    pub skipped: Vec<Fix>,
+}
+
+// Resolve the byte offsets of an edit, None if the edit is out of the content
+fn edit_offsets(edit: &TextEdit, content: &str) -> Option<(usize, usize)> {
+    let start = edit.range.start.to_offset(content)?;
+    let end = edit.range.end.to_offset(content)?;
+    if start > end {
+        return None;
+    }
+    Some((start, end))
+}
+
+fn overlaps(a: (usize, usize), b: (usize, usize)) -> bool {
+    // Two insertions at the same offset would be applied in an arbitrary order
+    if a.0 == a.1 && b.0 == b.1 {
+        return a.0 == b.0;
+    }
+    a.0 < b.1 && b.0 < a.1
+}
+
+pub fn apply_fixes(content: &str, diags: &[LintDiag]) -> FixResult {
+    let mut fixes: Vec<(usize, &Fix)> = diags
+        .iter()
+        .filter_map(|diag| diag.fix.as_ref())
+        .filter(|fix| !fix.edits.is_empty())
+        .filter_map(|fix| {
+            let start = fix
+                .edits
+                .iter()
+                .filter_map(|edit| edit_offsets(edit, content))
+                .map(|(start, _)| start)
+                .min()?;
+            Some((start, fix))
+        })
+        .collect();
+    fixes.sort_by_key(|(start, _)| *start);
+
+    let mut accepted: Vec<(usize, usize, &TextEdit)> = Vec::new();
+    let mut applied = Vec::new();
+    let mut skipped = Vec::new();
+
+    for (_, fix) in fixes {
+        if applied.contains(fix) {
+            continue;
+        }
+        let offsets: Option<Vec<(usize, usize)>> = fix
+            .edits
+            .iter()
+            .map(|edit| edit_offsets(edit, content))
+            .collect();
+        let offsets = match offsets {
+            Some(offsets) => offsets,
+            None => {
+                skipped.push(fix.clone());
+                continue;
+            }
+        };
+        let conflicts = offsets.iter().enumerate().any(|(idx, edit)| {
+            offsets[idx + 1..]
+                .iter()
+                .any(|other| overlaps(*edit, *other))
+                || accepted
+                    .iter()
+                    .any(|(start, end, _)| overlaps(*edit, (*start, *end)))
+        });
+
+        // A fix is only applied if all of its edits can be applied
+        if conflicts {
+            skipped.push(fix.clone());
+            continue;
+        }
+        for (edit, (start, end)) in fix.edits.iter().zip(offsets) {
+            accepted.push((start, end, edit));
+        }
+        applied.push(fix.clone());
+    }
+
+    accepted.sort_by_key(|(start, end, _)| (*start, *end));
+    let mut fixed = String::with_capacity(content.len());
+    let mut last = 0;
+    for (start, end, edit) in accepted {
+        fixed.push_str(&content[last..start]);
+        fixed.push_str(&edit.new_text);
+        last = end;
+    }
+    fixed.push_str(&content[last..]);
+
+    FixResult {
+        content: fixed,
+        applied,
+        skipped,
+    }
+}
@@ -1,6 +1,11 @@
 pub mod aggregate;
+pub mod baseline;
+pub mod cache;
 pub mod errors;
+pub mod fix;
 mod ignore;
 pub mod linter;
+pub mod metrics;
+pub mod reporters;
 pub mod rules;
 pub mod types;
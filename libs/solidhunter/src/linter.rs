@@ -1,6 +1,11 @@
-use crate::errors::SolidHunterError;
-use crate::rules::create_default_rules;
+use crate::baseline::Baseline;
+use crate::cache::{self, LintCache};
+use crate::errors::{RuleConfigError, SolidHunterError};
+use crate::fix::{apply_fixes, FixedFile};
+use crate::metrics::{file_metrics, FileMetrics};
 use crate::rules::factory::RuleFactory;
+use crate::rules::presets::create_recommended_rules;
+use crate::rules::rule_impl::merge_rules;
 use crate::rules::rule_impl::parse_rules;
 use crate::rules::rule_impl::parse_rules_content;
 use crate::rules::types::*;
@@ -8,8 +13,12 @@ use crate::types::*;
 use std::fs;
 
 use crate::ignore::get_excluded_files;
-use glob::glob;
-use std::path::Path;
+use glob::{glob, Pattern};
+use rayon::prelude::*;
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+
+const MAX_FIX_PASSES: usize = 10;
 
 #[derive(Debug, Clone)]
 pub struct SolidFile {
@@ -18,11 +27,35 @@ pub struct SolidFile {
     pub content: String,
 }
 
+struct RuleOverride {
+    patterns: Vec<Pattern>,
+    rules: Vec<RuleEntry>,
+}
+
+// File linted by a worker thread, `hash` is set when its diagnostics must be cached
+struct LintedFile {
+    content: String,
+    hash: Option<String>,
+    diags: Vec<LintDiag>,
+}
+
 pub struct SolidLinter {
     files: Vec<SolidFile>,
+    // Index of each file in `files` by path
+    file_indexes: HashMap<String, usize>,
     rule_factory: RuleFactory,
     rules: Vec<Box<dyn RuleType>>,
+    rule_entries: Vec<RuleEntry>,
+    overrides: Vec<RuleOverride>,
+    // Rules of the files matching overrides, by indexes of the matching overrides
+    override_rules: HashMap<Vec<usize>, Vec<Box<dyn RuleType>>>,
+    // Directory the override patterns are matched from
+    root: Option<PathBuf>,
+    // Hash of the loaded configurations, invalidating the cache when they change
+    config_hash: String,
     excluded_files: Vec<String>,
+    baseline: Option<Baseline>,
+    cache: Option<LintCache>,
 }
 
 impl Default for SolidLinter {
@@ -35,23 +68,44 @@ impl SolidLinter {
     pub fn new() -> Self {
         SolidLinter {
             files: Vec::new(),
+            file_indexes: HashMap::new(),
             rule_factory: RuleFactory::default(),
             rules: vec![],
+            rule_entries: Vec::new(),
+            overrides: Vec::new(),
+            override_rules: HashMap::new(),
+            root: None,
+            config_hash: String::new(),
             excluded_files: Vec::new(),
+            baseline: None,
+            cache: None,
         }
     }
 
     pub fn new_fileless() -> Self {
-        let default_rules = create_default_rules();
+        let default_rules = create_recommended_rules();
         let mut linter = SolidLinter {
             files: Vec::new(),
+            file_indexes: HashMap::new(),
             rule_factory: RuleFactory::default(),
             rules: Vec::new(),
+            rule_entries: default_rules.clone(),
+            overrides: Vec::new(),
+            override_rules: HashMap::new(),
+            root: None,
+            config_hash: String::new(),
             excluded_files: Vec::new(),
+            baseline: None,
+            cache: None,
         };
 
         for rule in default_rules {
-            linter.rules.push(linter.rule_factory.create_rule(rule));
+            if rule.severity == Severity::OFF {
+                continue;
+            }
+            if let Some(rule) = linter.rule_factory.create_rule(rule) {
+                linter.rules.push(rule);
+            }
         }
 
         linter
@@ -59,12 +113,109 @@ impl SolidLinter {
 
     pub fn initialize_rules(&mut self, rules_config: &str) -> Result<(), SolidHunterError> {
         let res = parse_rules(rules_config)?;
-        for rule in res.rules {
-            self.rules.push(self.rule_factory.create_rule(rule));
+        if let Some(parent) = Path::new(rules_config).parent() {
+            self.set_root_path(parent);
+        }
+        self.load_rules(res, Some(rules_config.to_string()))
+    }
+
+    /// Sets the directory the file patterns of the configuration overrides are relative to.
+    /// It defaults to the directory of the rules file, or the working directory for content.
+    pub fn set_root_path(&mut self, path: &Path) {
+        let path = if path.as_os_str().is_empty() {
+            Path::new(".")
+        } else {
+            path
+        };
+        self.root = Some(fs::canonicalize(path).unwrap_or(path.to_path_buf()));
+    }
+
+    fn load_rules(&mut self, res: Rules, config: Option<String>) -> Result<(), SolidHunterError> {
+        let mut errors = Vec::new();
+
+        self.config_hash = cache::hash(&format!(
+            "{}{}{:?}",
+            self.config_hash,
+            serde_json::to_string(&res)?,
+            self.root
+        ));
+
+        match self.rule_factory.create_rules("rules", res.rules.clone()) {
+            Ok(mut rules) => self.rules.append(&mut rules),
+            Err(mut rule_errors) => errors.append(&mut rule_errors),
+        }
+        for (i, rules_override) in res.overrides.into_iter().enumerate() {
+            let path = format!("overrides[{}]", i);
+            let mut patterns = Vec::new();
+
+            for (j, file) in rules_override.files.iter().enumerate() {
+                match Pattern::new(file) {
+                    Ok(pattern) => patterns.push(pattern),
+                    Err(e) => errors.push(RuleConfigError::InvalidPattern {
+                        path: format!("{}.files[{}]", path, j),
+                        pattern: file.clone(),
+                        reason: e.msg.to_string(),
+                    }),
+                }
+            }
+            if let Err(mut rule_errors) = self
+                .rule_factory
+                .create_rules(&format!("{}.rules", path), rules_override.rules.clone())
+            {
+                errors.append(&mut rule_errors);
+            }
+            self.overrides.push(RuleOverride {
+                patterns,
+                rules: rules_override.rules,
+            });
+        }
+        if !errors.is_empty() {
+            return Err(SolidHunterError::RulesConfigError { config, errors });
         }
+
+        merge_rules(&mut self.rule_entries, res.rules);
+        self.override_rules.clear();
         Ok(())
     }
 
+    fn matching_overrides(&self, filepath: &str) -> Vec<usize> {
+        if self.overrides.is_empty() {
+            return Vec::new();
+        }
+        let path = Path::new(filepath);
+        let absolute = fs::canonicalize(path).unwrap_or(path.to_path_buf());
+        let relative = match &self.root {
+            Some(root) => absolute.strip_prefix(root).unwrap_or(path),
+            None => path,
+        };
+        let relative = relative.strip_prefix("./").unwrap_or(relative);
+
+        self.overrides
+            .iter()
+            .enumerate()
+            .filter(|(_, rules_override)| {
+                rules_override
+                    .patterns
+                    .iter()
+                    .any(|pattern| pattern.matches_path(relative))
+            })
+            .map(|(i, _)| i)
+            .collect()
+    }
+
+    // Builds the rules of the files matching a set of overrides, the overrides are valid
+    // since they were checked when loading the configuration
+    fn create_override_rules(&self, overrides: &[usize]) -> Vec<Box<dyn RuleType>> {
+        let mut entries = self.rule_entries.clone();
+
+        for i in overrides {
+            merge_rules(&mut entries, self.overrides[*i].rules.clone());
+        }
+        self.rule_factory
+            .create_rules("rules", entries)
+            .unwrap_or_default()
+    }
+
     pub fn get_documentation(&self) -> Vec<RuleDocumentation> {
         let mut res = Vec::new();
         for rule in &self.rules {
@@ -88,21 +239,35 @@ impl SolidLinter {
         Ok(())
     }
 
+    pub fn initialize_baseline(&mut self, baseline_path: &str) -> Result<(), SolidHunterError> {
+        self.baseline = Some(Baseline::load(baseline_path)?);
+        Ok(())
+    }
+
+    pub fn set_baseline(&mut self, baseline: Option<Baseline>) {
+        self.baseline = baseline;
+    }
+
+    /// Loads the cache of previous runs, it must be initialized after the rules since
+    /// results of another configuration are discarded.
+    pub fn initialize_cache(&mut self, cache_path: &str) {
+        self.cache = Some(LintCache::load(cache_path, &self.config_hash));
+    }
+
+    pub fn save_cache(&self) -> Result<(), SolidHunterError> {
+        match &self.cache {
+            Some(cache) => cache.save(),
+            None => Ok(()),
+        }
+    }
+
     pub fn initialize_rules_content(&mut self, rules_config: &str) -> Result<(), SolidHunterError> {
         let res = parse_rules_content(rules_config)?;
-        for rule in res.rules {
-            self.rules.push(self.rule_factory.create_rule(rule));
-        }
-        Ok(())
+        self.load_rules(res, None)
     }
 
     fn _file_exists(&self, path: &str) -> bool {
-        for file in &self.files {
-            if file.path == path {
-                return true;
-            }
-        }
-        false
+        self.file_indexes.contains_key(path)
     }
 
     fn _add_file(
@@ -111,19 +276,18 @@ impl SolidLinter {
         ast: osmium_libs_solidity_ast_extractor::File,
         content: &str,
     ) {
-        if self._file_exists(path) {
-            for file in &mut self.files {
-                if file.path == path {
-                    file.data = ast.clone();
-                    file.content = String::from(content);
-                }
-            }
+        if let Some(idx) = self.file_indexes.get(path) {
+            let file = &mut self.files[*idx];
+            file.data = ast;
+            file.content = String::from(content);
         } else {
             let file = SolidFile {
                 data: ast,
                 path: String::from(path),
                 content: String::from(content),
             };
+            self.file_indexes
+                .insert(String::from(path), self.files.len());
             self.files.push(file);
         }
     }
@@ -136,7 +300,7 @@ impl SolidLinter {
         self.parse_content(&filepath, content.as_str())
     }
 
-    fn _check_is_in_disable_range(&self, diag: &LintDiag, disable_ranges: &[DisableRange]) -> bool {
+    fn _check_is_in_disable_range(diag: &LintDiag, disable_ranges: &[DisableRange]) -> bool {
         let mut rules_occurrences = vec![];
 
         let filtered_range = disable_ranges
@@ -208,7 +372,7 @@ impl SolidLinter {
         false
     }
 
-    fn _check_is_diag_ignored(&self, diag: &LintDiag, file: &SolidFile) -> bool {
+    fn _check_is_diag_ignored(diag: &LintDiag, file: &SolidFile) -> bool {
         let ignore_comments: Vec<IgnoreComment> = file
             .content
             .lines()
@@ -217,54 +381,395 @@ impl SolidLinter {
             .collect();
         let disable_ranges = build_disable_ranges(ignore_comments);
 
-        self._check_is_in_disable_range(diag, &disable_ranges)
+        Self::_check_is_in_disable_range(diag, &disable_ranges)
+    }
+
+    fn _lint_file<'a>(
+        rules: impl IntoIterator<Item = &'a Box<dyn RuleType>>,
+        file: &SolidFile,
+        files: &[SolidFile],
+    ) -> Vec<LintDiag> {
+        let mut res: Vec<_> = vec![];
+
+        for rule in rules {
+            let diags = rule.diagnose(file, files);
+            for diag in &diags {
+                if !Self::_check_is_diag_ignored(diag, file) {
+                    res.push(diag.clone());
+                }
+            }
+        }
+        res
+    }
+
+    // Builds the rules of the overrides matching a file if needed, and returns the key of its
+    // rules in `override_rules`
+    fn _prepare_rules(&mut self, filepath: &str) -> Vec<usize> {
+        let overrides = self.matching_overrides(filepath);
+        if !overrides.is_empty() && !self.override_rules.contains_key(&overrides) {
+            let rules = self.create_override_rules(&overrides);
+            self.override_rules.insert(overrides.clone(), rules);
+        }
+        overrides
+    }
+
+    fn _filter_baseline(&self, file_diags: FileDiags) -> FileDiags {
+        match &self.baseline {
+            Some(baseline) => baseline.filter(file_diags),
+            None => file_diags,
+        }
     }
 
     pub fn parse_content(&mut self, filepath: &str, content: &str) -> LintResult {
         let res = osmium_libs_solidity_ast_extractor::extract::extract_ast_from_content(content)?;
 
         self._add_file(filepath, res, content);
-        let mut res: Vec<_> = vec![];
-        let file = self.files.iter().find(|x| x.path == filepath).unwrap();
+        let overrides = self._prepare_rules(filepath);
+        let rules = self.override_rules.get(&overrides).unwrap_or(&self.rules);
+        let file = &self.files[self.file_indexes[filepath]];
 
-        for rule in &self.rules {
-            let mut diags = rule.diagnose(file, &self.files);
-            for diag in &mut diags {
-                if !self._check_is_diag_ignored(diag, file) {
-                    res.push(diag.clone());
+        let diags = Self::_lint_file(rules, file, &self.files);
+        Ok(self._filter_baseline(FileDiags::new(content.to_string(), diags)))
+    }
+
+    /// Lints files in parallel, skipping the files whose results are in the cache. Their
+    /// syntax trees are dropped once linted instead of being kept in the linter.
+    pub fn parse_files(&mut self, filepaths: &[String]) -> Vec<LintResult> {
+        let overrides: Vec<Vec<usize>> = filepaths
+            .iter()
+            .map(|filepath| self._prepare_rules(filepath))
+            .collect();
+        let rules = &self.rules;
+        let override_rules = &self.override_rules;
+        let excluded_files = &self.excluded_files;
+        let lint_cache = &self.cache;
+
+        // Syntax trees cannot be sent across threads, each file is parsed by the thread linting it
+        let linted: Vec<Result<LintedFile, SolidHunterError>> = filepaths
+            .par_iter()
+            .zip(overrides.par_iter())
+            .map(|(filepath, overrides)| {
+                let content = fs::read_to_string(filepath)?;
+                if excluded_files.contains(filepath) {
+                    return Ok(LintedFile {
+                        content,
+                        hash: None,
+                        diags: Vec::new(),
+                    });
+                }
+                let hash = cache::file_hash(filepath, &content);
+                if let Some(diags) = lint_cache
+                    .as_ref()
+                    .and_then(|lint_cache| lint_cache.get(filepath, &hash))
+                {
+                    return Ok(LintedFile {
+                        content,
+                        hash: None,
+                        diags: diags.clone(),
+                    });
+                }
+
+                let file = SolidFile {
+                    data: osmium_libs_solidity_ast_extractor::extract::extract_ast_from_content(
+                        &content,
+                    )?,
+                    path: filepath.clone(),
+                    content,
+                };
+                let rules = override_rules.get(overrides).unwrap_or(rules);
+                let diags = Self::_lint_file(
+                    rules.iter().filter(|rule| !rule.is_cross_file()),
+                    &file,
+                    std::slice::from_ref(&file),
+                );
+                Ok(LintedFile {
+                    content: file.content,
+                    hash: Some(hash),
+                    diags,
+                })
+            })
+            .collect();
+
+        let mut linted: Vec<Result<LintedFile, SolidHunterError>> = filepaths
+            .iter()
+            .zip(linted)
+            .map(|(filepath, linted)| {
+                let linted = linted?;
+                if let (Some(lint_cache), Some(hash)) = (&mut self.cache, &linted.hash) {
+                    lint_cache.insert(filepath, hash.clone(), linted.diags.clone());
                 }
+                Ok(linted)
+            })
+            .collect();
+        self._lint_cross_files(filepaths, &overrides, &mut linted);
+
+        linted
+            .into_iter()
+            .map(|linted| {
+                let linted = linted?;
+                Ok(self._filter_baseline(FileDiags::new(linted.content, linted.diags)))
+            })
+            .collect()
+    }
+
+    // Runs the cross-file rules once every file is loaded, so that their results do not depend
+    // on the order of the files. Their results depend on other files and are never cached.
+    fn _lint_cross_files(
+        &self,
+        filepaths: &[String],
+        overrides: &[Vec<usize>],
+        linted: &mut [Result<LintedFile, SolidHunterError>],
+    ) {
+        let has_cross_file_rules = overrides.iter().any(|overrides| {
+            self.override_rules
+                .get(overrides)
+                .unwrap_or(&self.rules)
+                .iter()
+                .any(|rule| rule.is_cross_file())
+        });
+        if !has_cross_file_rules {
+            return;
+        }
+
+        let mut files = Vec::new();
+        let mut file_entries = Vec::new();
+        for (i, (filepath, linted)) in filepaths.iter().zip(linted.iter()).enumerate() {
+            let Ok(linted) = linted else {
+                continue;
+            };
+            if self.excluded_files.contains(filepath) {
+                continue;
+            }
+            if let Ok(data) = osmium_libs_solidity_ast_extractor::extract::extract_ast_from_content(
+                &linted.content,
+            ) {
+                files.push(SolidFile {
+                    data,
+                    path: filepath.clone(),
+                    content: linted.content.clone(),
+                });
+                file_entries.push(i);
+            }
+        }
+
+        for (file, i) in files.iter().zip(file_entries) {
+            let rules = self
+                .override_rules
+                .get(&overrides[i])
+                .unwrap_or(&self.rules);
+            let diags = Self::_lint_file(
+                rules.iter().filter(|rule| rule.is_cross_file()),
+                file,
+                &files,
+            );
+            if let Ok(linted) = &mut linted[i] {
+                linted.diags.extend(diags);
             }
         }
-        Ok(FileDiags::new(content.to_string(), res))
     }
 
-    pub fn parse_folder(&mut self, folder: &str) -> Vec<LintResult> {
-        let mut result: Vec<LintResult> = Vec::new();
-        if let Ok(entries) = glob(&(folder.to_owned() + "/**/*.sol")) {
+    /// Computes the fixes of diagnostics returned by `parse_content`. Linting leaves them out
+    /// since most are never applied, and nothing is computed if the file has been linted with
+    /// another content since.
+    pub fn compute_fixes(&mut self, filepath: &str, file_diags: &mut FileDiags) {
+        let Some(&index) = self.file_indexes.get(filepath) else {
+            return;
+        };
+        if self.files[index].content != file_diags.source_file_content {
+            return;
+        }
+        let overrides = self._prepare_rules(filepath);
+        let rules = self.override_rules.get(&overrides).unwrap_or(&self.rules);
+        let file = &self.files[index];
+        let rules_by_id: HashMap<String, &dyn RuleType> = rules
+            .iter()
+            .map(|rule| (rule.get_documentation().id, rule.as_ref()))
+            .collect();
+
+        for diag in &mut file_diags.diags {
+            diag.fix = rules_by_id
+                .get(&diag.id)
+                .and_then(|rule| rule.fix(file, &self.files, diag));
+        }
+    }
+
+    pub fn fix_file(&mut self, filepath: String) -> Result<FixedFile, SolidHunterError> {
+        let content = fs::read_to_string(filepath.clone())?;
+        if self.excluded_files.contains(&filepath) {
+            return Ok(FixedFile {
+                path: filepath,
+                original_content: content.clone(),
+                diags: FileDiags::new(content, Vec::new()),
+                applied: Vec::new(),
+            });
+        }
+        self.fix_content(&filepath, &content)
+    }
+
+    pub fn fix_content(
+        &mut self,
+        filepath: &str,
+        content: &str,
+    ) -> Result<FixedFile, SolidHunterError> {
+        let mut diags = self.parse_content(filepath, content)?;
+        let mut applied = Vec::new();
+
+        // Fixes can unlock new fixes (or be skipped because of overlapping edits), so we
+        // lint the fixed content again until it is stable
+        for _ in 0..MAX_FIX_PASSES {
+            self.compute_fixes(filepath, &mut diags);
+            let result = apply_fixes(&diags.source_file_content, &diags.diags);
+            if result.applied.is_empty() {
+                break;
+            }
+            match self.parse_content(filepath, &result.content) {
+                Ok(new_diags) => {
+                    diags = new_diags;
+                    applied.extend(result.applied);
+                }
+                // A fix produced an invalid file, keep the last valid content
+                Err(_) => break,
+            }
+        }
+        Ok(FixedFile {
+            path: filepath.to_string(),
+            original_content: content.to_string(),
+            diags,
+            applied,
+        })
+    }
+
+    fn _collect_files(path: &str) -> Vec<String> {
+        if Path::new(&path).is_file() {
+            return vec![path.to_string()];
+        }
+        let mut filepaths = Vec::new();
+        if let Ok(entries) = glob(&(path.to_owned() + "/**/*.sol")) {
             for entry in entries.flatten() {
-                result.push(self.parse_file(entry.into_os_string().into_string().unwrap()));
+                filepaths.push(entry.into_os_string().into_string().unwrap());
             }
         }
-        result
+        filepaths
+    }
+
+    pub fn parse_folder(&mut self, folder: &str) -> Vec<LintResult> {
+        self.parse_paths(&[folder.to_string()])
     }
 
     pub fn parse_path(&mut self, path: &str) -> Vec<LintResult> {
-        if Path::new(&path).is_file() {
-            vec![self.parse_file(path.to_string())]
-        } else {
-            self.parse_folder(path)
+        self.parse_paths(&[path.to_string()])
+    }
+
+    /// Lints the files of several paths together, every file is loaded before the cross-file
+    /// rules run. A file given by several paths is only linted once.
+    pub fn parse_paths(&mut self, paths: &[String]) -> Vec<LintResult> {
+        self.parse_files(&Self::_collect_paths(paths))
+    }
+
+    fn _collect_paths(paths: &[String]) -> Vec<String> {
+        let mut seen = HashSet::new();
+        paths
+            .iter()
+            .flat_map(|path| Self::_collect_files(path))
+            .filter(|filepath| seen.insert(filepath.clone()))
+            .collect()
+    }
+
+    /// Loads the files of several paths without linting them, so that the cross-file rules of
+    /// `parse_content` see the whole project.
+    pub fn load_paths(&mut self, paths: &[String]) {
+        self._load_files(&Self::_collect_paths(paths));
+    }
+
+    fn _load_files(&mut self, filepaths: &[String]) {
+        for filepath in filepaths {
+            if !self.excluded_files.contains(filepath) {
+                self._load_file(filepath);
+            }
         }
     }
 
-    pub fn delete_file(&mut self, path: &str) {
-        loop {
-            let idx = self.files.iter().position(|x| x.path == path);
-            match idx {
-                Some(idx) => {
-                    self.files.remove(idx);
+    fn _load_file(&mut self, filepath: &str) -> bool {
+        let Ok(content) = fs::read_to_string(filepath) else {
+            return false;
+        };
+        match osmium_libs_solidity_ast_extractor::extract::extract_ast_from_content(&content) {
+            Ok(ast) => {
+                self._add_file(filepath, ast, &content);
+                true
+            }
+            Err(_) => false,
+        }
+    }
+
+    pub fn fix_path(&mut self, path: &str) -> Vec<Result<FixedFile, SolidHunterError>> {
+        self.fix_paths(&[path.to_string()])
+    }
+
+    /// Fixes the files of several paths, every file is loaded first so that the cross-file
+    /// rules see the whole project.
+    pub fn fix_paths(&mut self, paths: &[String]) -> Vec<Result<FixedFile, SolidHunterError>> {
+        let filepaths = Self::_collect_paths(paths);
+
+        // Files that cannot be read or parsed are reported when fixed
+        self._load_files(&filepaths);
+        filepaths
+            .into_iter()
+            .map(|filepath| self.fix_file(filepath))
+            .collect()
+    }
+
+    /// Measures the files of several paths, the bases of their contracts are looked up in every
+    /// file of the paths.
+    pub fn metrics_paths(&self, paths: &[String]) -> Vec<Result<FileMetrics, SolidHunterError>> {
+        let mut files = Vec::new();
+        let mut results = Vec::new();
+
+        for filepath in Self::_collect_paths(paths) {
+            if self.excluded_files.contains(&filepath) {
+                continue;
+            }
+            let parsed = fs::read_to_string(&filepath)
+                .map_err(SolidHunterError::from)
+                .and_then(|content| {
+                    let data =
+                        osmium_libs_solidity_ast_extractor::extract::extract_ast_from_content(
+                            &content,
+                        )?;
+                    Ok(SolidFile {
+                        data,
+                        path: filepath.clone(),
+                        content,
+                    })
+                });
+            match parsed {
+                Ok(file) => {
+                    results.push(Ok(files.len()));
+                    files.push(file);
                 }
-                None => {
-                    break;
+                Err(e) => results.push(Err(e)),
+            }
+        }
+        results
+            .into_iter()
+            .map(|result| result.map(|index| file_metrics(&files[index], &files)))
+            .collect()
+    }
+
+    /// Replaces the content given for a file with its content on the disk, the file is removed
+    /// if it cannot be read or parsed anymore.
+    pub fn reload_file(&mut self, path: &str) {
+        if !self._load_file(path) {
+            self.delete_file(path);
+        }
+    }
+
+    pub fn delete_file(&mut self, path: &str) {
+        if let Some(idx) = self.file_indexes.remove(path) {
+            self.files.remove(idx);
+            for file_idx in self.file_indexes.values_mut() {
+                if *file_idx > idx {
+                    *file_idx -= 1;
                 }
             }
         }
@@ -0,0 +1,278 @@
+use crate::linter::SolidFile;
+use crate::rules::imports::visible_bases;
+use osmium_libs_solidity_ast_extractor::*;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct FunctionMetrics {
+    pub name: String,
+    pub line: usize,
+    pub lines: usize,
+    pub cyclomatic_complexity: usize,
+    pub nesting_depth: usize,
+    pub params: usize,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ContractMetrics {
+    pub name: String,
+    pub kind: String,
+    pub line: usize,
+    pub inheritance_depth: usize,
+    pub functions: Vec<FunctionMetrics>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct FileMetrics {
+    pub path: String,
+    pub contracts: Vec<ContractMetrics>,
+}
+
+struct ComplexityVisitor {
+    complexity: usize,
+}
+
+impl<'ast> Visit<'ast> for ComplexityVisitor {
+    fn visit_stmt_if(&mut self, stmt: &'ast StmtIf) {
+        self.complexity += 1;
+        visit::visit_stmt_if(self, stmt);
+    }
+
+    fn visit_stmt_for(&mut self, stmt: &'ast StmtFor) {
+        self.complexity += 1;
+        visit::visit_stmt_for(self, stmt);
+    }
+
+    fn visit_stmt_while(&mut self, stmt: &'ast StmtWhile) {
+        self.complexity += 1;
+        visit::visit_stmt_while(self, stmt);
+    }
+
+    fn visit_stmt_dowhile(&mut self, stmt: &'ast StmtDoWhile) {
+        self.complexity += 1;
+        visit::visit_stmt_dowhile(self, stmt);
+    }
+
+    fn visit_stmt_try(&mut self, stmt: &'ast StmtTry) {
+        self.complexity += stmt.catch.len();
+        visit::visit_stmt_try(self, stmt);
+    }
+
+    fn visit_expr_ternary(&mut self, expr: &'ast ExprTernary) {
+        self.complexity += 1;
+        visit::visit_expr_ternary(self, expr);
+    }
+
+    fn visit_expr_binary(&mut self, expr: &'ast ExprBinary) {
+        if matches!(expr.op, BinOp::And(..) | BinOp::Or(..)) {
+            self.complexity += 1;
+        }
+        visit::visit_expr_binary(self, expr);
+    }
+}
+
+struct NestingVisitor {
+    depth: usize,
+    max_depth: usize,
+}
+
+impl NestingVisitor {
+    fn nested(&mut self, visit: impl FnOnce(&mut Self)) {
+        self.depth += 1;
+        self.max_depth = self.max_depth.max(self.depth);
+        visit(self);
+        self.depth -= 1;
+    }
+}
+
+impl<'ast> Visit<'ast> for NestingVisitor {
+    // `else if` chains are at the depth of their first `if`
+    fn visit_stmt_if(&mut self, stmt: &'ast StmtIf) {
+        self.nested(|v| {
+            v.visit_expr(&stmt.cond);
+            v.visit_stmt(&stmt.then_branch);
+        });
+        match stmt.else_branch.as_ref().map(|(_, branch)| branch.as_ref()) {
+            Some(Stmt::If(else_if)) => self.visit_stmt_if(else_if),
+            Some(branch) => self.nested(|v| v.visit_stmt(branch)),
+            None => {}
+        }
+    }
+
+    fn visit_stmt_for(&mut self, stmt: &'ast StmtFor) {
+        self.nested(|v| visit::visit_stmt_for(v, stmt));
+    }
+
+    fn visit_stmt_while(&mut self, stmt: &'ast StmtWhile) {
+        self.nested(|v| visit::visit_stmt_while(v, stmt));
+    }
+
+    fn visit_stmt_dowhile(&mut self, stmt: &'ast StmtDoWhile) {
+        self.nested(|v| visit::visit_stmt_dowhile(v, stmt));
+    }
+
+    fn visit_stmt_try(&mut self, stmt: &'ast StmtTry) {
+        self.nested(|v| visit::visit_stmt_try(v, stmt));
+    }
+}
+
+/// Number of independent paths through a function: one, plus one per branch, loop, catch clause,
+/// ternary and short-circuit operator.
+pub fn cyclomatic_complexity(function: &ItemFunction) -> usize {
+    let mut visitor = ComplexityVisitor { complexity: 1 };
+    if let FunctionBody::Block(block) = &function.body {
+        visitor.visit_block(block);
+    }
+    visitor.complexity
+}
+
+/// Deepest nesting of branches and loops in a function, 0 for straight-line code.
+pub fn nesting_depth(function: &ItemFunction) -> usize {
+    let mut visitor = NestingVisitor {
+        depth: 0,
+        max_depth: 0,
+    };
+    if let FunctionBody::Block(block) = &function.body {
+        visitor.visit_block(block);
+    }
+    visitor.max_depth
+}
+
+/// Length of the longest chain of bases of a contract, 0 without bases. Bases that are not
+/// declared in `bases` count as a single level.
+pub fn inheritance_depth(bases: &HashMap<String, Vec<String>>, contract: &str) -> usize {
+    fn depth<'a>(
+        bases: &'a HashMap<String, Vec<String>>,
+        contract: &'a str,
+        visiting: &mut HashSet<&'a str>,
+    ) -> usize {
+        // Cyclic inheritance does not compile, stop instead of looping
+        if !visiting.insert(contract) {
+            return 0;
+        }
+        let res = bases
+            .get(contract)
+            .into_iter()
+            .flatten()
+            .map(|base| 1 + depth(bases, base, visiting))
+            .max()
+            .unwrap_or(0);
+        visiting.remove(contract);
+        res
+    }
+    depth(bases, contract, &mut HashSet::new())
+}
+
+// Constructors, fallback and receive functions have no name
+pub fn function_name(function: &ItemFunction) -> String {
+    match &function.name {
+        Some(name) => name.to_string(),
+        None => function.kind.as_str().to_string(),
+    }
+}
+
+fn function_metrics(function: &ItemFunction) -> FunctionMetrics {
+    let lines = match &function.body {
+        FunctionBody::Block(block) => block.span().end().line - block.span().start().line,
+        _ => 0,
+    };
+
+    FunctionMetrics {
+        name: function_name(function),
+        line: function.span().start().line,
+        lines,
+        cyclomatic_complexity: cyclomatic_complexity(function),
+        nesting_depth: nesting_depth(function),
+        params: function.parameters.len(),
+    }
+}
+
+/// Measures the contracts and functions of a file, `files` are the loaded files the bases of its
+/// contracts are looked up in.
+pub fn file_metrics(file: &SolidFile, files: &[SolidFile]) -> FileMetrics {
+    let bases = visible_bases(file, files);
+    let contracts = retriever::retrieve_contract_nodes(&file.data)
+        .iter()
+        .map(|contract| ContractMetrics {
+            name: contract.name.to_string(),
+            kind: contract.kind.to_string(),
+            line: contract.name.span().start().line,
+            inheritance_depth: inheritance_depth(&bases, &contract.name.to_string()),
+            functions: retriever::retrieve_functions_nodes(contract)
+                .iter()
+                .map(function_metrics)
+                .collect(),
+        })
+        .collect();
+
+    FileMetrics {
+        path: file.path.clone(),
+        contracts,
+    }
+}
+
+const METRICS_HEADERS: [&str; 6] = [
+    "Function",
+    "Line",
+    "Lines",
+    "Complexity",
+    "Nesting",
+    "Params",
+];
+
+/// Formats the metrics as a table per contract.
+pub fn format_metrics(metrics: &[FileMetrics]) -> String {
+    let mut output = String::new();
+
+    for file in metrics {
+        for contract in &file.contracts {
+            output.push_str(&format!(
+                "{}:{}: {} {} (inheritance depth {})\n",
+                file.path, contract.line, contract.kind, contract.name, contract.inheritance_depth
+            ));
+            if contract.functions.is_empty() {
+                output.push('\n');
+                continue;
+            }
+            let rows: Vec<[String; 6]> = contract
+                .functions
+                .iter()
+                .map(|function| {
+                    [
+                        function.name.clone(),
+                        function.line.to_string(),
+                        function.lines.to_string(),
+                        function.cyclomatic_complexity.to_string(),
+                        function.nesting_depth.to_string(),
+                        function.params.to_string(),
+                    ]
+                })
+                .collect();
+            let widths: Vec<usize> = (0..METRICS_HEADERS.len())
+                .map(|column| {
+                    rows.iter()
+                        .map(|row| row[column].len())
+                        .chain(std::iter::once(METRICS_HEADERS[column].len()))
+                        .max()
+                        .unwrap_or(0)
+                })
+                .collect();
+            let headers = METRICS_HEADERS.map(|header| header.to_string());
+
+            for row in std::iter::once(&headers).chain(rows.iter()) {
+                let cells: Vec<String> = row
+                    .iter()
+                    .zip(&widths)
+                    .map(|(cell, width)| format!("{:<width$}", cell, width = width))
+                    .collect();
+                output.push_str(&format!("  {}\n", cells.join("  ").trim_end()));
+            }
+            output.push('\n');
+        }
+    }
+    output
+}
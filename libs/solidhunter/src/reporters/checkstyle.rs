@@ -0,0 +1,51 @@
+use super::{escape_xml, sorted_diags, Reporter};
+use crate::types::{LintResult, Severity};
+
+pub struct CheckstyleReporter;
+
+fn checkstyle_severity(severity: Severity) -> &'static str {
+    match severity {
+        Severity::ERROR => "error",
+        Severity::WARNING => "warning",
+        Severity::INFO | Severity::HINT | Severity::OFF => "info",
+    }
+}
+
+impl Reporter for CheckstyleReporter {
+    fn report(&self, results: &[LintResult]) -> String {
+        let mut output = String::from("<?xml version=\"1.0\" encoding=\"utf-8\"?>\n");
+        output.push_str("<checkstyle version=\"4.3\">\n");
+        for result in results {
+            match result {
+                Ok(diags) => {
+                    let diags = sorted_diags(&diags.diags);
+                    // Diagnostics of a file all share its path
+                    let path = match diags.first() {
+                        Some(diag) => &diag.uri,
+                        None => continue,
+                    };
+                    output.push_str(&format!("  <file name=\"{}\">\n", escape_xml(path)));
+                    for diag in diags {
+                        output.push_str(&format!(
+                            "    <error line=\"{}\" column=\"{}\" severity=\"{}\" message=\"{}\" source=\"solidhunter.{}\" />\n",
+                            diag.range.start.line,
+                            diag.range.start.character + 1,
+                            checkstyle_severity(diag.severity),
+                            escape_xml(&diag.message),
+                            escape_xml(&diag.id)
+                        ));
+                    }
+                    output.push_str("  </file>\n");
+                }
+                Err(e) => {
+                    output.push_str(&format!(
+                        "  <file name=\"\">\n    <error severity=\"error\" message=\"{}\" source=\"solidhunter\" />\n  </file>\n",
+                        escape_xml(&e.to_string())
+                    ));
+                }
+            }
+        }
+        output.push_str("</checkstyle>\n");
+        output
+    }
+}
@@ -0,0 +1,30 @@
+use super::{sorted_diags, Reporter};
+use crate::types::LintResult;
+
+/// One line per finding: `path:line:column: severity [rule-id] message`
+pub struct CompactReporter;
+
+impl Reporter for CompactReporter {
+    fn report(&self, results: &[LintResult]) -> String {
+        let mut output = String::new();
+        for result in results {
+            match result {
+                Ok(diags) => {
+                    for diag in sorted_diags(&diags.diags) {
+                        output.push_str(&format!(
+                            "{}:{}:{}: {} [{}] {}\n",
+                            diag.uri,
+                            diag.range.start.line,
+                            diag.range.start.character + 1,
+                            diag.severity.to_str(),
+                            diag.id,
+                            diag.message
+                        ));
+                    }
+                }
+                Err(e) => output.push_str(&format!("error: {}\n", e)),
+            }
+        }
+        output
+    }
+}
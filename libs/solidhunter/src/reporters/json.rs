@@ -0,0 +1,20 @@
+use super::Reporter;
+use crate::types::LintResult;
+
+pub struct JsonReporter;
+
+impl Reporter for JsonReporter {
+    fn report(&self, results: &[LintResult]) -> String {
+        let mut output = String::new();
+        for result in results {
+            match result {
+                Ok(diags) => match serde_json::to_string_pretty(&diags) {
+                    Ok(j) => output.push_str(&format!("{}\n", j)),
+                    Err(e) => output.push_str(&format!("{}\n", e)),
+                },
+                Err(e) => output.push_str(&format!("{}\n", e)),
+            }
+        }
+        output
+    }
+}
@@ -0,0 +1,58 @@
+use super::{escape_xml, sorted_diags, Reporter};
+use crate::types::LintResult;
+
+/// Every file is a test suite and every finding a failed test case
+pub struct JunitReporter;
+
+impl Reporter for JunitReporter {
+    fn report(&self, results: &[LintResult]) -> String {
+        let mut suites = String::new();
+        let mut tests = 0;
+        let mut failures = 0;
+        let mut errors = 0;
+
+        for result in results {
+            match result {
+                Ok(diags) => {
+                    let diags = sorted_diags(&diags.diags);
+                    let path = match diags.first() {
+                        Some(diag) => escape_xml(&diag.uri),
+                        None => continue,
+                    };
+                    tests += diags.len();
+                    failures += diags.len();
+                    suites.push_str(&format!(
+                        "  <testsuite name=\"{}\" tests=\"{}\" failures=\"{}\" errors=\"0\">\n",
+                        path,
+                        diags.len(),
+                        diags.len()
+                    ));
+                    for diag in diags {
+                        let message = escape_xml(&diag.message);
+                        let severity = diag.severity.to_str();
+                        let line = diag.range.start.line;
+                        let column = diag.range.start.character + 1;
+                        suites.push_str(&format!(
+                            "    <testcase name=\"{} {line}:{column}\" classname=\"{path}\">\n      <failure message=\"{message}\" type=\"{severity}\">{severity}: {message} ({path}:{line}:{column})</failure>\n    </testcase>\n",
+                            escape_xml(&diag.id),
+                        ));
+                    }
+                    suites.push_str("  </testsuite>\n");
+                }
+                Err(e) => {
+                    tests += 1;
+                    errors += 1;
+                    suites.push_str(&format!(
+                        "  <testsuite name=\"solidhunter\" tests=\"1\" failures=\"0\" errors=\"1\">\n    <testcase name=\"parsing\" classname=\"solidhunter\">\n      <error message=\"{}\" />\n    </testcase>\n  </testsuite>\n",
+                        escape_xml(&e.to_string())
+                    ));
+                }
+            }
+        }
+
+        format!(
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<testsuites name=\"solidhunter\" tests=\"{}\" failures=\"{}\" errors=\"{}\">\n{}</testsuites>\n",
+            tests, failures, errors, suites
+        )
+    }
+}
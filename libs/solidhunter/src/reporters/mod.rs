@@ -0,0 +1,49 @@
+use crate::types::{LintDiag, LintResult};
+
+mod checkstyle;
+pub use checkstyle::CheckstyleReporter;
+mod compact;
+pub use compact::CompactReporter;
+mod github;
+pub use github::GithubReporter;
+mod json;
+pub use json::JsonReporter;
+mod junit;
+pub use junit::JunitReporter;
+pub mod sarif;
+pub use sarif::SarifReporter;
+mod text;
+pub use text::TextReporter;
+
+pub trait Reporter {
+    /// Formats the results of a lint run into the reporter's output.
+    fn report(&self, results: &[LintResult]) -> String;
+}
+
+// Diagnostics of a file ordered by position, so reports are stable between runs
+pub(crate) fn sorted_diags(diags: &[LintDiag]) -> Vec<&LintDiag> {
+    let mut sorted: Vec<&LintDiag> = diags.iter().collect();
+    sorted.sort_by_key(|diag| {
+        (
+            diag.range.start.line,
+            diag.range.start.character,
+            diag.id.clone(),
+        )
+    });
+    sorted
+}
+
+pub(crate) fn escape_xml(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
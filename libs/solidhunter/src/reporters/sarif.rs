@@ -0,0 +1,245 @@
+use super::Reporter;
+use crate::aggregate::aggregate_diags;
+use crate::rules::types::RuleDocumentation;
+use crate::types::{LintDiag, LintResult, Range, Severity};
+use serde::{Deserialize, Serialize};
+
+const SARIF_SCHEMA: &str = "https://json.schemastore.org/sarif-2.1.0.json";
+const SARIF_VERSION: &str = "2.1.0";
+const TOOL_NAME: &str = "solidhunter";
+const TOOL_URI: &str = "https://github.com/astrodevs-labs/osmium-solidity";
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct SarifLog {
+    #[serde(rename = "$schema")]
+    pub schema: String,
+    pub version: String,
+    pub runs: Vec<SarifRun>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct SarifRun {
+    pub tool: SarifTool,
+    pub invocations: Vec<SarifInvocation>,
+    pub results: Vec<SarifResult>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct SarifTool {
+    pub driver: SarifDriver,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SarifDriver {
+    pub name: String,
+    pub version: String,
+    pub information_uri: String,
+    pub rules: Vec<SarifRule>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SarifRule {
+    pub id: String,
+    pub short_description: SarifMessage,
+    pub help_uri: String,
+    pub default_configuration: SarifConfiguration,
+    pub properties: SarifRuleProperties,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct SarifConfiguration {
+    pub level: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct SarifRuleProperties {
+    pub category: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct SarifMessage {
+    pub text: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SarifInvocation {
+    pub execution_successful: bool,
+    pub tool_execution_notifications: Vec<SarifNotification>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct SarifNotification {
+    pub level: String,
+    pub message: SarifMessage,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SarifResult {
+    pub rule_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rule_index: Option<usize>,
+    pub level: String,
+    pub message: SarifMessage,
+    pub locations: Vec<SarifLocation>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SarifLocation {
+    pub physical_location: SarifPhysicalLocation,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SarifPhysicalLocation {
+    pub artifact_location: SarifArtifactLocation,
+    pub region: SarifRegion,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct SarifArtifactLocation {
+    pub uri: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SarifRegion {
+    pub start_line: usize,
+    pub start_column: usize,
+    pub end_line: usize,
+    pub end_column: usize,
+}
+
+pub fn sarif_level(severity: Severity) -> String {
+    match severity {
+        Severity::ERROR => "error",
+        Severity::WARNING => "warning",
+        Severity::INFO => "note",
+        Severity::HINT | Severity::OFF => "none",
+    }
+    .to_string()
+}
+
+// SARIF uris are relative references using forward slashes
+fn artifact_uri(path: &str) -> String {
+    let path = path.replace('\\', "/");
+    path.strip_prefix("./").unwrap_or(&path).to_string()
+}
+
+fn sarif_location(uri: &str, range: &Range) -> SarifLocation {
+    // SARIF columns start at 1 while solidhunter columns start at 0
+    SarifLocation {
+        physical_location: SarifPhysicalLocation {
+            artifact_location: SarifArtifactLocation {
+                uri: artifact_uri(uri),
+            },
+            region: SarifRegion {
+                start_line: range.start.line,
+                start_column: range.start.character + 1,
+                end_line: range.end.line,
+                end_column: range.end.character + 1,
+            },
+        },
+    }
+}
+
+fn sarif_rule(doc: &RuleDocumentation) -> SarifRule {
+    SarifRule {
+        id: doc.id.clone(),
+        short_description: SarifMessage {
+            text: doc.description.clone(),
+        },
+        help_uri: doc.source_link.clone(),
+        default_configuration: SarifConfiguration {
+            level: sarif_level(doc.severity),
+        },
+        properties: SarifRuleProperties {
+            category: doc.category.clone(),
+        },
+    }
+}
+
+fn sarif_result(diag: &LintDiag, rules: &[RuleDocumentation]) -> SarifResult {
+    let mut locations = vec![sarif_location(&diag.uri, &diag.range)];
+    if let Some(same_line_ranges) = &diag.same_line_ranges {
+        for range in same_line_ranges {
+            locations.push(sarif_location(&diag.uri, range));
+        }
+    }
+
+    SarifResult {
+        rule_id: diag.id.clone(),
+        rule_index: rules.iter().position(|rule| rule.id == diag.id),
+        level: sarif_level(diag.severity),
+        message: SarifMessage {
+            text: diag.message.clone(),
+        },
+        locations,
+    }
+}
+
+pub fn sarif_log(results: &[LintResult], rules: &[RuleDocumentation]) -> SarifLog {
+    let mut sarif_results = Vec::new();
+    let mut notifications = Vec::new();
+
+    for result in results {
+        match result {
+            Ok(file_diags) => {
+                let mut diags = aggregate_diags(file_diags.diags.clone());
+                diags.sort_by_key(|diag| (diag.range.start.line, diag.range.start.character));
+                for diag in &diags {
+                    sarif_results.push(sarif_result(diag, rules));
+                }
+            }
+            Err(e) => notifications.push(SarifNotification {
+                level: "error".to_string(),
+                message: SarifMessage {
+                    text: e.to_string(),
+                },
+            }),
+        }
+    }
+
+    SarifLog {
+        schema: SARIF_SCHEMA.to_string(),
+        version: SARIF_VERSION.to_string(),
+        runs: vec![SarifRun {
+            tool: SarifTool {
+                driver: SarifDriver {
+                    name: TOOL_NAME.to_string(),
+                    version: env!("CARGO_PKG_VERSION").to_string(),
+                    information_uri: TOOL_URI.to_string(),
+                    rules: rules.iter().map(sarif_rule).collect(),
+                },
+            },
+            invocations: vec![SarifInvocation {
+                execution_successful: notifications.is_empty(),
+                tool_execution_notifications: notifications,
+            }],
+            results: sarif_results,
+        }],
+    }
+}
+
+pub struct SarifReporter {
+    rules: Vec<RuleDocumentation>,
+}
+
+impl SarifReporter {
+    pub fn new(rules: Vec<RuleDocumentation>) -> Self {
+        SarifReporter { rules }
+    }
+}
+
+impl Reporter for SarifReporter {
+    fn report(&self, results: &[LintResult]) -> String {
+        match serde_json::to_string_pretty(&sarif_log(results, &self.rules)) {
+            Ok(j) => format!("{}\n", j),
+            Err(e) => format!("{}\n", e),
+        }
+    }
+}
@@ -0,0 +1,25 @@
+use super::Reporter;
+use crate::aggregate::aggregate_diags;
+use crate::types::{FileDiags, LintResult};
+
+pub struct TextReporter;
+
+impl Reporter for TextReporter {
+    fn report(&self, results: &[LintResult]) -> String {
+        let mut output = String::new();
+        for result in results {
+            match result {
+                Ok(diags) => {
+                    let aggregated = aggregate_diags(diags.diags.clone());
+                    let new_file = FileDiags::new(diags.source_file_content.clone(), aggregated);
+                    output.push_str(&new_file.to_string());
+                }
+                Err(e) => {
+                    output.push_str(&format!("{}\n", e));
+                }
+            }
+        }
+        output.push('\n');
+        output
+    }
+}
@@ -0,0 +1,143 @@
+use crate::linter::SolidFile;
+use crate::rules::imports::ImportResolver;
+use crate::rules::types::*;
+use crate::types::*;
+use osmium_libs_solidity_ast_extractor::*;
+use std::collections::VecDeque;
+use std::path::Path;
+
+// global
+pub const RULE_ID: &str = "circular-imports";
+
+// specific
+const DEFAULT_SEVERITY: Severity = Severity::WARNING;
+
+pub struct CircularImports {
+    data: RuleEntry,
+}
+
+// Shortest chain of imports from `start` to `target`, both included
+fn find_import_chain(graph: &[Vec<usize>], start: usize, target: usize) -> Option<Vec<usize>> {
+    let mut previous: Vec<Option<usize>> = vec![None; graph.len()];
+    let mut visited = vec![false; graph.len()];
+    let mut queue = VecDeque::from([start]);
+    visited[start] = true;
+
+    while let Some(current) = queue.pop_front() {
+        if current == target {
+            let mut chain = vec![current];
+            let mut node = current;
+            while let Some(prev) = previous[node] {
+                chain.push(prev);
+                node = prev;
+            }
+            chain.reverse();
+            return Some(chain);
+        }
+        for &next in &graph[current] {
+            if !visited[next] {
+                visited[next] = true;
+                previous[next] = Some(current);
+                queue.push_back(next);
+            }
+        }
+    }
+    None
+}
+
+fn file_name(file: &SolidFile) -> String {
+    Path::new(&file.path)
+        .file_name()
+        .map(|name| name.to_string_lossy().to_string())
+        .unwrap_or(file.path.clone())
+}
+
+impl CircularImports {
+    fn create_diag(&self, file: &SolidFile, import: &ImportDirective, cycle: String) -> LintDiag {
+        LintDiag {
+            id: RULE_ID.to_string(),
+            range: Range {
+                start: Position {
+                    line: import.span().start().line,
+                    character: import.span().start().column,
+                },
+                end: Position {
+                    line: import.span().end().line,
+                    character: import.span().end().column,
+                },
+            },
+            same_line_ranges: None,
+            fix: None,
+            message: format!("Circular import: {}", cycle),
+            severity: self.data.severity,
+            code: None,
+            source: None,
+            uri: file.path.clone(),
+        }
+    }
+}
+
+impl RuleType for CircularImports {
+    fn diagnose(&self, file: &SolidFile, files: &[SolidFile]) -> Vec<LintDiag> {
+        let mut res = Vec::new();
+        let resolver = ImportResolver::new(files);
+        let Some(index) = resolver.index_of(file) else {
+            return res;
+        };
+        let graph = resolver.graph();
+
+        for (import, imported) in resolver.imports_of(index) {
+            if let Some(chain) = find_import_chain(&graph, imported, index) {
+                let cycle: Vec<String> = std::iter::once(index)
+                    .chain(chain)
+                    .map(|i| file_name(&files[i]))
+                    .collect();
+                res.push(self.create_diag(file, &import, cycle.join(" -> ")));
+            }
+        }
+        res
+    }
+
+    fn get_documentation(&self) -> RuleDocumentation {
+        RuleDocumentation {
+            id: RULE_ID.to_string(),
+            severity: DEFAULT_SEVERITY,
+            description: "Files must not import themselves through a chain of imports. Circular imports make the dependencies of the project hard to follow.".to_string(),
+            category: "best-practises".to_string(),
+            example_config: "{\"id\": \"circular-imports\", \"severity\": \"WARNING\"}".to_string(),
+            source_link: "https://github.com/astrodevs-labs/osmium/blob/main/toolchains/solidity/core/crates/linter-lib/src/rules/best_practises/circular_imports.rs".to_string(),
+            test_link: "https://github.com/astrodevs-labs/osmium/tree/main/toolchains/solidity/core/crates/linter-lib/testdata/CircularImports".to_string(),
+            options: vec![],
+            examples: Examples {
+                good: vec![Example {
+                    description: "Imports in one direction".to_string(),
+                    code: "// A.sol\nimport \"./B.sol\";\n// B.sol\ncontract B {}".to_string(),
+                }],
+                bad: vec![Example {
+                    description: "Files importing each other".to_string(),
+                    code: "// A.sol\nimport \"./B.sol\";\n// B.sol\nimport \"./A.sol\";"
+                        .to_string(),
+                }],
+            },
+        }
+    }
+
+    fn is_cross_file(&self) -> bool {
+        true
+    }
+}
+
+impl CircularImports {
+    pub(crate) fn create(data: RuleEntry) -> Box<dyn RuleType> {
+        let rule = CircularImports { data };
+        Box::new(rule)
+    }
+
+    pub(crate) fn create_default() -> RuleEntry {
+        RuleEntry {
+            id: RULE_ID.to_string(),
+            severity: DEFAULT_SEVERITY,
+            data: None,
+        }
+    }
+}
@@ -0,0 +1,109 @@
+use crate::linter::SolidFile;
+use crate::metrics::{cyclomatic_complexity, function_name};
+use crate::rules::types::*;
+use crate::rules::utils::function_range;
+use crate::types::*;
+use osmium_libs_solidity_ast_extractor::*;
+
+// global
+pub const RULE_ID: &str = "code-complexity";
+
+// specific
+const DEFAULT_SEVERITY: Severity = Severity::WARNING;
+const DEFAULT_MAX_COMPLEXITY: usize = 7;
+
+pub struct CodeComplexity {
+    max_complexity: usize,
+    data: RuleEntry,
+}
+
+impl CodeComplexity {
+    fn create_diag(
+        &self,
+        file: &SolidFile,
+        function: &ItemFunction,
+        complexity: usize,
+    ) -> LintDiag {
+        LintDiag {
+            id: RULE_ID.to_string(),
+            range: function_range(function),
+            same_line_ranges: None,
+            fix: None,
+            message: format!(
+                "{} has a cyclomatic complexity of {} but allowed no more than {}",
+                function_name(function),
+                complexity,
+                self.max_complexity
+            ),
+            severity: self.data.severity,
+            code: None,
+            source: None,
+            uri: file.path.clone(),
+        }
+    }
+}
+
+impl RuleType for CodeComplexity {
+    fn diagnose(&self, file: &SolidFile, _files: &[SolidFile]) -> Vec<LintDiag> {
+        let mut res = Vec::new();
+
+        for contract in retriever::retrieve_contract_nodes(&file.data) {
+            for function in retriever::retrieve_functions_nodes(&contract) {
+                let complexity = cyclomatic_complexity(&function);
+                if complexity > self.max_complexity {
+                    res.push(self.create_diag(file, &function, complexity));
+                }
+            }
+        }
+        res
+    }
+
+    fn get_documentation(&self) -> RuleDocumentation {
+        RuleDocumentation {
+            id: RULE_ID.to_string(),
+            severity: DEFAULT_SEVERITY,
+            description: "The cyclomatic complexity of a function, one plus its branches, loops, catch clauses, ternaries and && or || operators, must not exceed the maximum.".to_string(),
+            category: "best-practises".to_string(),
+            example_config: "{\"id\": \"code-complexity\", \"severity\": \"WARNING\", \"data\": 7}".to_string(),
+            source_link: "https://github.com/astrodevs-labs/osmium/blob/main/toolchains/solidity/core/crates/linter-lib/src/rules/best_practises/code_complexity.rs".to_string(),
+            test_link: "https://github.com/astrodevs-labs/osmium/tree/main/toolchains/solidity/core/crates/linter-lib/testdata/CodeComplexity".to_string(),
+            options: vec![Options {
+                description: "Maximum cyclomatic complexity per function".to_string(),
+                default: DEFAULT_MAX_COMPLEXITY.to_string(),
+            }],
+            examples: Examples {
+                good: vec![],
+                bad: vec![],
+            },
+        }
+    }
+}
+
+impl CodeComplexity {
+    pub(crate) fn create(data: RuleEntry) -> Box<dyn RuleType> {
+        let mut max_complexity = DEFAULT_MAX_COMPLEXITY;
+
+        if let Some(data) = &data.data {
+            let parsed: Result<usize, serde_json::Error> = serde_json::from_value(data.clone());
+            match parsed {
+                Ok(val) => max_complexity = val,
+                Err(_) => {
+                    eprintln!("{} rule : bad config data", RULE_ID);
+                }
+            }
+        }
+        let rule = CodeComplexity {
+            max_complexity,
+            data,
+        };
+        Box::new(rule)
+    }
+
+    pub(crate) fn create_default() -> RuleEntry {
+        RuleEntry {
+            id: RULE_ID.to_string(),
+            severity: DEFAULT_SEVERITY,
+            data: Some(DEFAULT_MAX_COMPLEXITY.into()),
+        }
+    }
+}
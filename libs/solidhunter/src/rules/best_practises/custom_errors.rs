@@ -37,6 +37,7 @@ impl CustomErrors {
             code: None,
             source: None,
             same_line_ranges: None,
+            fix: None,
             uri: file.path.clone(),
         }
     }
@@ -0,0 +1,107 @@
+use crate::linter::SolidFile;
+use crate::rules::types::*;
+use crate::types::*;
+use osmium_libs_solidity_ast_extractor::*;
+
+// global
+pub const RULE_ID: &str = "duplicate-contract-name";
+
+// specific
+const DEFAULT_SEVERITY: Severity = Severity::WARNING;
+
+pub struct DuplicateContractName {
+    data: RuleEntry,
+}
+
+impl DuplicateContractName {
+    fn create_diag(&self, file: &SolidFile, name: &SolIdent, other: &str) -> LintDiag {
+        LintDiag {
+            id: RULE_ID.to_string(),
+            range: Range {
+                start: Position {
+                    line: name.span().start().line,
+                    character: name.span().start().column,
+                },
+                end: Position {
+                    line: name.span().end().line,
+                    character: name.span().end().column,
+                },
+            },
+            same_line_ranges: None,
+            fix: None,
+            message: format!("{} is also defined in {}", name, other),
+            severity: self.data.severity,
+            code: None,
+            source: None,
+            uri: file.path.clone(),
+        }
+    }
+}
+
+impl RuleType for DuplicateContractName {
+    fn diagnose(&self, file: &SolidFile, files: &[SolidFile]) -> Vec<LintDiag> {
+        let mut res = Vec::new();
+
+        for contract in retriever::retrieve_contract_nodes(&file.data) {
+            let name = contract.name.to_string();
+            let others: Vec<&str> = files
+                .iter()
+                .filter(|other| other.path != file.path)
+                .filter(|other| {
+                    retriever::retrieve_contract_nodes(&other.data)
+                        .iter()
+                        .any(|other_contract| other_contract.name == name)
+                })
+                .map(|other| other.path.as_str())
+                .collect();
+            if !others.is_empty() {
+                res.push(self.create_diag(file, &contract.name, &others.join(", ")));
+            }
+        }
+        res
+    }
+
+    fn get_documentation(&self) -> RuleDocumentation {
+        RuleDocumentation {
+            id: RULE_ID.to_string(),
+            severity: DEFAULT_SEVERITY,
+            description: "Contracts, interfaces and libraries must have a unique name across the project, the compilation artifacts and imports of two contracts with the same name conflict.".to_string(),
+            category: "best-practises".to_string(),
+            example_config: "{\"id\": \"duplicate-contract-name\", \"severity\": \"WARNING\"}".to_string(),
+            source_link: "https://github.com/astrodevs-labs/osmium/blob/main/toolchains/solidity/core/crates/linter-lib/src/rules/best_practises/duplicate_contract_name.rs".to_string(),
+            test_link: "https://github.com/astrodevs-labs/osmium/tree/main/toolchains/solidity/core/crates/linter-lib/testdata/DuplicateContractName".to_string(),
+            options: vec![],
+            examples: Examples {
+                good: vec![Example {
+                    description: "Unique names".to_string(),
+                    code: "// Token.sol\ncontract Token {}\n// Vault.sol\ncontract Vault {}"
+                        .to_string(),
+                }],
+                bad: vec![Example {
+                    description: "Same name in two files".to_string(),
+                    code: "// Token.sol\ncontract Token {}\n// mocks/Token.sol\ncontract Token {}"
+                        .to_string(),
+                }],
+            },
+        }
+    }
+
+    fn is_cross_file(&self) -> bool {
+        true
+    }
+}
+
+impl DuplicateContractName {
+    pub(crate) fn create(data: RuleEntry) -> Box<dyn RuleType> {
+        let rule = DuplicateContractName { data };
+        Box::new(rule)
+    }
+
+    pub(crate) fn create_default() -> RuleEntry {
+        RuleEntry {
+            id: RULE_ID.to_string(),
+            severity: DEFAULT_SEVERITY,
+            data: None,
+        }
+    }
+}
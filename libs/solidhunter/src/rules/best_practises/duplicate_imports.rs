@@ -0,0 +1,184 @@
+use crate::linter::SolidFile;
+use crate::rules::imports::{import_deletion_range, import_path, import_range, normalize_path};
+use crate::rules::types::*;
+use crate::types::*;
+use osmium_libs_solidity_ast_extractor::*;
+use std::path::Path;
+
+// global
+pub const RULE_ID: &str = "duplicate-imports";
+
+// specific
+const DEFAULT_SEVERITY: Severity = Severity::WARNING;
+
+pub struct DuplicateImports {
+    data: RuleEntry,
+}
+
+// `./lib/../A.sol` and `./A.sol` import the same file
+fn import_key(import: &ImportDirective) -> String {
+    normalize_path(Path::new(&import_path(import)))
+        .to_string_lossy()
+        .to_string()
+}
+
+// Symbols of an `import {A, B as C} from "path";` directive, written as in the source
+fn imported_symbols(import: &ImportDirective) -> Option<Vec<String>> {
+    match &import.path {
+        ImportPath::Aliases(aliases) => Some(
+            aliases
+                .imports
+                .iter()
+                .map(|(name, alias)| match alias {
+                    Some(alias) => format!("{} {}", name, alias),
+                    None => name.to_string(),
+                })
+                .collect(),
+        ),
+        _ => None,
+    }
+}
+
+// Replace the symbols between the braces of an import, keeping its formatting
+fn with_symbols(text: &str, symbols: &[String]) -> Option<String> {
+    let open = text.find('{')?;
+    let close = text.find('}')?;
+    let padding = if text[open + 1..].starts_with(' ') {
+        " "
+    } else {
+        ""
+    };
+    Some(format!(
+        "{}{}{}{}{}",
+        &text[..open + 1],
+        padding,
+        symbols.join(", "),
+        padding,
+        &text[close..]
+    ))
+}
+
+impl DuplicateImports {
+    fn create_diag(
+        &self,
+        file: &SolidFile,
+        import: &ImportDirective,
+        first: &ImportDirective,
+    ) -> LintDiag {
+        LintDiag {
+            id: RULE_ID.to_string(),
+            range: import_range(import),
+            same_line_ranges: None,
+            fix: None,
+            message: format!(
+                "\"{}\" is already imported line {}",
+                import_path(import),
+                first.span().start().line
+            ),
+            severity: self.data.severity,
+            code: None,
+            source: None,
+            uri: file.path.clone(),
+        }
+    }
+
+    // The first import of the path the import at `index` duplicates
+    fn first_import(imports: &[ImportDirective], index: usize) -> Option<&ImportDirective> {
+        let key = import_key(&imports[index]);
+        imports[..index]
+            .iter()
+            .find(|import| import_key(import) == key)
+    }
+}
+
+impl RuleType for DuplicateImports {
+    fn diagnose(&self, file: &SolidFile, _files: &[SolidFile]) -> Vec<LintDiag> {
+        let mut res = Vec::new();
+        let imports = retriever::retrieve_import_directive_nodes(&file.data);
+
+        for (index, import) in imports.iter().enumerate() {
+            if let Some(first) = Self::first_import(&imports, index) {
+                res.push(self.create_diag(file, import, first));
+            }
+        }
+        res
+    }
+
+    fn get_documentation(&self) -> RuleDocumentation {
+        RuleDocumentation {
+            id: RULE_ID.to_string(),
+            severity: DEFAULT_SEVERITY,
+            description: "A file must be imported once. The symbols imported from the same file are merged in the first import.".to_string(),
+            category: "best-practises".to_string(),
+            example_config: "{\"id\": \"duplicate-imports\", \"severity\": \"WARNING\"}".to_string(),
+            source_link: "https://github.com/astrodevs-labs/osmium/blob/main/toolchains/solidity/core/crates/linter-lib/src/rules/best_practises/duplicate_imports.rs".to_string(),
+            test_link: "https://github.com/astrodevs-labs/osmium/tree/main/toolchains/solidity/core/crates/linter-lib/testdata/DuplicateImports".to_string(),
+            options: vec![],
+            examples: Examples {
+                good: vec![Example {
+                    description: "Symbols imported together".to_string(),
+                    code: "import {A, B} from \"./A.sol\";".to_string(),
+                }],
+                bad: vec![Example {
+                    description: "Same file imported twice".to_string(),
+                    code: "import {A} from \"./A.sol\";\nimport {B} from \"./A.sol\";".to_string(),
+                }],
+            },
+        }
+    }
+
+    fn fix(&self, file: &SolidFile, _files: &[SolidFile], diag: &LintDiag) -> Option<Fix> {
+        let imports = retriever::retrieve_import_directive_nodes(&file.data);
+        let index = imports
+            .iter()
+            .position(|import| import_range(import) == diag.range)?;
+        let import = &imports[index];
+        let first = Self::first_import(&imports, index)?;
+        let deletion = TextEdit {
+            range: import_deletion_range(&file.content, import),
+            new_text: String::new(),
+        };
+
+        // An identical import is removed, the symbols of two imports with braces are merged
+        if import.to_string() == first.to_string() {
+            return Some(Fix {
+                message: format!("Remove duplicated import of \"{}\"", import_path(import)),
+                edits: vec![deletion],
+            });
+        }
+        let mut symbols = imported_symbols(first)?;
+        let added: Vec<String> = imported_symbols(import)?
+            .into_iter()
+            .filter(|symbol| !symbols.contains(symbol))
+            .collect();
+        if added.is_empty() {
+            return Some(Fix {
+                message: format!("Remove duplicated import of \"{}\"", import_path(import)),
+                edits: vec![deletion],
+            });
+        }
+        symbols.extend(added);
+        let range = import_range(first);
+        let new_text = with_symbols(range.text(&file.content)?, &symbols)?;
+
+        Some(Fix {
+            message: format!("Merge the imports of \"{}\"", import_path(import)),
+            edits: vec![TextEdit { range, new_text }, deletion],
+        })
+    }
+}
+
+impl DuplicateImports {
+    pub(crate) fn create(data: RuleEntry) -> Box<dyn RuleType> {
+        let rule = DuplicateImports { data };
+        Box::new(rule)
+    }
+
+    pub(crate) fn create_default() -> RuleEntry {
+        RuleEntry {
+            id: RULE_ID.to_string(),
+            severity: DEFAULT_SEVERITY,
+            data: None,
+        }
+    }
+}
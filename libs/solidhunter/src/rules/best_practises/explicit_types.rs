@@ -12,14 +12,24 @@ pub const RULE_ID: &str = "explicit-types";
 // specific
 const DEFAULT_RULE: &str = "explicit";
 const DEFAULT_SEVERITY: Severity = Severity::WARNING;
+// Implicit types and the explicit types they stand for
+const TYPE_ALIASES: [(&str, &str); 4] = [
+    ("uint", "uint256"),
+    ("int", "int256"),
+    ("ufixed", "ufixed128x18"),
+    ("fixed", "fixed128x18"),
+];
 
 pub struct ExplicitTypes {
     rule: String,
     data: RuleEntry,
+    // Types replaced by the fix and their replacements, compiled when the rule is created
+    aliases: Vec<(Regex, &'static str)>,
 }
 
 pub struct ExplicitTypesVisitor {
     explicit: bool,
+    sized: Regex,
     defs: Vec<VariableDefinition>,
     decls: Vec<VariableDeclaration>,
     types: Vec<Type>,
@@ -50,11 +60,10 @@ impl<'ast> Visit<'ast> for ExplicitTypesVisitor {
 
 impl ExplicitTypesVisitor {
     fn is_type_match(&self, ty: &Type) -> bool {
-        let re = Regex::new(r"\d$").unwrap();
         if self.explicit {
-            !re.is_match(&ty.to_string())
+            !self.sized.is_match(&ty.to_string())
         } else {
-            re.is_match(&ty.to_string())
+            self.sized.is_match(&ty.to_string())
         }
     }
 }
@@ -82,6 +91,7 @@ impl ExplicitTypes {
             code: None,
             source: None,
             same_line_ranges: None,
+            fix: None,
             uri: file.path.clone(),
         }
     }
@@ -92,6 +102,7 @@ impl RuleType for ExplicitTypes {
         let mut res = Vec::new();
         let mut visitor = ExplicitTypesVisitor {
             explicit: self.rule == "explicit",
+            sized: Regex::new(r"\d$").unwrap(),
             defs: vec![],
             decls: vec![],
             types: vec![],
@@ -160,6 +171,25 @@ impl RuleType for ExplicitTypes {
             },
         }
     }
+
+    fn fix(&self, file: &SolidFile, _files: &[SolidFile], diag: &LintDiag) -> Option<Fix> {
+        let ty = diag.range.text(&file.content)?;
+        let mut new_ty = ty.to_string();
+
+        for (re, to) in &self.aliases {
+            new_ty = re.replace_all(&new_ty, *to).to_string();
+        }
+        if new_ty == ty {
+            return None;
+        }
+        Some(Fix {
+            message: format!("Replace '{}' with '{}'", ty, new_ty),
+            edits: vec![TextEdit {
+                range: diag.range.clone(),
+                new_text: new_ty,
+            }],
+        })
+    }
 }
 
 impl ExplicitTypes {
@@ -177,7 +207,22 @@ impl ExplicitTypes {
         } else {
             eprintln!("{} rule : bad config data", RULE_ID);
         }
-        let rule = ExplicitTypes { rule: value, data };
+        let aliases = TYPE_ALIASES
+            .into_iter()
+            .map(|(implicit, sized)| {
+                let (from, to) = if value == "explicit" {
+                    (implicit, sized)
+                } else {
+                    (sized, implicit)
+                };
+                (Regex::new(&format!(r"\b{}\b", from)).unwrap(), to)
+            })
+            .collect();
+        let rule = ExplicitTypes {
+            rule: value,
+            data,
+            aliases,
+        };
         Box::new(rule)
     }
     pub(crate) fn create_default() -> RuleEntry {
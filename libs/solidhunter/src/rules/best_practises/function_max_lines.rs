@@ -38,6 +38,7 @@ impl RuleType for FunctionMaxLines {
                         code: None,
                         source: None,
                         same_line_ranges: None,
+                        fix: None,
                         message: format!(
                             "Function body contains {} lines but allowed no more than {} lines",
                             end - start,
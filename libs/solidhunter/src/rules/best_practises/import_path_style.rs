@@ -0,0 +1,135 @@
+use crate::linter::SolidFile;
+use crate::rules::imports::{import_path, Remappings};
+use crate::rules::types::*;
+use crate::types::*;
+use osmium_libs_solidity_ast_extractor::*;
+use std::path::Path;
+
+// global
+pub const RULE_ID: &str = "import-path-style";
+
+// specific
+const DEFAULT_SEVERITY: Severity = Severity::WARNING;
+
+pub struct ImportPathStyle {
+    data: RuleEntry,
+}
+
+fn path_range(import: &ImportDirective) -> Range {
+    let path = import.path.path();
+    Range {
+        start: Position {
+            line: path.span().start().line,
+            character: path.span().start().column,
+        },
+        end: Position {
+            line: path.span().end().line,
+            character: path.span().end().column,
+        },
+    }
+}
+
+impl ImportPathStyle {
+    fn create_diag(&self, file: &SolidFile, import: &ImportDirective, remapped: &str) -> LintDiag {
+        LintDiag {
+            id: RULE_ID.to_string(),
+            range: path_range(import),
+            same_line_ranges: None,
+            fix: None,
+            message: format!(
+                "Import \"{}\" with the remapped path \"{}\" instead of a relative path",
+                import_path(import),
+                remapped
+            ),
+            severity: self.data.severity,
+            code: None,
+            source: None,
+            uri: file.path.clone(),
+        }
+    }
+
+    // Remapped path of an import leaving the directory of the file
+    fn remapped_path(
+        file: &SolidFile,
+        remappings: &Remappings,
+        import: &ImportDirective,
+    ) -> Option<String> {
+        let path = import_path(import);
+        if !path.starts_with("../") {
+            return None;
+        }
+        let directory = Path::new(&file.path).parent()?;
+        remappings.remap(&directory.join(path))
+    }
+}
+
+impl RuleType for ImportPathStyle {
+    fn diagnose(&self, file: &SolidFile, _files: &[SolidFile]) -> Vec<LintDiag> {
+        let mut res = Vec::new();
+        let Some(remappings) = Remappings::find(&file.path) else {
+            return res;
+        };
+
+        for import in retriever::retrieve_import_directive_nodes(&file.data) {
+            if let Some(remapped) = Self::remapped_path(file, &remappings, &import) {
+                res.push(self.create_diag(file, &import, &remapped));
+            }
+        }
+        res
+    }
+
+    fn get_documentation(&self) -> RuleDocumentation {
+        RuleDocumentation {
+            id: RULE_ID.to_string(),
+            severity: DEFAULT_SEVERITY,
+            description: "Imports reaching a parent directory must use the remappings of the project, read from the remappings.txt and foundry.toml files. Imports from the directory of the file can stay relative.".to_string(),
+            category: "best-practises".to_string(),
+            example_config: "{\"id\": \"import-path-style\", \"severity\": \"WARNING\"}".to_string(),
+            source_link: "https://github.com/astrodevs-labs/osmium/blob/main/toolchains/solidity/core/crates/linter-lib/src/rules/best_practises/import_path_style.rs".to_string(),
+            test_link: "https://github.com/astrodevs-labs/osmium/tree/main/toolchains/solidity/core/crates/linter-lib/testdata/ImportPathStyle".to_string(),
+            options: vec![],
+            examples: Examples {
+                good: vec![Example {
+                    description: "Remapped path, with src/=src/ in remappings.txt".to_string(),
+                    code: "import {Token} from \"src/tokens/Token.sol\";".to_string(),
+                }],
+                bad: vec![Example {
+                    description: "Relative path to a parent directory".to_string(),
+                    code: "import {Token} from \"../../src/tokens/Token.sol\";".to_string(),
+                }],
+            },
+        }
+    }
+
+    fn fix(&self, file: &SolidFile, _files: &[SolidFile], diag: &LintDiag) -> Option<Fix> {
+        let remappings = Remappings::find(&file.path)?;
+        let import = retriever::retrieve_import_directive_nodes(&file.data)
+            .into_iter()
+            .find(|import| path_range(import) == diag.range)?;
+        let remapped = Self::remapped_path(file, &remappings, &import)?;
+        let quote = diag.range.text(&file.content)?.chars().next()?;
+
+        Some(Fix {
+            message: format!("Import \"{}\"", remapped),
+            edits: vec![TextEdit {
+                range: diag.range.clone(),
+                new_text: format!("{}{}{}", quote, remapped, quote),
+            }],
+        })
+    }
+}
+
+impl ImportPathStyle {
+    pub(crate) fn create(data: RuleEntry) -> Box<dyn RuleType> {
+        let rule = ImportPathStyle { data };
+        Box::new(rule)
+    }
+
+    pub(crate) fn create_default() -> RuleEntry {
+        RuleEntry {
+            id: RULE_ID.to_string(),
+            severity: DEFAULT_SEVERITY,
+            data: None,
+        }
+    }
+}
@@ -0,0 +1,106 @@
+use crate::linter::SolidFile;
+use crate::metrics::function_name;
+use crate::rules::types::*;
+use crate::rules::utils::function_range;
+use crate::types::*;
+use osmium_libs_solidity_ast_extractor::*;
+
+// global
+pub const RULE_ID: &str = "max-function-params";
+
+// specific
+const DEFAULT_SEVERITY: Severity = Severity::WARNING;
+const DEFAULT_MAX_PARAMS: usize = 5;
+
+pub struct MaxFunctionParams {
+    max_params: usize,
+    data: RuleEntry,
+}
+
+impl MaxFunctionParams {
+    fn create_diag(&self, file: &SolidFile, function: &ItemFunction) -> LintDiag {
+        LintDiag {
+            id: RULE_ID.to_string(),
+            range: function_range(function),
+            same_line_ranges: None,
+            fix: None,
+            message: format!(
+                "{} has {} parameters but allowed no more than {}",
+                function_name(function),
+                function.parameters.len(),
+                self.max_params
+            ),
+            severity: self.data.severity,
+            code: None,
+            source: None,
+            uri: file.path.clone(),
+        }
+    }
+}
+
+impl RuleType for MaxFunctionParams {
+    fn diagnose(&self, file: &SolidFile, _files: &[SolidFile]) -> Vec<LintDiag> {
+        let mut res = Vec::new();
+
+        for contract in retriever::retrieve_contract_nodes(&file.data) {
+            for function in retriever::retrieve_functions_nodes(&contract) {
+                if function.parameters.len() > self.max_params {
+                    res.push(self.create_diag(file, &function));
+                }
+            }
+        }
+        res
+    }
+
+    fn get_documentation(&self) -> RuleDocumentation {
+        RuleDocumentation {
+            id: RULE_ID.to_string(),
+            severity: DEFAULT_SEVERITY,
+            description: "Functions, constructors and modifiers must not take more parameters than the maximum, group them in a struct instead.".to_string(),
+            category: "best-practises".to_string(),
+            example_config: "{\"id\": \"max-function-params\", \"severity\": \"WARNING\", \"data\": 5}".to_string(),
+            source_link: "https://github.com/astrodevs-labs/osmium/blob/main/toolchains/solidity/core/crates/linter-lib/src/rules/best_practises/max_function_params.rs".to_string(),
+            test_link: "https://github.com/astrodevs-labs/osmium/tree/main/toolchains/solidity/core/crates/linter-lib/testdata/MaxFunctionParams".to_string(),
+            options: vec![Options {
+                description: "Maximum number of parameters per function".to_string(),
+                default: DEFAULT_MAX_PARAMS.to_string(),
+            }],
+            examples: Examples {
+                good: vec![Example {
+                    description: "Parameters grouped in a struct".to_string(),
+                    code: "function open(Position calldata position) external {}".to_string(),
+                }],
+                bad: vec![Example {
+                    description: "Six parameters".to_string(),
+                    code: "function open(address a, address b, uint256 c, uint256 d, bool e, bytes calldata f) external {}".to_string(),
+                }],
+            },
+        }
+    }
+}
+
+impl MaxFunctionParams {
+    pub(crate) fn create(data: RuleEntry) -> Box<dyn RuleType> {
+        let mut max_params = DEFAULT_MAX_PARAMS;
+
+        if let Some(data) = &data.data {
+            let parsed: Result<usize, serde_json::Error> = serde_json::from_value(data.clone());
+            match parsed {
+                Ok(val) => max_params = val,
+                Err(_) => {
+                    eprintln!("{} rule : bad config data", RULE_ID);
+                }
+            }
+        }
+        let rule = MaxFunctionParams { max_params, data };
+        Box::new(rule)
+    }
+
+    pub(crate) fn create_default() -> RuleEntry {
+        RuleEntry {
+            id: RULE_ID.to_string(),
+            severity: DEFAULT_SEVERITY,
+            data: Some(DEFAULT_MAX_PARAMS.into()),
+        }
+    }
+}
@@ -0,0 +1,111 @@
+use crate::linter::SolidFile;
+use crate::metrics::inheritance_depth;
+use crate::rules::imports::visible_bases;
+use crate::rules::types::*;
+use crate::types::*;
+use osmium_libs_solidity_ast_extractor::*;
+
+// global
+pub const RULE_ID: &str = "max-inheritance-depth";
+
+// specific
+const DEFAULT_SEVERITY: Severity = Severity::WARNING;
+const DEFAULT_MAX_DEPTH: usize = 4;
+
+pub struct MaxInheritanceDepth {
+    max_depth: usize,
+    data: RuleEntry,
+}
+
+impl MaxInheritanceDepth {
+    fn create_diag(&self, file: &SolidFile, contract: &ItemContract, depth: usize) -> LintDiag {
+        LintDiag {
+            id: RULE_ID.to_string(),
+            range: Range {
+                start: Position {
+                    line: contract.name.span().start().line,
+                    character: contract.name.span().start().column,
+                },
+                end: Position {
+                    line: contract.name.span().end().line,
+                    character: contract.name.span().end().column,
+                },
+            },
+            same_line_ranges: None,
+            fix: None,
+            message: format!(
+                "{} inherits through {} levels of bases but allowed no more than {}",
+                contract.name, depth, self.max_depth
+            ),
+            severity: self.data.severity,
+            code: None,
+            source: None,
+            uri: file.path.clone(),
+        }
+    }
+}
+
+impl RuleType for MaxInheritanceDepth {
+    fn diagnose(&self, file: &SolidFile, files: &[SolidFile]) -> Vec<LintDiag> {
+        let mut res = Vec::new();
+        let bases = visible_bases(file, files);
+
+        for contract in retriever::retrieve_contract_nodes(&file.data) {
+            let depth = inheritance_depth(&bases, &contract.name.to_string());
+            if depth > self.max_depth {
+                res.push(self.create_diag(file, &contract, depth));
+            }
+        }
+        res
+    }
+
+    fn get_documentation(&self) -> RuleDocumentation {
+        RuleDocumentation {
+            id: RULE_ID.to_string(),
+            severity: DEFAULT_SEVERITY,
+            description: "The longest chain of bases of a contract, including the bases imported from other files, must not exceed the maximum.".to_string(),
+            category: "best-practises".to_string(),
+            example_config: "{\"id\": \"max-inheritance-depth\", \"severity\": \"WARNING\", \"data\": 4}".to_string(),
+            source_link: "https://github.com/astrodevs-labs/osmium/blob/main/toolchains/solidity/core/crates/linter-lib/src/rules/best_practises/max_inheritance_depth.rs".to_string(),
+            test_link: "https://github.com/astrodevs-labs/osmium/tree/main/toolchains/solidity/core/crates/linter-lib/testdata/MaxInheritanceDepth".to_string(),
+            options: vec![Options {
+                description: "Maximum inheritance depth per contract".to_string(),
+                default: DEFAULT_MAX_DEPTH.to_string(),
+            }],
+            examples: Examples {
+                good: vec![],
+                bad: vec![],
+            },
+        }
+    }
+
+    fn is_cross_file(&self) -> bool {
+        true
+    }
+}
+
+impl MaxInheritanceDepth {
+    pub(crate) fn create(data: RuleEntry) -> Box<dyn RuleType> {
+        let mut max_depth = DEFAULT_MAX_DEPTH;
+
+        if let Some(data) = &data.data {
+            let parsed: Result<usize, serde_json::Error> = serde_json::from_value(data.clone());
+            match parsed {
+                Ok(val) => max_depth = val,
+                Err(_) => {
+                    eprintln!("{} rule : bad config data", RULE_ID);
+                }
+            }
+        }
+        let rule = MaxInheritanceDepth { max_depth, data };
+        Box::new(rule)
+    }
+
+    pub(crate) fn create_default() -> RuleEntry {
+        RuleEntry {
+            id: RULE_ID.to_string(),
+            severity: DEFAULT_SEVERITY,
+            data: Some(DEFAULT_MAX_DEPTH.into()),
+        }
+    }
+}
@@ -0,0 +1,104 @@
+use crate::linter::SolidFile;
+use crate::metrics::{function_name, nesting_depth};
+use crate::rules::types::*;
+use crate::rules::utils::function_range;
+use crate::types::*;
+use osmium_libs_solidity_ast_extractor::*;
+
+// global
+pub const RULE_ID: &str = "max-nesting-depth";
+
+// specific
+const DEFAULT_SEVERITY: Severity = Severity::WARNING;
+const DEFAULT_MAX_DEPTH: usize = 4;
+
+pub struct MaxNestingDepth {
+    max_depth: usize,
+    data: RuleEntry,
+}
+
+impl MaxNestingDepth {
+    fn create_diag(&self, file: &SolidFile, function: &ItemFunction, depth: usize) -> LintDiag {
+        LintDiag {
+            id: RULE_ID.to_string(),
+            range: function_range(function),
+            same_line_ranges: None,
+            fix: None,
+            message: format!(
+                "{} nests branches and loops {} levels deep but allowed no more than {}",
+                function_name(function),
+                depth,
+                self.max_depth
+            ),
+            severity: self.data.severity,
+            code: None,
+            source: None,
+            uri: file.path.clone(),
+        }
+    }
+}
+
+impl RuleType for MaxNestingDepth {
+    fn diagnose(&self, file: &SolidFile, _files: &[SolidFile]) -> Vec<LintDiag> {
+        let mut res = Vec::new();
+
+        for contract in retriever::retrieve_contract_nodes(&file.data) {
+            for function in retriever::retrieve_functions_nodes(&contract) {
+                let depth = nesting_depth(&function);
+                if depth > self.max_depth {
+                    res.push(self.create_diag(file, &function, depth));
+                }
+            }
+        }
+        res
+    }
+
+    fn get_documentation(&self) -> RuleDocumentation {
+        RuleDocumentation {
+            id: RULE_ID.to_string(),
+            severity: DEFAULT_SEVERITY,
+            description: "Branches and loops must not be nested deeper than the maximum. `else if` chains count as a single level.".to_string(),
+            category: "best-practises".to_string(),
+            example_config: "{\"id\": \"max-nesting-depth\", \"severity\": \"WARNING\", \"data\": 4}".to_string(),
+            source_link: "https://github.com/astrodevs-labs/osmium/blob/main/toolchains/solidity/core/crates/linter-lib/src/rules/best_practises/max_nesting_depth.rs".to_string(),
+            test_link: "https://github.com/astrodevs-labs/osmium/tree/main/toolchains/solidity/core/crates/linter-lib/testdata/MaxNestingDepth".to_string(),
+            options: vec![Options {
+                description: "Maximum nesting depth per function".to_string(),
+                default: DEFAULT_MAX_DEPTH.to_string(),
+            }],
+            examples: Examples {
+                good: vec![Example {
+                    description: "Early return instead of nesting".to_string(),
+                    code: "if (!enabled) return;\nfor (uint256 i; i < n; ++i) {}".to_string(),
+                }],
+                bad: vec![],
+            },
+        }
+    }
+}
+
+impl MaxNestingDepth {
+    pub(crate) fn create(data: RuleEntry) -> Box<dyn RuleType> {
+        let mut max_depth = DEFAULT_MAX_DEPTH;
+
+        if let Some(data) = &data.data {
+            let parsed: Result<usize, serde_json::Error> = serde_json::from_value(data.clone());
+            match parsed {
+                Ok(val) => max_depth = val,
+                Err(_) => {
+                    eprintln!("{} rule : bad config data", RULE_ID);
+                }
+            }
+        }
+        let rule = MaxNestingDepth { max_depth, data };
+        Box::new(rule)
+    }
+
+    pub(crate) fn create_default() -> RuleEntry {
+        RuleEntry {
+            id: RULE_ID.to_string(),
+            severity: DEFAULT_SEVERITY,
+            data: Some(DEFAULT_MAX_DEPTH.into()),
+        }
+    }
+}
@@ -3,9 +3,17 @@ use std::collections::HashMap;
 
 #[macro_use]
 pub mod max_line_length;
+pub mod circular_imports;
+pub mod code_complexity;
 pub mod custom_errors;
+pub mod duplicate_contract_name;
+pub mod duplicate_imports;
 pub mod explicit_types;
 pub mod function_max_lines;
+pub mod import_path_style;
+pub mod max_function_params;
+pub mod max_inheritance_depth;
+pub mod max_nesting_depth;
 pub mod max_states_count;
 pub mod no_console;
 pub mod no_empty_block;
@@ -13,12 +21,21 @@ pub mod no_global_import;
 pub mod one_contract_per_file;
 pub mod payable_fallback;
 pub mod reason_string;
+pub mod unused_file;
 
 // List all rules
+use crate::rules::best_practises::circular_imports::CircularImports;
+use crate::rules::best_practises::code_complexity::CodeComplexity;
 use crate::rules::best_practises::custom_errors::CustomErrors;
+use crate::rules::best_practises::duplicate_contract_name::DuplicateContractName;
+use crate::rules::best_practises::duplicate_imports::DuplicateImports;
 use crate::rules::best_practises::explicit_types::ExplicitTypes;
 use crate::rules::best_practises::function_max_lines::FunctionMaxLines;
+use crate::rules::best_practises::import_path_style::ImportPathStyle;
+use crate::rules::best_practises::max_function_params::MaxFunctionParams;
+use crate::rules::best_practises::max_inheritance_depth::MaxInheritanceDepth;
 use crate::rules::best_practises::max_line_length::MaxLineLength;
+use crate::rules::best_practises::max_nesting_depth::MaxNestingDepth;
 use crate::rules::best_practises::max_states_count::MaxStatesCount;
 use crate::rules::best_practises::no_console::NoConsole;
 use crate::rules::best_practises::no_empty_block::NoEmptyBlock;
@@ -26,6 +43,7 @@ use crate::rules::best_practises::no_global_import::NoGlobalImport;
 use crate::rules::best_practises::one_contract_per_file::OneContractPerFile;
 use crate::rules::best_practises::payable_fallback::PayableFallback;
 use crate::rules::best_practises::reason_string::ReasonString;
+use crate::rules::best_practises::unused_file::UnusedFile;
 use crate::rules::RuleBuilder;
 
 pub fn create_default_rules() -> Vec<RuleEntry> {
@@ -41,6 +59,15 @@ pub fn create_default_rules() -> Vec<RuleEntry> {
         NoEmptyBlock::create_default(),
         ExplicitTypes::create_default(),
         PayableFallback::create_default(),
+        DuplicateContractName::create_default(),
+        UnusedFile::create_default(),
+        CircularImports::create_default(),
+        DuplicateImports::create_default(),
+        ImportPathStyle::create_default(),
+        CodeComplexity::create_default(),
+        MaxFunctionParams::create_default(),
+        MaxInheritanceDepth::create_default(),
+        MaxNestingDepth::create_default(),
     ]
 }
 
@@ -74,6 +101,36 @@ pub fn create_rules() -> RulesMap {
         payable_fallback::RULE_ID.to_string(),
         PayableFallback::create,
     );
+    rules.insert(
+        duplicate_contract_name::RULE_ID.to_string(),
+        DuplicateContractName::create,
+    );
+    rules.insert(unused_file::RULE_ID.to_string(), UnusedFile::create);
+    rules.insert(
+        circular_imports::RULE_ID.to_string(),
+        CircularImports::create,
+    );
+    rules.insert(
+        duplicate_imports::RULE_ID.to_string(),
+        DuplicateImports::create,
+    );
+    rules.insert(
+        import_path_style::RULE_ID.to_string(),
+        ImportPathStyle::create,
+    );
+    rules.insert(code_complexity::RULE_ID.to_string(), CodeComplexity::create);
+    rules.insert(
+        max_function_params::RULE_ID.to_string(),
+        MaxFunctionParams::create,
+    );
+    rules.insert(
+        max_inheritance_depth::RULE_ID.to_string(),
+        MaxInheritanceDepth::create,
+    );
+    rules.insert(
+        max_nesting_depth::RULE_ID.to_string(),
+        MaxNestingDepth::create,
+    );
 
     rules
 }
@@ -38,6 +38,7 @@ impl NoConsole {
             code: None,
             source: None,
             same_line_ranges: None,
+            fix: None,
             uri: file.path.clone(),
         }
     }
@@ -29,6 +29,7 @@ impl RuleType for NoEmptyBlock {
                 code: None,
                 source: None,
                 same_line_ranges: None,
+                fix: None,
                 message: DEFAULT_MESSAGE.to_string(),
                 uri: _file.path.clone(),
             });
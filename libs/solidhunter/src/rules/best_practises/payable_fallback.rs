@@ -31,6 +31,7 @@ impl RuleType for PayableFallback {
                 code: None,
                 source: None,
                 same_line_ranges: None,
+                fix: None,
                 message: DEFAULT_MESSAGE.to_string(),
                 uri: file.path.clone(),
             });
@@ -40,6 +40,7 @@ impl ReasonString {
             code: None,
             source: None,
             same_line_ranges: None,
+            fix: None,
             uri: file.path.clone(),
         }
     }
@@ -138,8 +139,8 @@ impl RuleType for ReasonString {
             example_config: " {\"id\": \"reason-string\", \"severity\": \"WARNING\", \"data\": 20}".to_string(),
             source_link: "https://github.com/astrodevs-labs/osmium/blob/main/toolchains/solidity/core/crates/linter-lib/src/rules/best_practices/reason_string.rs".to_string(),
             test_link: "https://github.com/astrodevs-labs/osmium/tree/main/toolchains/solidity/core/crates/linter-lib/testdata/ReasonString".to_string(),
-            options: vec![Options{description: "A JSON object with a single property \"maxLength\" specifying the max number of characters per reason string.".to_string(),
-                default: "{\"maxLength\":32}".to_string()}],
+            options: vec![Options{description: "Maximum number of characters per reason string".to_string(),
+                default: "32".to_string()}],
             examples: Examples {
                 good: vec![],
                 bad: vec![],
@@ -0,0 +1,126 @@
+use crate::linter::SolidFile;
+use crate::rules::imports::ImportResolver;
+use crate::rules::types::*;
+use crate::types::*;
+use osmium_libs_solidity_ast_extractor::*;
+
+// global
+pub const RULE_ID: &str = "unused-file";
+
+// specific
+const DEFAULT_SEVERITY: Severity = Severity::WARNING;
+// Tests and scripts of Foundry are run, not imported
+const ENTRY_POINT_EXTENSIONS: [&str; 2] = [".t.sol", ".s.sol"];
+
+pub struct UnusedFile {
+    data: RuleEntry,
+}
+
+// Files declaring a contract can be deployed, the others are only useful when imported
+fn is_import_only(file: &SolidFile) -> bool {
+    retriever::retrieve_contract_nodes(&file.data)
+        .iter()
+        .all(|contract| !matches!(contract.kind, ContractKind::Contract(_)))
+}
+
+impl UnusedFile {
+    fn create_diag(&self, file: &SolidFile) -> LintDiag {
+        let first_line = file.content.lines().next().unwrap_or_default();
+
+        LintDiag {
+            id: RULE_ID.to_string(),
+            range: Range {
+                start: Position {
+                    line: 1,
+                    character: 0,
+                },
+                end: Position {
+                    line: 1,
+                    character: first_line.chars().count(),
+                },
+            },
+            same_line_ranges: None,
+            fix: None,
+            message: "File is never imported, its interfaces, libraries and abstract contracts are unused".to_string(),
+            severity: self.data.severity,
+            code: None,
+            source: None,
+            uri: file.path.clone(),
+        }
+    }
+}
+
+impl RuleType for UnusedFile {
+    fn diagnose(&self, file: &SolidFile, files: &[SolidFile]) -> Vec<LintDiag> {
+        let mut res = Vec::new();
+
+        // A file linted alone is not part of a project
+        if files.len() < 2
+            || ENTRY_POINT_EXTENSIONS
+                .iter()
+                .any(|extension| file.path.ends_with(extension))
+            || !is_import_only(file)
+        {
+            return res;
+        }
+        let resolver = ImportResolver::new(files);
+        let Some(index) = resolver.index_of(file) else {
+            return res;
+        };
+        let imported = (0..files.len())
+            .filter(|other| *other != index)
+            .any(|other| {
+                resolver
+                    .imports_of(other)
+                    .iter()
+                    .any(|(_, imported)| *imported == index)
+            });
+        if !imported {
+            res.push(self.create_diag(file));
+        }
+        res
+    }
+
+    fn get_documentation(&self) -> RuleDocumentation {
+        RuleDocumentation {
+            id: RULE_ID.to_string(),
+            severity: DEFAULT_SEVERITY,
+            description: "Files only declaring interfaces, libraries, abstract contracts or free definitions must be imported by another file of the project. Files declaring a contract, tests (.t.sol) and scripts (.s.sol) are entry points and are not checked.".to_string(),
+            category: "best-practises".to_string(),
+            example_config: "{\"id\": \"unused-file\", \"severity\": \"WARNING\"}".to_string(),
+            source_link: "https://github.com/astrodevs-labs/osmium/blob/main/toolchains/solidity/core/crates/linter-lib/src/rules/best_practises/unused_file.rs".to_string(),
+            test_link: "https://github.com/astrodevs-labs/osmium/tree/main/toolchains/solidity/core/crates/linter-lib/testdata/UnusedFile".to_string(),
+            options: vec![],
+            examples: Examples {
+                good: vec![Example {
+                    description: "Imported interface".to_string(),
+                    code: "// IToken.sol\ninterface IToken {}\n// Token.sol\nimport {IToken} from \"./IToken.sol\";\ncontract Token is IToken {}".to_string(),
+                }],
+                bad: vec![Example {
+                    description: "Interface never imported".to_string(),
+                    code: "// IToken.sol\ninterface IToken {}\n// Token.sol\ncontract Token {}"
+                        .to_string(),
+                }],
+            },
+        }
+    }
+
+    fn is_cross_file(&self) -> bool {
+        true
+    }
+}
+
+impl UnusedFile {
+    pub(crate) fn create(data: RuleEntry) -> Box<dyn RuleType> {
+        let rule = UnusedFile { data };
+        Box::new(rule)
+    }
+
+    pub(crate) fn create_default() -> RuleEntry {
+        RuleEntry {
+            id: RULE_ID.to_string(),
+            severity: DEFAULT_SEVERITY,
+            data: None,
+        }
+    }
+}
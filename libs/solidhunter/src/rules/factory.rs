@@ -1,5 +1,8 @@
-use crate::rules::create_rules;
+use crate::errors::RuleConfigError;
 use crate::rules::types::*;
+use crate::rules::validation::{suggest_rule_id, validate_options};
+use crate::rules::{create_default_rules, create_rules};
+use crate::types::Severity;
 use std::collections::HashMap;
 
 pub struct RuleFactory {
@@ -21,11 +24,65 @@ impl RuleFactory {
         }
     }
 
-    pub fn create_rule(&self, rule: RuleEntry) -> Box<dyn RuleType> {
-        let rule_type = self._buildables.get(&rule.id);
-        if rule_type.is_none() {
-            panic!("Rule {} not found", &rule.id);
+    pub fn create_rule(&self, rule: RuleEntry) -> Option<Box<dyn RuleType>> {
+        self._buildables.get(&rule.id).map(|create| create(rule))
+    }
+
+    /// Returns the documentation of every known rule, including the ones off by default.
+    pub fn get_documentation(&self) -> Vec<RuleDocumentation> {
+        create_default_rules()
+            .into_iter()
+            .filter_map(|rule| self.create_rule(rule))
+            .map(|rule| rule.get_documentation())
+            .collect()
+    }
+
+    /// Creates the rules of a configuration, collecting every unknown rule id and invalid
+    /// option instead of stopping at the first one. Rules turned off are validated but skipped.
+    pub fn create_rules(
+        &self,
+        path: &str,
+        rules: Vec<RuleEntry>,
+    ) -> Result<Vec<Box<dyn RuleType>>, Vec<RuleConfigError>> {
+        let mut created = Vec::new();
+        let mut errors = Vec::new();
+        // Documentation is read from rules built with their default data, which is always valid
+        let defaults: HashMap<String, RuleEntry> = create_default_rules()
+            .into_iter()
+            .map(|rule| (rule.id.clone(), rule))
+            .collect();
+
+        for (i, rule) in rules.into_iter().enumerate() {
+            let path = format!("{}[{}]", path, i);
+            let create = match self._buildables.get(&rule.id) {
+                Some(create) => create,
+                None => {
+                    errors.push(RuleConfigError::UnknownRule {
+                        suggestion: suggest_rule_id(&rule.id, self._buildables.keys()),
+                        id: rule.id,
+                        path,
+                    });
+                    continue;
+                }
+            };
+            if let (Some(data), Some(default)) = (&rule.data, defaults.get(&rule.id)) {
+                let options = create(default.clone()).get_documentation().options;
+                let rule_errors =
+                    validate_options(&format!("{}.data", path), &rule.id, data, &options);
+                if !rule_errors.is_empty() {
+                    errors.extend(rule_errors);
+                    continue;
+                }
+            }
+            if rule.severity != Severity::OFF {
+                created.push(create(rule));
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(created)
+        } else {
+            Err(errors)
         }
-        rule_type.unwrap()(rule)
     }
 }
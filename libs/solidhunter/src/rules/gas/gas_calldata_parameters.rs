@@ -0,0 +1,217 @@
+use crate::linter::SolidFile;
+use crate::rules::types::*;
+use crate::rules::utils::{is_assign, is_delete, root_ident};
+use crate::types::*;
+use osmium_libs_solidity_ast_extractor::*;
+use std::collections::HashSet;
+
+// global
+pub const RULE_ID: &str = "gas-calldata-parameters";
+
+// specific
+const DEFAULT_SEVERITY: Severity = Severity::INFO;
+
+// Collects the variables modified in a function body
+struct WritesVisitor {
+    writes: HashSet<String>,
+}
+
+impl WritesVisitor {
+    fn write(&mut self, target: &Expr) {
+        if let Expr::Tuple(tuple) = target {
+            for elem in tuple.elems.iter() {
+                self.write(elem);
+            }
+        } else if let Some(ident) = root_ident(target) {
+            self.writes.insert(ident.to_string());
+        }
+    }
+
+    // The parser reads `a.b = c` as the member `b = c` of `a`, so the accessed expression is
+    // carried along the member chain
+    fn member_access(&mut self, target: &Expr, member: &Expr) {
+        match member {
+            Expr::Ident(_) => {}
+            Expr::Member(inner) => {
+                self.member_access(target, &inner.expr);
+                self.member_access(target, &inner.member);
+            }
+            Expr::Binary(binary) if is_assign(&binary.op) => {
+                self.visit_expr(&binary.right);
+                self.write(target);
+            }
+            Expr::Binary(binary) => {
+                self.member_access(target, &binary.left);
+                self.visit_expr(&binary.right);
+            }
+            Expr::Postfix(postfix) => {
+                self.member_access(target, &postfix.expr);
+                self.write(target);
+            }
+            Expr::Index(index) => {
+                self.member_access(target, &index.expr);
+                visit::visit_expr_index(self, index);
+            }
+            _ => self.visit_expr(member),
+        }
+    }
+}
+
+impl<'ast> Visit<'ast> for WritesVisitor {
+    fn visit_expr_binary(&mut self, binary: &'ast ExprBinary) {
+        visit::visit_expr_binary(self, binary);
+        if is_assign(&binary.op) {
+            self.write(&binary.left);
+        }
+    }
+
+    fn visit_expr_postfix(&mut self, postfix: &'ast ExprPostfix) {
+        visit::visit_expr_postfix(self, postfix);
+        self.write(&postfix.expr);
+    }
+
+    fn visit_expr_unary(&mut self, unary: &'ast ExprUnary) {
+        visit::visit_expr_unary(self, unary);
+        if matches!(unary.op, UnOp::Increment(..) | UnOp::Decrement(..)) {
+            self.write(&unary.expr);
+        }
+    }
+
+    fn visit_expr_member(&mut self, member: &'ast ExprMember) {
+        self.visit_expr(&member.expr);
+        self.member_access(&member.expr, &member.member);
+    }
+
+    fn visit_variable_declaration(&mut self, var: &'ast VariableDeclaration) {
+        if let (Some(name), true) = (&var.name, is_delete(&var.ty)) {
+            self.writes.insert(name.to_string());
+        }
+    }
+}
+
+pub struct GasCalldataParameters {
+    data: RuleEntry,
+}
+
+impl GasCalldataParameters {
+    fn create_diag(&self, file: &SolidFile, storage: &Storage, name: &SolIdent) -> LintDiag {
+        LintDiag {
+            id: RULE_ID.to_string(),
+            range: Range {
+                start: Position {
+                    line: storage.span().start().line,
+                    character: storage.span().start().column,
+                },
+                end: Position {
+                    line: storage.span().end().line,
+                    character: storage.span().end().column,
+                },
+            },
+            same_line_ranges: None,
+            fix: None,
+            message: format!(
+                "Parameter '{}' is never modified, declare it calldata instead of memory to save gas",
+                name
+            ),
+            severity: self.data.severity,
+            code: None,
+            source: None,
+            uri: file.path.clone(),
+        }
+    }
+}
+
+impl RuleType for GasCalldataParameters {
+    fn diagnose(&self, file: &SolidFile, _files: &[SolidFile]) -> Vec<LintDiag> {
+        let mut res = Vec::new();
+
+        for contract in retriever::retrieve_contract_nodes(&file.data) {
+            for function in retriever::retrieve_functions_nodes(&contract) {
+                let block = match &function.body {
+                    FunctionBody::Block(block) => block,
+                    FunctionBody::Empty(_) => continue,
+                };
+                if !matches!(
+                    function.attributes.visibility(),
+                    Some(Visibility::External(_))
+                ) {
+                    continue;
+                }
+
+                let mut visitor = WritesVisitor {
+                    writes: HashSet::new(),
+                };
+                visitor.visit_block(block);
+
+                for param in function.parameters.iter() {
+                    if let (Some(storage @ Storage::Memory(_)), Some(name)) =
+                        (&param.storage, &param.name)
+                    {
+                        if !visitor.writes.contains(&name.to_string()) {
+                            res.push(self.create_diag(file, storage, name));
+                        }
+                    }
+                }
+            }
+        }
+        res
+    }
+
+    fn get_documentation(&self) -> RuleDocumentation {
+        RuleDocumentation {
+            id: RULE_ID.to_string(),
+            severity: DEFAULT_SEVERITY,
+            description: "Parameters of external functions that are never modified should be declared calldata instead of memory, it avoids copying them to memory.".to_string(),
+            category: "gas".to_string(),
+            example_config: "{\"id\": \"gas-calldata-parameters\", \"severity\": \"INFO\"}".to_string(),
+            source_link: "https://github.com/astrodevs-labs/osmium/blob/main/toolchains/solidity/core/crates/linter-lib/src/rules/gas/gas_calldata_parameters.rs".to_string(),
+            test_link: "https://github.com/astrodevs-labs/osmium/tree/main/toolchains/solidity/core/crates/linter-lib/testdata/GasCalldataParameters".to_string(),
+            options: vec![],
+            examples: Examples {
+                good: vec![
+                    Example {
+                        description: "Read-only parameter declared calldata".to_string(),
+                        code: "function total(uint256[] calldata values) external pure returns (uint256 sum) {\n    for (uint256 i; i < values.length; ++i) sum += values[i];\n}".to_string(),
+                    },
+                    Example {
+                        description: "Modified parameter declared memory".to_string(),
+                        code: "function sort(uint256[] memory values) external pure returns (uint256[] memory) {\n    values[0] = 0;\n    return values;\n}".to_string(),
+                    },
+                ],
+                bad: vec![Example {
+                    description: "Read-only parameter declared memory".to_string(),
+                    code: "function total(uint256[] memory values) external pure returns (uint256 sum) {\n    for (uint256 i; i < values.length; ++i) sum += values[i];\n}".to_string(),
+                }],
+            },
+        }
+    }
+
+    fn fix(&self, file: &SolidFile, _files: &[SolidFile], diag: &LintDiag) -> Option<Fix> {
+        if diag.range.text(&file.content)? != "memory" {
+            return None;
+        }
+
+        Some(Fix {
+            message: "Replace memory with calldata".to_string(),
+            edits: vec![TextEdit {
+                range: diag.range.clone(),
+                new_text: "calldata".to_string(),
+            }],
+        })
+    }
+}
+
+impl GasCalldataParameters {
+    pub(crate) fn create(data: RuleEntry) -> Box<dyn RuleType> {
+        let rule = GasCalldataParameters { data };
+        Box::new(rule)
+    }
+
+    pub(crate) fn create_default() -> RuleEntry {
+        RuleEntry {
+            id: RULE_ID.to_string(),
+            severity: DEFAULT_SEVERITY,
+            data: None,
+        }
+    }
+}
@@ -0,0 +1,144 @@
+use crate::linter::SolidFile;
+use crate::rules::types::*;
+use crate::types::*;
+use osmium_libs_solidity_ast_extractor::*;
+
+// global
+pub const RULE_ID: &str = "gas-custom-errors";
+
+// specific
+const DEFAULT_SEVERITY: Severity = Severity::INFO;
+
+pub struct GasCustomErrors {
+    data: RuleEntry,
+}
+
+fn is_string_literal(expr: &Expr) -> bool {
+    matches!(expr, Expr::Lit(Lit::Str(_)))
+}
+
+impl GasCustomErrors {
+    fn create_diag(
+        &self,
+        file: &SolidFile,
+        location: (LineColumn, LineColumn),
+        statement: &str,
+    ) -> LintDiag {
+        LintDiag {
+            id: RULE_ID.to_string(),
+            range: Range {
+                start: Position {
+                    line: location.0.line,
+                    character: location.0.column,
+                },
+                end: Position {
+                    line: location.1.line,
+                    character: location.1.column,
+                },
+            },
+            same_line_ranges: None,
+            fix: None,
+            message: format!(
+                "Use a custom error instead of a {} with a string message to save gas",
+                statement
+            ),
+            severity: self.data.severity,
+            code: None,
+            source: None,
+            uri: file.path.clone(),
+        }
+    }
+}
+
+impl RuleType for GasCustomErrors {
+    fn diagnose(&self, file: &SolidFile, _files: &[SolidFile]) -> Vec<LintDiag> {
+        let mut res = Vec::new();
+
+        for contract in retriever::retrieve_contract_nodes(&file.data) {
+            for stmt in retriever::retrieve_stmts_nodes(&contract) {
+                match &stmt {
+                    // `revert CustomError()` is parsed as a call, only `revert("...")` is a tuple
+                    Stmt::Revert(revert) => {
+                        if let Expr::Tuple(tuple) = &revert.expr {
+                            if tuple.elems.iter().any(is_string_literal) {
+                                let location = (
+                                    revert.revert_token.span().start(),
+                                    revert.revert_token.span().end(),
+                                );
+                                res.push(self.create_diag(file, location, "revert"));
+                            }
+                        }
+                    }
+                    Stmt::Expr(expr) => {
+                        if let Expr::Call(call) = &expr.expr {
+                            if let (Expr::Ident(ident), ArgListImpl::Unnamed(args)) =
+                                (&*call.expr, &call.args.list)
+                            {
+                                if *ident == "require"
+                                    && args.len() == 2
+                                    && args.last().is_some_and(is_string_literal)
+                                {
+                                    let location = (ident.span().start(), ident.span().end());
+                                    res.push(self.create_diag(file, location, "require"));
+                                }
+                            }
+                        }
+                    }
+                    _ => {}
+                }
+            }
+        }
+        res
+    }
+
+    fn get_documentation(&self) -> RuleDocumentation {
+        RuleDocumentation {
+            id: RULE_ID.to_string(),
+            severity: DEFAULT_SEVERITY,
+            description: "Enforces the use of custom errors over require and revert statements with a string message, the string is stored in the bytecode and returned at every failure.".to_string(),
+            category: "gas".to_string(),
+            example_config: "{\"id\": \"gas-custom-errors\", \"severity\": \"INFO\"}".to_string(),
+            source_link: "https://github.com/astrodevs-labs/osmium/blob/main/toolchains/solidity/core/crates/linter-lib/src/rules/gas/gas_custom_errors.rs".to_string(),
+            test_link: "https://github.com/astrodevs-labs/osmium/tree/main/toolchains/solidity/core/crates/linter-lib/testdata/GasCustomErrors".to_string(),
+            options: vec![],
+            examples: Examples {
+                good: vec![
+                    Example {
+                        description: "Use of a custom error".to_string(),
+                        code: "if (balance < amount) revert InsufficientBalance(balance, amount);"
+                            .to_string(),
+                    },
+                    Example {
+                        description: "Use of require without message".to_string(),
+                        code: "require(balance >= amount);".to_string(),
+                    },
+                ],
+                bad: vec![
+                    Example {
+                        description: "Use of require with a string message".to_string(),
+                        code: "require(balance >= amount, \"Insufficient balance\");".to_string(),
+                    },
+                    Example {
+                        description: "Use of revert with a string message".to_string(),
+                        code: "revert(\"Insufficient balance\");".to_string(),
+                    },
+                ],
+            },
+        }
+    }
+}
+
+impl GasCustomErrors {
+    pub(crate) fn create(data: RuleEntry) -> Box<dyn RuleType> {
+        let rule = GasCustomErrors { data };
+        Box::new(rule)
+    }
+
+    pub(crate) fn create_default() -> RuleEntry {
+        RuleEntry {
+            id: RULE_ID.to_string(),
+            severity: DEFAULT_SEVERITY,
+            data: None,
+        }
+    }
+}
@@ -0,0 +1,154 @@
+use crate::linter::SolidFile;
+use crate::rules::types::*;
+use crate::types::*;
+use osmium_libs_solidity_ast_extractor::*;
+
+// global
+pub const RULE_ID: &str = "gas-increment-by-one";
+
+// specific
+const DEFAULT_SEVERITY: Severity = Severity::INFO;
+
+// The parser reads `s.count++` as the member `count++` of `s`, retrieve the postfix operation
+// ending a member chain
+fn trailing_postfix(expr: &Expr) -> Option<&ExprPostfix> {
+    match expr {
+        Expr::Postfix(postfix) => Some(postfix),
+        Expr::Member(member) => trailing_postfix(&member.member),
+        _ => None,
+    }
+}
+
+// `x++` becomes `++x`
+fn to_prefix(text: &str) -> Option<String> {
+    let operand = text
+        .strip_suffix("++")
+        .or_else(|| text.strip_suffix("--"))?;
+    Some(format!("{}{}", &text[operand.len()..], operand.trim_end()))
+}
+
+pub struct GasIncrementByOne {
+    data: RuleEntry,
+}
+
+impl GasIncrementByOne {
+    fn create_diag(
+        &self,
+        file: &SolidFile,
+        expr: &Expr,
+        postfix: &ExprPostfix,
+    ) -> Option<LintDiag> {
+        // The operator is made of two punctuations and its span only covers the first one
+        let range = Range {
+            start: Position {
+                line: expr.span().start().line,
+                character: expr.span().start().column,
+            },
+            end: Position {
+                line: postfix.op.span().start().line,
+                character: postfix.op.span().start().column + 2,
+            },
+        };
+        let text = range.text(&file.content)?;
+        let prefix = to_prefix(text)?;
+
+        Some(LintDiag {
+            id: RULE_ID.to_string(),
+            message: format!("Use {} instead of {} to save gas", prefix, text),
+            range,
+            same_line_ranges: None,
+            fix: None,
+            severity: self.data.severity,
+            code: None,
+            source: None,
+            uri: file.path.clone(),
+        })
+    }
+}
+
+impl RuleType for GasIncrementByOne {
+    fn diagnose(&self, file: &SolidFile, _files: &[SolidFile]) -> Vec<LintDiag> {
+        let mut res = Vec::new();
+
+        for contract in retriever::retrieve_contract_nodes(&file.data) {
+            for stmt in retriever::retrieve_stmts_nodes(&contract) {
+                // Only the statements discarding the value, `x = i++` relies on the old value
+                let expr = match &stmt {
+                    Stmt::Expr(stmt) => &stmt.expr,
+                    Stmt::For(stmt) => match &stmt.post {
+                        Some(post) => post,
+                        None => continue,
+                    },
+                    _ => continue,
+                };
+                if let Some(postfix) = trailing_postfix(expr) {
+                    res.extend(self.create_diag(file, expr, postfix));
+                }
+            }
+        }
+        res
+    }
+
+    fn get_documentation(&self) -> RuleDocumentation {
+        RuleDocumentation {
+            id: RULE_ID.to_string(),
+            severity: DEFAULT_SEVERITY,
+            description: "Prefix increments and decrements are cheaper than postfix ones when their value is not used, postfix operations keep a copy of the previous value.".to_string(),
+            category: "gas".to_string(),
+            example_config: "{\"id\": \"gas-increment-by-one\", \"severity\": \"INFO\"}".to_string(),
+            source_link: "https://github.com/astrodevs-labs/osmium/blob/main/toolchains/solidity/core/crates/linter-lib/src/rules/gas/gas_increment_by_one.rs".to_string(),
+            test_link: "https://github.com/astrodevs-labs/osmium/tree/main/toolchains/solidity/core/crates/linter-lib/testdata/GasIncrementByOne".to_string(),
+            options: vec![],
+            examples: Examples {
+                good: vec![
+                    Example {
+                        description: "Prefix increment".to_string(),
+                        code: "for (uint256 i = 0; i < length; ++i) {}".to_string(),
+                    },
+                    Example {
+                        description: "Postfix increment whose previous value is used".to_string(),
+                        code: "uint256 id = nextId++;".to_string(),
+                    },
+                ],
+                bad: vec![
+                    Example {
+                        description: "Postfix increment".to_string(),
+                        code: "for (uint256 i = 0; i < length; i++) {}".to_string(),
+                    },
+                    Example {
+                        description: "Postfix decrement".to_string(),
+                        code: "count--;".to_string(),
+                    },
+                ],
+            },
+        }
+    }
+
+    fn fix(&self, file: &SolidFile, _files: &[SolidFile], diag: &LintDiag) -> Option<Fix> {
+        let text = diag.range.text(&file.content)?;
+        let new_text = to_prefix(text)?;
+
+        Some(Fix {
+            message: format!("Replace {} with {}", text, new_text),
+            edits: vec![TextEdit {
+                range: diag.range.clone(),
+                new_text,
+            }],
+        })
+    }
+}
+
+impl GasIncrementByOne {
+    pub(crate) fn create(data: RuleEntry) -> Box<dyn RuleType> {
+        let rule = GasIncrementByOne { data };
+        Box::new(rule)
+    }
+
+    pub(crate) fn create_default() -> RuleEntry {
+        RuleEntry {
+            id: RULE_ID.to_string(),
+            severity: DEFAULT_SEVERITY,
+            data: None,
+        }
+    }
+}
@@ -0,0 +1,144 @@
+use crate::linter::SolidFile;
+use crate::rules::types::*;
+use crate::types::*;
+use osmium_libs_solidity_ast_extractor::*;
+
+// global
+pub const RULE_ID: &str = "gas-indexed-events";
+
+// specific
+const DEFAULT_SEVERITY: Severity = Severity::INFO;
+// The first topic holds the signature of non anonymous events
+const MAX_INDEXED: usize = 3;
+const MAX_INDEXED_ANONYMOUS: usize = 4;
+
+pub struct GasIndexedEvents {
+    data: RuleEntry,
+}
+
+fn is_indexable(ty: &Type) -> bool {
+    matches!(
+        ty,
+        Type::Bool(_) | Type::Int(..) | Type::Uint(..) | Type::FixedBytes(..) | Type::Address(..)
+    )
+}
+
+impl GasIndexedEvents {
+    fn create_diag(
+        &self,
+        file: &SolidFile,
+        event: &ItemEvent,
+        param: &EventParameter,
+        left: usize,
+    ) -> LintDiag {
+        let span = match &param.name {
+            Some(name) => name.span(),
+            None => param.ty.span(),
+        };
+        let name = match &param.name {
+            Some(name) => format!("'{}'", name),
+            None => format!("of type {}", param.ty),
+        };
+
+        LintDiag {
+            id: RULE_ID.to_string(),
+            range: Range {
+                start: Position {
+                    line: span.start().line,
+                    character: span.start().column,
+                },
+                end: Position {
+                    line: span.end().line,
+                    character: span.end().column,
+                },
+            },
+            same_line_ranges: None,
+            fix: None,
+            message: format!(
+                "Parameter {} of event {} can be indexed to save gas, {} indexed parameter(s) left",
+                name, event.name, left
+            ),
+            severity: self.data.severity,
+            code: None,
+            source: None,
+            uri: file.path.clone(),
+        }
+    }
+}
+
+impl RuleType for GasIndexedEvents {
+    fn diagnose(&self, file: &SolidFile, _files: &[SolidFile]) -> Vec<LintDiag> {
+        let mut res = Vec::new();
+
+        let mut events = retriever::retrieve_events_file_nodes(&file.data);
+        for contract in retriever::retrieve_contract_nodes(&file.data) {
+            events.extend(retriever::retrieve_events_contract_nodes(&contract));
+        }
+
+        for event in events {
+            let max = if event.anonymous.is_some() {
+                MAX_INDEXED_ANONYMOUS
+            } else {
+                MAX_INDEXED
+            };
+            let indexed = event
+                .parameters
+                .iter()
+                .filter(|param| param.indexed.is_some())
+                .count();
+            if indexed >= max {
+                continue;
+            }
+            for param in event.parameters.iter() {
+                if param.indexed.is_none() && is_indexable(&param.ty) {
+                    res.push(self.create_diag(file, &event, param, max - indexed));
+                }
+            }
+        }
+        res
+    }
+
+    fn get_documentation(&self) -> RuleDocumentation {
+        RuleDocumentation {
+            id: RULE_ID.to_string(),
+            severity: DEFAULT_SEVERITY,
+            description: "Value type parameters of events should be indexed while topics are available, indexed parameters are cheaper to emit than ABI encoded data.".to_string(),
+            category: "gas".to_string(),
+            example_config: "{\"id\": \"gas-indexed-events\", \"severity\": \"INFO\"}".to_string(),
+            source_link: "https://github.com/astrodevs-labs/osmium/blob/main/toolchains/solidity/core/crates/linter-lib/src/rules/gas/gas_indexed_events.rs".to_string(),
+            test_link: "https://github.com/astrodevs-labs/osmium/tree/main/toolchains/solidity/core/crates/linter-lib/testdata/GasIndexedEvents".to_string(),
+            options: vec![],
+            examples: Examples {
+                good: vec![
+                    Example {
+                        description: "Value type parameters indexed".to_string(),
+                        code: "event Transfer(address indexed from, address indexed to, uint256 indexed value);".to_string(),
+                    },
+                    Example {
+                        description: "Non value type parameters are not indexed".to_string(),
+                        code: "event Message(address indexed sender, string text);".to_string(),
+                    },
+                ],
+                bad: vec![Example {
+                    description: "Value type parameter not indexed while a topic is available".to_string(),
+                    code: "event Transfer(address indexed from, address indexed to, uint256 value);".to_string(),
+                }],
+            },
+        }
+    }
+}
+
+impl GasIndexedEvents {
+    pub(crate) fn create(data: RuleEntry) -> Box<dyn RuleType> {
+        let rule = GasIndexedEvents { data };
+        Box::new(rule)
+    }
+
+    pub(crate) fn create_default() -> RuleEntry {
+        RuleEntry {
+            id: RULE_ID.to_string(),
+            severity: DEFAULT_SEVERITY,
+            data: None,
+        }
+    }
+}
@@ -0,0 +1,154 @@
+use crate::linter::SolidFile;
+use crate::rules::types::*;
+use crate::rules::utils::{tokenize_code, CodeToken, CodeTokenKind};
+use crate::types::*;
+use osmium_libs_solidity_ast_extractor::*;
+
+// global
+pub const RULE_ID: &str = "gas-length-in-loops";
+
+// specific
+const DEFAULT_SEVERITY: Severity = Severity::INFO;
+
+pub struct GasLengthInLoops {
+    data: RuleEntry,
+}
+
+fn to_key(position: &Position) -> (usize, usize) {
+    (position.line, position.character)
+}
+
+// Whether two tokens are only separated by a dot, like `values` and `length` in `values.length`
+fn is_member_of(content: &str, object: &CodeToken, member: &CodeToken) -> bool {
+    let between = Range {
+        start: object.range.end.clone(),
+        end: member.range.start.clone(),
+    };
+    object.kind == CodeTokenKind::Identifier
+        && between.text(content).is_some_and(|text| text.trim() == ".")
+}
+
+impl GasLengthInLoops {
+    fn create_diag(&self, file: &SolidFile, range: Range, accessed: &str) -> LintDiag {
+        LintDiag {
+            id: RULE_ID.to_string(),
+            range,
+            same_line_ranges: None,
+            fix: None,
+            message: format!(
+                "{} is read at every iteration, cache it in a local variable before the loop to save gas",
+                accessed
+            ),
+            severity: self.data.severity,
+            code: None,
+            source: None,
+            uri: file.path.clone(),
+        }
+    }
+
+    // The member accesses are found from the tokens, the parser nests everything following a
+    // dot in the member expression
+    fn check_condition(
+        &self,
+        file: &SolidFile,
+        tokens: &[CodeToken],
+        cond: &Expr,
+        res: &mut Vec<LintDiag>,
+    ) {
+        let start = (cond.span().start().line, cond.span().start().column);
+        let end = (cond.span().end().line, cond.span().end().column);
+
+        for (index, token) in tokens.iter().enumerate() {
+            let location = to_key(&token.range.start);
+            if location < start || location >= end {
+                continue;
+            }
+            if token.kind != CodeTokenKind::Identifier || token.text != "length" {
+                continue;
+            }
+            // Walk back the member chain, `a.b.length` is reported as a whole
+            let mut first = index;
+            while first > 0
+                && to_key(&tokens[first - 1].range.start) >= start
+                && is_member_of(&file.content, &tokens[first - 1], &tokens[first])
+            {
+                first -= 1;
+            }
+            if first == index {
+                continue;
+            }
+            let range = Range {
+                start: tokens[first].range.start.clone(),
+                end: token.range.end.clone(),
+            };
+            if let Some(accessed) = range.text(&file.content) {
+                let accessed = accessed.to_string();
+                res.push(self.create_diag(file, range, &accessed));
+            }
+        }
+    }
+}
+
+impl RuleType for GasLengthInLoops {
+    fn diagnose(&self, file: &SolidFile, _files: &[SolidFile]) -> Vec<LintDiag> {
+        let mut res = Vec::new();
+        let tokens = tokenize_code(&file.content);
+
+        for contract in retriever::retrieve_contract_nodes(&file.data) {
+            for stmt in retriever::retrieve_stmts_nodes(&contract) {
+                match &stmt {
+                    Stmt::For(stmt) => {
+                        if let Some(cond) = &stmt.cond {
+                            self.check_condition(file, &tokens, cond, &mut res);
+                        }
+                    }
+                    Stmt::While(stmt) => self.check_condition(file, &tokens, &stmt.cond, &mut res),
+                    Stmt::DoWhile(stmt) => {
+                        self.check_condition(file, &tokens, &stmt.cond, &mut res)
+                    }
+                    _ => {}
+                }
+            }
+        }
+        res
+    }
+
+    fn get_documentation(&self) -> RuleDocumentation {
+        RuleDocumentation {
+            id: RULE_ID.to_string(),
+            severity: DEFAULT_SEVERITY,
+            description: "The length of an array should be cached before a loop instead of being read in the loop condition at every iteration.".to_string(),
+            category: "gas".to_string(),
+            example_config: "{\"id\": \"gas-length-in-loops\", \"severity\": \"INFO\"}".to_string(),
+            source_link: "https://github.com/astrodevs-labs/osmium/blob/main/toolchains/solidity/core/crates/linter-lib/src/rules/gas/gas_length_in_loops.rs".to_string(),
+            test_link: "https://github.com/astrodevs-labs/osmium/tree/main/toolchains/solidity/core/crates/linter-lib/testdata/GasLengthInLoops".to_string(),
+            options: vec![],
+            examples: Examples {
+                good: vec![Example {
+                    description: "Length cached before the loop".to_string(),
+                    code: "uint256 length = values.length;\nfor (uint256 i; i < length; ++i) {}"
+                        .to_string(),
+                }],
+                bad: vec![Example {
+                    description: "Length read in the loop condition".to_string(),
+                    code: "for (uint256 i; i < values.length; ++i) {}".to_string(),
+                }],
+            },
+        }
+    }
+}
+
+impl GasLengthInLoops {
+    pub(crate) fn create(data: RuleEntry) -> Box<dyn RuleType> {
+        let rule = GasLengthInLoops { data };
+        Box::new(rule)
+    }
+
+    pub(crate) fn create_default() -> RuleEntry {
+        RuleEntry {
+            id: RULE_ID.to_string(),
+            severity: DEFAULT_SEVERITY,
+            data: None,
+        }
+    }
+}
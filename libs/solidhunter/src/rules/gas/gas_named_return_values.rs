@@ -0,0 +1,106 @@
+use crate::linter::SolidFile;
+use crate::rules::types::*;
+use crate::types::*;
+use osmium_libs_solidity_ast_extractor::*;
+
+// global
+pub const RULE_ID: &str = "gas-named-return-values";
+
+// specific
+const DEFAULT_SEVERITY: Severity = Severity::INFO;
+
+pub struct GasNamedReturnValues {
+    data: RuleEntry,
+}
+
+impl GasNamedReturnValues {
+    fn create_diag(&self, file: &SolidFile, function: &ItemFunction, ty: &Type) -> LintDiag {
+        let function_name = match &function.name {
+            Some(name) => name.to_string(),
+            None => function.kind.as_str().to_string(),
+        };
+
+        LintDiag {
+            id: RULE_ID.to_string(),
+            range: Range {
+                start: Position {
+                    line: ty.span().start().line,
+                    character: ty.span().start().column,
+                },
+                end: Position {
+                    line: ty.span().end().line,
+                    character: ty.span().end().column,
+                },
+            },
+            same_line_ranges: None,
+            fix: None,
+            message: format!(
+                "Return value of type {} of {} should be named to save gas",
+                ty, function_name
+            ),
+            severity: self.data.severity,
+            code: None,
+            source: None,
+            uri: file.path.clone(),
+        }
+    }
+}
+
+impl RuleType for GasNamedReturnValues {
+    fn diagnose(&self, file: &SolidFile, _files: &[SolidFile]) -> Vec<LintDiag> {
+        let mut res = Vec::new();
+
+        for contract in retriever::retrieve_contract_nodes(&file.data) {
+            for function in retriever::retrieve_functions_nodes(&contract) {
+                // Declarations without body have nothing to save
+                if let (FunctionBody::Block(_), Some(returns)) = (&function.body, &function.returns)
+                {
+                    for ret in returns.returns.iter() {
+                        if ret.name.is_none() {
+                            res.push(self.create_diag(file, &function, &ret.ty));
+                        }
+                    }
+                }
+            }
+        }
+        res
+    }
+
+    fn get_documentation(&self) -> RuleDocumentation {
+        RuleDocumentation {
+            id: RULE_ID.to_string(),
+            severity: DEFAULT_SEVERITY,
+            description: "Return values of implemented functions should be named, assigning them directly avoids declaring a local variable.".to_string(),
+            category: "gas".to_string(),
+            example_config: "{\"id\": \"gas-named-return-values\", \"severity\": \"INFO\"}".to_string(),
+            source_link: "https://github.com/astrodevs-labs/osmium/blob/main/toolchains/solidity/core/crates/linter-lib/src/rules/gas/gas_named_return_values.rs".to_string(),
+            test_link: "https://github.com/astrodevs-labs/osmium/tree/main/toolchains/solidity/core/crates/linter-lib/testdata/GasNamedReturnValues".to_string(),
+            options: vec![],
+            examples: Examples {
+                good: vec![Example {
+                    description: "Named return value".to_string(),
+                    code: "function balance(address owner) external view returns (uint256 amount) {\n    amount = balances[owner];\n}".to_string(),
+                }],
+                bad: vec![Example {
+                    description: "Unnamed return value".to_string(),
+                    code: "function balance(address owner) external view returns (uint256) {\n    return balances[owner];\n}".to_string(),
+                }],
+            },
+        }
+    }
+}
+
+impl GasNamedReturnValues {
+    pub(crate) fn create(data: RuleEntry) -> Box<dyn RuleType> {
+        let rule = GasNamedReturnValues { data };
+        Box::new(rule)
+    }
+
+    pub(crate) fn create_default() -> RuleEntry {
+        RuleEntry {
+            id: RULE_ID.to_string(),
+            severity: DEFAULT_SEVERITY,
+            data: None,
+        }
+    }
+}
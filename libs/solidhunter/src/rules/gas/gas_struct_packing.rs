@@ -0,0 +1,191 @@
+use crate::linter::SolidFile;
+use crate::rules::types::*;
+use crate::types::*;
+use osmium_libs_solidity_ast_extractor::*;
+use std::collections::HashMap;
+
+// global
+pub const RULE_ID: &str = "gas-struct-packing";
+
+// specific
+const DEFAULT_SEVERITY: Severity = Severity::INFO;
+const SLOT_SIZE: usize = 32;
+
+pub struct GasStructPacking {
+    data: RuleEntry,
+}
+
+// Storage size in bytes of the types of the file, enums and user defined value types
+fn custom_sizes(file: &File) -> HashMap<String, usize> {
+    let mut sizes = HashMap::new();
+
+    let mut enums = retriever::retrieve_enums_file_nodes(file);
+    for contract in retriever::retrieve_contract_nodes(file) {
+        enums.extend(retriever::retrieve_enums_contract_nodes(&contract));
+    }
+    for item in enums {
+        sizes.insert(item.name.to_string(), 1);
+    }
+    for udt in retriever::retrieve_udts_nodes(file) {
+        let size = type_size(&udt.ty, &HashMap::new());
+        sizes.insert(udt.name.to_string(), size);
+    }
+    sizes
+}
+
+// Storage size in bytes of a type, the types taking whole slots count as one slot
+fn type_size(ty: &Type, custom_sizes: &HashMap<String, usize>) -> usize {
+    match ty {
+        Type::Bool(_) => 1,
+        Type::Address(..) => 20,
+        Type::FixedBytes(_, size) => size.get() as usize,
+        Type::Int(_, size) | Type::Uint(_, size) => {
+            size.map_or(SLOT_SIZE, |size| size.get() as usize / 8)
+        }
+        // Contracts and imported types are unknown, they are considered to take a whole slot
+        Type::Custom(path) if path.len() == 1 => custom_sizes
+            .get(&path.first().to_string())
+            .copied()
+            .unwrap_or(SLOT_SIZE),
+        _ => SLOT_SIZE,
+    }
+}
+
+// Number of slots used by fields stored in the given order
+fn count_slots(sizes: &[usize]) -> usize {
+    let mut slots = 0;
+    let mut used = 0;
+
+    for &size in sizes {
+        if size >= SLOT_SIZE {
+            if used > 0 {
+                slots += 1;
+                used = 0;
+            }
+            slots += 1;
+        } else if used + size > SLOT_SIZE {
+            slots += 1;
+            used = size;
+        } else {
+            used += size;
+        }
+    }
+    if used > 0 {
+        slots += 1;
+    }
+    slots
+}
+
+// Number of slots used once the fields are reordered, the biggest fields are placed first in
+// the first slot with enough space left
+fn count_packed_slots(sizes: &[usize]) -> usize {
+    let mut sorted = sizes.to_vec();
+    sorted.sort_unstable_by(|a, b| b.cmp(a));
+    let mut slots: Vec<usize> = Vec::new();
+
+    for size in sorted {
+        match slots.iter_mut().find(|used| **used + size <= SLOT_SIZE) {
+            Some(used) => *used += size,
+            None => slots.push(size.min(SLOT_SIZE)),
+        }
+    }
+    slots.len()
+}
+
+impl GasStructPacking {
+    fn create_diag(
+        &self,
+        file: &SolidFile,
+        item: &ItemStruct,
+        slots: usize,
+        packed_slots: usize,
+    ) -> LintDiag {
+        LintDiag {
+            id: RULE_ID.to_string(),
+            range: Range {
+                start: Position {
+                    line: item.name.span().start().line,
+                    character: item.name.span().start().column,
+                },
+                end: Position {
+                    line: item.name.span().end().line,
+                    character: item.name.span().end().column,
+                },
+            },
+            same_line_ranges: None,
+            fix: None,
+            message: format!(
+                "Struct {} can be packed in {} storage slots instead of {} by reordering its fields",
+                item.name, packed_slots, slots
+            ),
+            severity: self.data.severity,
+            code: None,
+            source: None,
+            uri: file.path.clone(),
+        }
+    }
+}
+
+impl RuleType for GasStructPacking {
+    fn diagnose(&self, file: &SolidFile, _files: &[SolidFile]) -> Vec<LintDiag> {
+        let mut res = Vec::new();
+        let custom_sizes = custom_sizes(&file.data);
+
+        let mut structs = retriever::retrieve_structs_file_nodes(&file.data);
+        for contract in retriever::retrieve_contract_nodes(&file.data) {
+            structs.extend(retriever::retrieve_structs_contract_nodes(&contract));
+        }
+
+        for item in structs {
+            let sizes: Vec<usize> = item
+                .fields
+                .iter()
+                .map(|field| type_size(&field.ty, &custom_sizes))
+                .collect();
+            let slots = count_slots(&sizes);
+            let packed_slots = count_packed_slots(&sizes);
+            if packed_slots < slots {
+                res.push(self.create_diag(file, &item, slots, packed_slots));
+            }
+        }
+        res
+    }
+
+    fn get_documentation(&self) -> RuleDocumentation {
+        RuleDocumentation {
+            id: RULE_ID.to_string(),
+            severity: DEFAULT_SEVERITY,
+            description: "Struct fields should be ordered so that the fields smaller than 32 bytes share storage slots, every slot saved avoids a storage read and write.".to_string(),
+            category: "gas".to_string(),
+            example_config: "{\"id\": \"gas-struct-packing\", \"severity\": \"INFO\"}".to_string(),
+            source_link: "https://github.com/astrodevs-labs/osmium/blob/main/toolchains/solidity/core/crates/linter-lib/src/rules/gas/gas_struct_packing.rs".to_string(),
+            test_link: "https://github.com/astrodevs-labs/osmium/tree/main/toolchains/solidity/core/crates/linter-lib/testdata/GasStructPacking".to_string(),
+            options: vec![],
+            examples: Examples {
+                good: vec![Example {
+                    description: "Small fields next to each other, 2 slots".to_string(),
+                    code: "struct Position {\n    uint256 amount;\n    address owner;\n    bool active;\n}".to_string(),
+                }],
+                bad: vec![Example {
+                    description: "Small fields separated by a whole slot field, 3 slots".to_string(),
+                    code: "struct Position {\n    address owner;\n    uint256 amount;\n    bool active;\n}".to_string(),
+                }],
+            },
+        }
+    }
+}
+
+impl GasStructPacking {
+    pub(crate) fn create(data: RuleEntry) -> Box<dyn RuleType> {
+        let rule = GasStructPacking { data };
+        Box::new(rule)
+    }
+
+    pub(crate) fn create_default() -> RuleEntry {
+        RuleEntry {
+            id: RULE_ID.to_string(),
+            severity: DEFAULT_SEVERITY,
+            data: None,
+        }
+    }
+}
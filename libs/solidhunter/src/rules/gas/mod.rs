@@ -0,0 +1,68 @@
+use crate::rules::types::{RuleEntry, RulesMap};
+use std::collections::HashMap;
+
+pub(crate) mod gas_calldata_parameters;
+pub(crate) mod gas_custom_errors;
+pub(crate) mod gas_increment_by_one;
+pub(crate) mod gas_indexed_events;
+pub(crate) mod gas_length_in_loops;
+pub(crate) mod gas_named_return_values;
+pub(crate) mod gas_struct_packing;
+
+// List all rules
+use crate::rules::gas::gas_calldata_parameters::GasCalldataParameters;
+use crate::rules::gas::gas_custom_errors::GasCustomErrors;
+use crate::rules::gas::gas_increment_by_one::GasIncrementByOne;
+use crate::rules::gas::gas_indexed_events::GasIndexedEvents;
+use crate::rules::gas::gas_length_in_loops::GasLengthInLoops;
+use crate::rules::gas::gas_named_return_values::GasNamedReturnValues;
+use crate::rules::gas::gas_struct_packing::GasStructPacking;
+
+use crate::rules::RuleBuilder;
+
+pub fn create_default_rules() -> Vec<RuleEntry> {
+    vec![
+        GasCustomErrors::create_default(),
+        GasCalldataParameters::create_default(),
+        GasIncrementByOne::create_default(),
+        GasLengthInLoops::create_default(),
+        GasStructPacking::create_default(),
+        GasIndexedEvents::create_default(),
+        GasNamedReturnValues::create_default(),
+    ]
+}
+
+pub fn create_rules() -> RulesMap {
+    let mut rules: HashMap<String, RuleBuilder> = HashMap::new();
+
+    rules.insert(
+        gas_custom_errors::RULE_ID.to_string(),
+        GasCustomErrors::create,
+    );
+    rules.insert(
+        gas_calldata_parameters::RULE_ID.to_string(),
+        GasCalldataParameters::create,
+    );
+    rules.insert(
+        gas_increment_by_one::RULE_ID.to_string(),
+        GasIncrementByOne::create,
+    );
+    rules.insert(
+        gas_length_in_loops::RULE_ID.to_string(),
+        GasLengthInLoops::create,
+    );
+    rules.insert(
+        gas_struct_packing::RULE_ID.to_string(),
+        GasStructPacking::create,
+    );
+    rules.insert(
+        gas_indexed_events::RULE_ID.to_string(),
+        GasIndexedEvents::create,
+    );
+    rules.insert(
+        gas_named_return_values::RULE_ID.to_string(),
+        GasNamedReturnValues::create,
+    );
+
+    rules
+}
@@ -0,0 +1,290 @@
+use crate::linter::SolidFile;
+use crate::rules::utils::{tokenize_code, CodeTokenKind};
+use crate::types::{Position, Range};
+use osmium_libs_solidity_ast_extractor::{retriever, ImportDirective, ItemContract, Spanned};
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::path::{Component, Path, PathBuf};
+
+// Normalize a path without touching the file system, files may only exist in memory
+pub fn normalize_path(path: &Path) -> PathBuf {
+    let mut normalized = PathBuf::new();
+
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                if !normalized.pop() {
+                    normalized.push("..");
+                }
+            }
+            component => normalized.push(component),
+        }
+    }
+    normalized
+}
+
+pub fn import_path(import: &ImportDirective) -> String {
+    import.path.path().value()
+}
+
+pub fn is_relative_import(path: &str) -> bool {
+    path.starts_with("./") || path.starts_with("../")
+}
+
+pub fn import_range(import: &ImportDirective) -> Range {
+    Range {
+        start: Position {
+            line: import.span().start().line,
+            character: import.span().start().column,
+        },
+        end: Position {
+            line: import.span().end().line,
+            character: import.span().end().column,
+        },
+    }
+}
+
+// Range removing the import with its line when nothing else is written on it
+pub fn import_deletion_range(content: &str, import: &ImportDirective) -> Range {
+    let mut range = import_range(import);
+    let lines: Vec<&str> = content.split('\n').collect();
+    let before = lines
+        .get(range.start.line - 1)
+        .map(|line| line.chars().take(range.start.character).collect::<String>())
+        .unwrap_or_default();
+    let after = lines
+        .get(range.end.line - 1)
+        .map(|line| line.chars().skip(range.end.character).collect::<String>())
+        .unwrap_or_default();
+
+    if before.trim().is_empty() && after.trim().is_empty() && range.end.line < lines.len() {
+        range.start.character = 0;
+        range.end = Position {
+            line: range.end.line + 1,
+            character: 0,
+        };
+    }
+    range
+}
+
+// Identifiers used in the file, outside of the import directives
+pub fn used_identifiers(file: &SolidFile) -> HashSet<String> {
+    let imports_locations: Vec<((usize, usize), (usize, usize))> =
+        retriever::retrieve_import_directive_nodes(&file.data)
+            .iter()
+            .map(|import| {
+                let (start, end) = (import.span().start(), import.span().end());
+                ((start.line, start.column), (end.line, end.column))
+            })
+            .collect();
+
+    tokenize_code(&file.content)
+        .into_iter()
+        .filter(|token| {
+            let location = (token.range.start.line, token.range.start.character);
+            token.kind == CodeTokenKind::Identifier
+                && !imports_locations
+                    .iter()
+                    .any(|(start, end)| location >= *start && location < *end)
+        })
+        .map(|token| token.text)
+        .collect()
+}
+
+/// Remappings of a Foundry project, read from the `remappings.txt` and `foundry.toml` files of
+/// the closest parent directory having one.
+pub struct Remappings {
+    pub root: PathBuf,
+    /// The remapped prefixes with the directories they point to, relative to `root`.
+    pub entries: Vec<(String, String)>,
+}
+
+// `context:prefix=target`, the context is not supported and ignored
+fn parse_remapping(remapping: &str) -> Option<(String, String)> {
+    let (prefix, target) = remapping.trim().split_once('=')?;
+    let prefix = prefix.rsplit(':').next().unwrap_or(prefix);
+    if prefix.is_empty() || target.is_empty() {
+        return None;
+    }
+    Some((prefix.to_string(), target.to_string()))
+}
+
+fn foundry_remappings(content: &str) -> Vec<String> {
+    let Ok(config) = content.parse::<toml::Table>() else {
+        return vec![];
+    };
+    let profile = config
+        .get("profile")
+        .and_then(|profile| profile.get("default"));
+    match profile.and_then(|profile| profile.get("remappings")) {
+        Some(toml::Value::Array(remappings)) => remappings
+            .iter()
+            .filter_map(|remapping| remapping.as_str())
+            .map(|remapping| remapping.to_string())
+            .collect(),
+        _ => vec![],
+    }
+}
+
+impl Remappings {
+    /// The directory of the closest remappings.txt or foundry.toml above a file, with the content
+    /// of both files.
+    pub fn sources(file: &str) -> Option<(PathBuf, String, String)> {
+        let directory = Path::new(file).parent()?;
+
+        for root in directory.ancestors() {
+            let remappings_file = root.join("remappings.txt");
+            let foundry_file = root.join("foundry.toml");
+            if !remappings_file.is_file() && !foundry_file.is_file() {
+                continue;
+            }
+            return Some((
+                root.to_path_buf(),
+                fs::read_to_string(remappings_file).unwrap_or_default(),
+                fs::read_to_string(foundry_file).unwrap_or_default(),
+            ));
+        }
+        None
+    }
+
+    pub fn find(file: &str) -> Option<Self> {
+        let (root, remappings_content, foundry_content) = Self::sources(file)?;
+        let mut remappings: Vec<String> = remappings_content
+            .lines()
+            .map(|line| line.to_string())
+            .collect();
+        remappings.extend(foundry_remappings(&foundry_content));
+        let entries = remappings
+            .iter()
+            .filter_map(|remapping| parse_remapping(remapping))
+            .collect();
+        Some(Remappings { root, entries })
+    }
+
+    /// The remapped path of a file, using the remapping with the longest target containing it.
+    pub fn remap(&self, path: &Path) -> Option<String> {
+        let path = normalize_path(path);
+
+        self.entries
+            .iter()
+            .filter_map(|(prefix, target)| {
+                let target = normalize_path(&self.root.join(target));
+                let rest = path.strip_prefix(&target).ok()?;
+                let rest = rest.to_str()?.replace('\\', "/");
+                let remapped = match prefix.ends_with('/') {
+                    true => format!("{}{}", prefix, rest),
+                    false => format!("{}/{}", prefix, rest),
+                };
+                Some((target.components().count(), remapped))
+            })
+            .max_by_key(|(depth, _)| *depth)
+            .map(|(_, remapped)| remapped)
+    }
+}
+
+/// Resolves the import directives of the loaded files to the files they import.
+pub struct ImportResolver<'a> {
+    files: &'a [SolidFile],
+    indexes: HashMap<PathBuf, usize>,
+}
+
+impl<'a> ImportResolver<'a> {
+    pub fn new(files: &'a [SolidFile]) -> Self {
+        let indexes = files
+            .iter()
+            .enumerate()
+            .map(|(index, file)| (normalize_path(Path::new(&file.path)), index))
+            .collect();
+        ImportResolver { files, indexes }
+    }
+
+    pub fn index_of(&self, file: &SolidFile) -> Option<usize> {
+        self.indexes
+            .get(&normalize_path(Path::new(&file.path)))
+            .copied()
+    }
+
+    /// Finds the file imported by `import` from the file at index `from`. Relative imports are
+    /// resolved from the directory of the file, others from any of its parent directories.
+    pub fn resolve(&self, from: usize, import: &str) -> Option<usize> {
+        let directory = Path::new(&self.files[from].path)
+            .parent()
+            .unwrap_or(Path::new(""));
+
+        if is_relative_import(import) {
+            return self
+                .indexes
+                .get(&normalize_path(&directory.join(import)))
+                .copied();
+        }
+        directory
+            .ancestors()
+            .find_map(|ancestor| self.indexes.get(&normalize_path(&ancestor.join(import))))
+            .copied()
+    }
+
+    /// The files imported by the file at index `from`, with the directive importing them.
+    pub fn imports_of(&self, from: usize) -> Vec<(ImportDirective, usize)> {
+        retriever::retrieve_import_directive_nodes(&self.files[from].data)
+            .into_iter()
+            .filter_map(|import| {
+                let index = self.resolve(from, &import_path(&import))?;
+                Some((import, index))
+            })
+            .collect()
+    }
+
+    /// The files imported by each loaded file, by index.
+    pub fn graph(&self) -> Vec<Vec<usize>> {
+        (0..self.files.len())
+            .map(|from| {
+                self.imports_of(from)
+                    .into_iter()
+                    .map(|(_, index)| index)
+                    .collect()
+            })
+            .collect()
+    }
+}
+
+// Bases of a contract, `Lib.Base` is looked up as `Base`
+pub fn base_names(contract: &ItemContract) -> Vec<String> {
+    match &contract.inheritance {
+        Some(inheritance) => inheritance
+            .inheritance
+            .iter()
+            .map(|base| base.name.last().to_string())
+            .collect(),
+        None => vec![],
+    }
+}
+
+// Bases of the contracts visible from a file, the ones it declares and the ones it imports
+// directly or not
+pub fn visible_bases(file: &SolidFile, files: &[SolidFile]) -> HashMap<String, Vec<String>> {
+    let mut bases = HashMap::new();
+    let mut add_contracts = |file: &SolidFile| {
+        for contract in retriever::retrieve_contract_nodes(&file.data) {
+            bases
+                .entry(contract.name.to_string())
+                .or_insert_with(|| base_names(&contract));
+        }
+    };
+    add_contracts(file);
+
+    let resolver = ImportResolver::new(files);
+    let Some(index) = resolver.index_of(file) else {
+        return bases;
+    };
+    let graph = resolver.graph();
+    let mut visited = HashSet::from([index]);
+    let mut stack = graph[index].clone();
+    while let Some(current) = stack.pop() {
+        if visited.insert(current) {
+            add_contracts(&files[current]);
+            stack.extend(&graph[current]);
+        }
+    }
+    bases
+}
@@ -0,0 +1,104 @@
+use crate::linter::SolidFile;
+use crate::rules::types::*;
+use crate::types::*;
+use osmium_libs_solidity_ast_extractor::*;
+
+// global
+pub const RULE_ID: &str = "comprehensive-interface";
+
+// specific
+const DEFAULT_SEVERITY: Severity = Severity::WARNING;
+
+pub struct ComprehensiveInterface {
+    data: RuleEntry,
+}
+
+impl ComprehensiveInterface {
+    fn create_diag(&self, file: &SolidFile, function: &ItemFunction) -> LintDiag {
+        let name = function.name.as_ref().unwrap();
+
+        LintDiag {
+            id: RULE_ID.to_string(),
+            range: Range {
+                start: Position {
+                    line: name.span().start().line,
+                    character: name.span().start().column,
+                },
+                end: Position {
+                    line: name.span().end().line,
+                    character: name.span().end().column,
+                },
+            },
+            same_line_ranges: None,
+            fix: None,
+            message: format!(
+                "Public function '{}' is not declared in an interface, add it to an interface and mark it override",
+                name
+            ),
+            severity: self.data.severity,
+            code: None,
+            source: None,
+            uri: file.path.clone(),
+        }
+    }
+}
+
+impl RuleType for ComprehensiveInterface {
+    fn diagnose(&self, file: &SolidFile, _files: &[SolidFile]) -> Vec<LintDiag> {
+        let mut res = Vec::new();
+
+        for contract in retriever::retrieve_contract_nodes(&file.data) {
+            if contract.is_interface() || contract.is_library() {
+                continue;
+            }
+            for function in retriever::retrieve_functions_nodes(&contract) {
+                if matches!(function.kind, FunctionKind::Function(_))
+                    && function.name.is_some()
+                    && (function.attributes.has_public() || function.attributes.has_external())
+                    && !function.attributes.has_override(None)
+                {
+                    res.push(self.create_diag(file, &function));
+                }
+            }
+        }
+        res
+    }
+
+    fn get_documentation(&self) -> RuleDocumentation {
+        RuleDocumentation {
+            id: RULE_ID.to_string(),
+            severity: DEFAULT_SEVERITY,
+            description: "All public and external functions of a contract must override a function declared in an interface.".to_string(),
+            category: "miscellaneous".to_string(),
+            example_config: "{\"id\": \"comprehensive-interface\", \"severity\": \"WARNING\"}".to_string(),
+            source_link: "https://github.com/astrodevs-labs/osmium/blob/main/toolchains/solidity/core/crates/linter-lib/src/rules/miscellaneous/comprehensive_interface.rs".to_string(),
+            test_link: "https://github.com/astrodevs-labs/osmium/tree/main/toolchains/solidity/core/crates/linter-lib/testdata/ComprehensiveInterface".to_string(),
+            options: vec![],
+            examples: Examples {
+                good: vec![Example {
+                    description: "Public function overriding an interface function".to_string(),
+                    code: "interface IToken { function mint() external; }\ncontract Token is IToken { function mint() external override {} }".to_string(),
+                }],
+                bad: vec![Example {
+                    description: "Public function not declared in an interface".to_string(),
+                    code: "contract Token { function mint() external {} }".to_string(),
+                }],
+            },
+        }
+    }
+}
+
+impl ComprehensiveInterface {
+    pub(crate) fn create(data: RuleEntry) -> Box<dyn RuleType> {
+        let rule = ComprehensiveInterface { data };
+        Box::new(rule)
+    }
+
+    pub(crate) fn create_default() -> RuleEntry {
+        RuleEntry {
+            id: RULE_ID.to_string(),
+            severity: DEFAULT_SEVERITY,
+            data: None,
+        }
+    }
+}
@@ -1,10 +1,47 @@
+use crate::rules::miscellaneous::comprehensive_interface::ComprehensiveInterface;
+use crate::rules::miscellaneous::no_unused_import::NoUnusedImport;
+use crate::rules::miscellaneous::no_unused_import_symbols::NoUnusedImportSymbols;
+use crate::rules::miscellaneous::no_unused_vars::NoUnusedVars;
+use crate::rules::miscellaneous::quotes::Quotes;
 use crate::rules::types::{RuleEntry, RulesMap};
+use crate::rules::RuleBuilder;
 use std::collections::HashMap;
 
+pub(crate) mod comprehensive_interface;
+pub(crate) mod no_unused_import;
+pub(crate) mod no_unused_import_symbols;
+pub(crate) mod no_unused_vars;
+pub(crate) mod quotes;
+
+// List all rules
+
 pub fn create_default_rules() -> Vec<RuleEntry> {
-    vec![]
+    vec![
+        Quotes::create_default(),
+        NoUnusedVars::create_default(),
+        NoUnusedImport::create_default(),
+        NoUnusedImportSymbols::create_default(),
+        ComprehensiveInterface::create_default(),
+    ]
 }
 
 pub fn create_rules() -> RulesMap {
-    HashMap::new()
+    let mut rules: HashMap<String, RuleBuilder> = HashMap::new();
+
+    rules.insert(quotes::RULE_ID.to_string(), Quotes::create);
+    rules.insert(no_unused_vars::RULE_ID.to_string(), NoUnusedVars::create);
+    rules.insert(
+        no_unused_import::RULE_ID.to_string(),
+        NoUnusedImport::create,
+    );
+    rules.insert(
+        no_unused_import_symbols::RULE_ID.to_string(),
+        NoUnusedImportSymbols::create,
+    );
+    rules.insert(
+        comprehensive_interface::RULE_ID.to_string(),
+        ComprehensiveInterface::create,
+    );
+
+    rules
 }
@@ -0,0 +1,117 @@
+use crate::linter::SolidFile;
+use crate::rules::imports::used_identifiers;
+use crate::rules::types::*;
+use crate::types::*;
+use osmium_libs_solidity_ast_extractor::*;
+
+// global
+pub const RULE_ID: &str = "no-unused-import";
+
+// specific
+const DEFAULT_SEVERITY: Severity = Severity::WARNING;
+
+pub struct NoUnusedImport {
+    data: RuleEntry,
+}
+
+// Names an import directive brings in scope, plain imports without alias bring everything.
+// Symbols imported between braces are checked one by one by no-unused-import-symbols.
+fn imported_names(import: &ImportDirective) -> Vec<String> {
+    match &import.path {
+        ImportPath::Plain(plain) => plain
+            .alias
+            .iter()
+            .map(|alias| alias.alias.to_string())
+            .collect(),
+        ImportPath::Aliases(_) => Vec::new(),
+        ImportPath::Glob(glob) => glob
+            .alias
+            .iter()
+            .map(|alias| alias.alias.to_string())
+            .collect(),
+    }
+}
+
+impl NoUnusedImport {
+    fn create_diag(
+        &self,
+        file: &SolidFile,
+        import: &ImportDirective,
+        names: &[String],
+    ) -> LintDiag {
+        LintDiag {
+            id: RULE_ID.to_string(),
+            range: Range {
+                start: Position {
+                    line: import.span().start().line,
+                    character: import.span().start().column,
+                },
+                end: Position {
+                    line: import.span().end().line,
+                    character: import.span().end().column,
+                },
+            },
+            same_line_ranges: None,
+            fix: None,
+            message: format!("Imported {} never used", names.join(", ")),
+            severity: self.data.severity,
+            code: None,
+            source: None,
+            uri: file.path.clone(),
+        }
+    }
+}
+
+impl RuleType for NoUnusedImport {
+    fn diagnose(&self, file: &SolidFile, _files: &[SolidFile]) -> Vec<LintDiag> {
+        let mut res = Vec::new();
+        let imports = retriever::retrieve_import_directive_nodes(&file.data);
+        let used = used_identifiers(file);
+
+        for import in &imports {
+            let names = imported_names(import);
+            if !names.is_empty() && names.iter().all(|name| !used.contains(name)) {
+                res.push(self.create_diag(file, import, &names));
+            }
+        }
+        res
+    }
+
+    fn get_documentation(&self) -> RuleDocumentation {
+        RuleDocumentation {
+            id: RULE_ID.to_string(),
+            severity: DEFAULT_SEVERITY,
+            description: "Imported aliases must be used. Plain imports without alias are not checked as they import everything, and symbols imported between braces are checked by no-unused-import-symbols.".to_string(),
+            category: "miscellaneous".to_string(),
+            example_config: "{\"id\": \"no-unused-import\", \"severity\": \"WARNING\"}".to_string(),
+            source_link: "https://github.com/astrodevs-labs/osmium/blob/main/toolchains/solidity/core/crates/linter-lib/src/rules/miscellaneous/no_unused_import.rs".to_string(),
+            test_link: "https://github.com/astrodevs-labs/osmium/tree/main/toolchains/solidity/core/crates/linter-lib/testdata/NoUnusedImport".to_string(),
+            options: vec![],
+            examples: Examples {
+                good: vec![Example {
+                    description: "Imported alias is used".to_string(),
+                    code: "import \"./A.sol\" as A;\ncontract B is A.C {}".to_string(),
+                }],
+                bad: vec![Example {
+                    description: "Imported alias is never used".to_string(),
+                    code: "import \"./A.sol\" as A;\ncontract B {}".to_string(),
+                }],
+            },
+        }
+    }
+}
+
+impl NoUnusedImport {
+    pub(crate) fn create(data: RuleEntry) -> Box<dyn RuleType> {
+        let rule = NoUnusedImport { data };
+        Box::new(rule)
+    }
+
+    pub(crate) fn create_default() -> RuleEntry {
+        RuleEntry {
+            id: RULE_ID.to_string(),
+            severity: DEFAULT_SEVERITY,
+            data: None,
+        }
+    }
+}
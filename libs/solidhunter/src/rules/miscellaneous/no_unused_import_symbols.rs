@@ -0,0 +1,113 @@
+use crate::linter::SolidFile;
+use crate::rules::imports::{import_path, used_identifiers};
+use crate::rules::types::*;
+use crate::types::*;
+use osmium_libs_solidity_ast_extractor::*;
+
+// global
+pub const RULE_ID: &str = "no-unused-import-symbols";
+
+// specific
+const DEFAULT_SEVERITY: Severity = Severity::WARNING;
+
+pub struct NoUnusedImportSymbols {
+    data: RuleEntry,
+}
+
+impl NoUnusedImportSymbols {
+    fn create_diag(
+        &self,
+        file: &SolidFile,
+        import: &ImportDirective,
+        symbol: &SolIdent,
+        name: &str,
+    ) -> LintDiag {
+        LintDiag {
+            id: RULE_ID.to_string(),
+            range: Range {
+                start: Position {
+                    line: symbol.span().start().line,
+                    character: symbol.span().start().column,
+                },
+                end: Position {
+                    line: symbol.span().end().line,
+                    character: symbol.span().end().column,
+                },
+            },
+            same_line_ranges: None,
+            fix: None,
+            message: format!(
+                "{} imported from \"{}\" is never used",
+                name,
+                import_path(import)
+            ),
+            severity: self.data.severity,
+            code: None,
+            source: None,
+            uri: file.path.clone(),
+        }
+    }
+}
+
+impl RuleType for NoUnusedImportSymbols {
+    fn diagnose(&self, file: &SolidFile, _files: &[SolidFile]) -> Vec<LintDiag> {
+        let mut res = Vec::new();
+        let used = used_identifiers(file);
+
+        for import in retriever::retrieve_import_directive_nodes(&file.data) {
+            let ImportPath::Aliases(aliases) = &import.path else {
+                continue;
+            };
+            for (name, alias) in aliases.imports.iter() {
+                // The alias is the name in scope, the diagnostic points to it
+                let symbol = match alias {
+                    Some(alias) => &alias.alias,
+                    None => name,
+                };
+                let symbol_name = symbol.to_string();
+                if !used.contains(&symbol_name) {
+                    res.push(self.create_diag(file, &import, symbol, &symbol_name));
+                }
+            }
+        }
+        res
+    }
+
+    fn get_documentation(&self) -> RuleDocumentation {
+        RuleDocumentation {
+            id: RULE_ID.to_string(),
+            severity: DEFAULT_SEVERITY,
+            description: "Each symbol imported between braces must be used. Unlike no-unused-import, the symbols are checked one by one.".to_string(),
+            category: "miscellaneous".to_string(),
+            example_config: "{\"id\": \"no-unused-import-symbols\", \"severity\": \"WARNING\"}".to_string(),
+            source_link: "https://github.com/astrodevs-labs/osmium/blob/main/toolchains/solidity/core/crates/linter-lib/src/rules/miscellaneous/no_unused_import_symbols.rs".to_string(),
+            test_link: "https://github.com/astrodevs-labs/osmium/tree/main/toolchains/solidity/core/crates/linter-lib/testdata/NoUnusedImportSymbols".to_string(),
+            options: vec![],
+            examples: Examples {
+                good: vec![Example {
+                    description: "Every imported symbol is used".to_string(),
+                    code: "import {A, B} from \"./A.sol\";\ncontract C is A, B {}".to_string(),
+                }],
+                bad: vec![Example {
+                    description: "B is never used".to_string(),
+                    code: "import {A, B} from \"./A.sol\";\ncontract C is A {}".to_string(),
+                }],
+            },
+        }
+    }
+}
+
+impl NoUnusedImportSymbols {
+    pub(crate) fn create(data: RuleEntry) -> Box<dyn RuleType> {
+        let rule = NoUnusedImportSymbols { data };
+        Box::new(rule)
+    }
+
+    pub(crate) fn create_default() -> RuleEntry {
+        RuleEntry {
+            id: RULE_ID.to_string(),
+            severity: DEFAULT_SEVERITY,
+            data: None,
+        }
+    }
+}
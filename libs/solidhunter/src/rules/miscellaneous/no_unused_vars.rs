@@ -0,0 +1,172 @@
+use crate::linter::SolidFile;
+use crate::rules::types::*;
+use crate::rules::utils::{tokenize_code, CodeToken, CodeTokenKind};
+use crate::types::*;
+use osmium_libs_solidity_ast_extractor::*;
+
+// global
+pub const RULE_ID: &str = "no-unused-vars";
+
+// specific
+const DEFAULT_SEVERITY: Severity = Severity::WARNING;
+
+struct VarDeclVisitor {
+    names: Vec<SolIdent>,
+}
+
+impl<'ast> Visit<'ast> for VarDeclVisitor {
+    fn visit_stmt_var_decl(&mut self, stmt: &'ast StmtVarDecl) {
+        match &stmt.declaration {
+            VarDeclDecl::VarDecl(var) => self.names.extend(var.name.clone()),
+            VarDeclDecl::Tuple(tuple) => {
+                for var in tuple.vars.iter().flatten() {
+                    self.names.extend(var.name.clone());
+                }
+            }
+        }
+        visit::visit_stmt_var_decl(self, stmt);
+    }
+}
+
+pub struct NoUnusedVars {
+    data: RuleEntry,
+}
+
+fn to_key(location: LineColumn) -> (usize, usize) {
+    (location.line, location.column)
+}
+
+// Count the occurrences of an identifier between two locations
+fn count_occurrences(
+    tokens: &[CodeToken],
+    name: &str,
+    start: (usize, usize),
+    end: (usize, usize),
+) -> usize {
+    tokens
+        .iter()
+        .filter(|token| {
+            let location = (token.range.start.line, token.range.start.character);
+            token.kind == CodeTokenKind::Identifier
+                && token.text == name
+                && location >= start
+                && location < end
+        })
+        .count()
+}
+
+impl NoUnusedVars {
+    fn create_diag(&self, file: &SolidFile, name: &SolIdent, kind: &str) -> LintDiag {
+        LintDiag {
+            id: RULE_ID.to_string(),
+            range: Range {
+                start: Position {
+                    line: name.span().start().line,
+                    character: name.span().start().column,
+                },
+                end: Position {
+                    line: name.span().end().line,
+                    character: name.span().end().column,
+                },
+            },
+            same_line_ranges: None,
+            fix: None,
+            message: format!("{} '{}' is unused", kind, name),
+            severity: self.data.severity,
+            code: None,
+            source: None,
+            uri: file.path.clone(),
+        }
+    }
+}
+
+impl RuleType for NoUnusedVars {
+    fn diagnose(&self, file: &SolidFile, _files: &[SolidFile]) -> Vec<LintDiag> {
+        let mut res = Vec::new();
+        let tokens = tokenize_code(&file.content);
+
+        for contract in retriever::retrieve_contract_nodes(&file.data) {
+            for function in retriever::retrieve_functions_nodes(&contract) {
+                let block = match &function.body {
+                    FunctionBody::Block(block) => block,
+                    FunctionBody::Empty(_) => continue,
+                };
+                // Parameters can be used by the modifiers, so everything after the parameter list
+                let start = match (&function.paren_token, &function.name) {
+                    (Some(paren), _) => to_key(paren.span.close().end()),
+                    (None, Some(name)) => to_key(name.span().end()),
+                    (None, None) => to_key(function.span().start()),
+                };
+                let end = to_key(block.brace_token.span.close().end());
+
+                for param in function.parameters.iter() {
+                    if let Some(name) = &param.name {
+                        if count_occurrences(&tokens, &name.to_string(), start, end) == 0 {
+                            res.push(self.create_diag(file, name, "Parameter"));
+                        }
+                    }
+                }
+
+                let mut visitor = VarDeclVisitor { names: vec![] };
+                visitor.visit_block(block);
+                for name in visitor.names {
+                    // The declaration itself is one occurrence
+                    if count_occurrences(&tokens, &name.to_string(), start, end) <= 1 {
+                        res.push(self.create_diag(file, &name, "Variable"));
+                    }
+                }
+            }
+        }
+        res
+    }
+
+    fn get_documentation(&self) -> RuleDocumentation {
+        RuleDocumentation {
+            id: RULE_ID.to_string(),
+            severity: DEFAULT_SEVERITY,
+            description: "Variable and function parameters declared must be used. Unused parameters can be left unnamed.".to_string(),
+            category: "miscellaneous".to_string(),
+            example_config: "{\"id\": \"no-unused-vars\", \"severity\": \"WARNING\"}".to_string(),
+            source_link: "https://github.com/astrodevs-labs/osmium/blob/main/toolchains/solidity/core/crates/linter-lib/src/rules/miscellaneous/no_unused_vars.rs".to_string(),
+            test_link: "https://github.com/astrodevs-labs/osmium/tree/main/toolchains/solidity/core/crates/linter-lib/testdata/NoUnusedVars".to_string(),
+            options: vec![],
+            examples: Examples {
+                good: vec![
+                    Example {
+                        description: "Every variable and parameter is used".to_string(),
+                        code: "function add(uint256 a, uint256 b) public pure returns (uint256) {\n    uint256 sum = a + b;\n    return sum;\n}".to_string(),
+                    },
+                    Example {
+                        description: "Unused parameters are unnamed".to_string(),
+                        code: "function onReceived(address, uint256 amount) external { total += amount; }".to_string(),
+                    },
+                ],
+                bad: vec![
+                    Example {
+                        description: "Parameter b is unused".to_string(),
+                        code: "function double(uint256 a, uint256 b) public pure returns (uint256) { return a * 2; }".to_string(),
+                    },
+                    Example {
+                        description: "Variable sum is unused".to_string(),
+                        code: "function add(uint256 a, uint256 b) public pure { uint256 sum = a + b; }".to_string(),
+                    },
+                ],
+            },
+        }
+    }
+}
+
+impl NoUnusedVars {
+    pub(crate) fn create(data: RuleEntry) -> Box<dyn RuleType> {
+        let rule = NoUnusedVars { data };
+        Box::new(rule)
+    }
+
+    pub(crate) fn create_default() -> RuleEntry {
+        RuleEntry {
+            id: RULE_ID.to_string(),
+            severity: DEFAULT_SEVERITY,
+            data: None,
+        }
+    }
+}
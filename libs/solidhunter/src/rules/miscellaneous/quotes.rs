@@ -0,0 +1,140 @@
+use crate::linter::SolidFile;
+use crate::rules::types::*;
+use crate::rules::utils::{tokenize_code, CodeTokenKind};
+use crate::types::*;
+
+// global
+pub const RULE_ID: &str = "quotes";
+
+// specific
+const DEFAULT_QUOTES: &str = "double";
+const DEFAULT_SEVERITY: Severity = Severity::WARNING;
+
+pub struct Quotes {
+    quote: char,
+    data: RuleEntry,
+}
+
+impl Quotes {
+    fn create_diag(&self, file: &SolidFile, range: Range) -> LintDiag {
+        let message = if self.quote == '"' {
+            "Use double quotes for string literals"
+        } else {
+            "Use single quotes for string literals"
+        };
+
+        LintDiag {
+            id: RULE_ID.to_string(),
+            range,
+            same_line_ranges: None,
+            fix: None,
+            message: message.to_string(),
+            severity: self.data.severity,
+            code: None,
+            source: None,
+            uri: file.path.clone(),
+        }
+    }
+}
+
+impl RuleType for Quotes {
+    fn diagnose(&self, file: &SolidFile, _files: &[SolidFile]) -> Vec<LintDiag> {
+        let mut res = Vec::new();
+
+        for token in tokenize_code(&file.content) {
+            if let CodeTokenKind::String(quote) = token.kind {
+                let inner = &token.text[1..token.text.len() - 1];
+                // Switching quotes would require escaping the preferred one
+                if quote != self.quote && !inner.contains(self.quote) {
+                    res.push(self.create_diag(file, token.range));
+                }
+            }
+        }
+        res
+    }
+
+    fn get_documentation(&self) -> RuleDocumentation {
+        RuleDocumentation {
+            id: RULE_ID.to_string(),
+            severity: DEFAULT_SEVERITY,
+            description: "Enforce the quotes used for string literals. Strings containing the preferred quote are allowed to use the other one.".to_string(),
+            category: "miscellaneous".to_string(),
+            example_config: "{\"id\": \"quotes\", \"severity\": \"WARNING\", \"data\": \"double\"}".to_string(),
+            source_link: "https://github.com/astrodevs-labs/osmium/blob/main/toolchains/solidity/core/crates/linter-lib/src/rules/miscellaneous/quotes.rs".to_string(),
+            test_link: "https://github.com/astrodevs-labs/osmium/tree/main/toolchains/solidity/core/crates/linter-lib/testdata/Quotes".to_string(),
+            options: vec![Options {
+                description: "Options need to be one of \"double\", \"single\"".to_string(),
+                default: DEFAULT_QUOTES.to_string(),
+            }],
+            examples: Examples {
+                good: vec![
+                    Example {
+                        description: "If double is selected".to_string(),
+                        code: "string public name = \"Token\";".to_string(),
+                    },
+                    Example {
+                        description: "If single is selected".to_string(),
+                        code: "string public name = 'Token';".to_string(),
+                    },
+                    Example {
+                        description: "At any setting, when the string contains the preferred quote".to_string(),
+                        code: "string public message = 'Say \"hello\"';".to_string(),
+                    },
+                ],
+                bad: vec![
+                    Example {
+                        description: "If double is selected".to_string(),
+                        code: "string public name = 'Token';".to_string(),
+                    },
+                    Example {
+                        description: "If single is selected".to_string(),
+                        code: "string public name = \"Token\";".to_string(),
+                    },
+                ],
+            },
+        }
+    }
+
+    fn fix(&self, file: &SolidFile, _files: &[SolidFile], diag: &LintDiag) -> Option<Fix> {
+        let literal = diag.range.text(&file.content)?;
+        let inner = literal.get(1..literal.len().checked_sub(1)?)?;
+        if inner.contains(self.quote) {
+            return None;
+        }
+        let new_text = format!("{}{}{}", self.quote, inner, self.quote);
+
+        Some(Fix {
+            message: format!("Replace {} with {}", literal, new_text),
+            edits: vec![TextEdit {
+                range: diag.range.clone(),
+                new_text,
+            }],
+        })
+    }
+}
+
+impl Quotes {
+    pub(crate) fn create(data: RuleEntry) -> Box<dyn RuleType> {
+        let mut value = DEFAULT_QUOTES.to_string();
+
+        if let Some(data) = &data.data {
+            match serde_json::from_value::<String>(data.clone()) {
+                Ok(val) if val == "double" || val == "single" => value = val,
+                _ => {
+                    eprintln!("{} rule : bad config data", RULE_ID);
+                }
+            }
+        }
+        let quote = if value == "single" { '\'' } else { '"' };
+        let rule = Quotes { quote, data };
+        Box::new(rule)
+    }
+
+    pub(crate) fn create_default() -> RuleEntry {
+        RuleEntry {
+            id: RULE_ID.to_string(),
+            severity: DEFAULT_SEVERITY,
+            data: Some(DEFAULT_QUOTES.into()),
+        }
+    }
+}
@@ -1,17 +1,25 @@
 use crate::rules::types::{RuleEntry, RuleType, RulesMap};
+use crate::types::Severity;
 use std::collections::HashMap;
 
 pub mod factory;
+pub mod imports;
+pub mod presets;
 pub mod rule_impl;
+pub mod semver;
 pub mod types;
 pub mod utils;
+pub mod validation;
 
 // List all rules
 pub mod best_practises;
+pub mod gas;
 pub mod miscellaneous;
 pub mod naming;
+pub mod natspec;
 pub mod order;
 pub mod security;
+pub mod upgradeability;
 
 pub fn create_default_rules() -> Vec<RuleEntry> {
     let mut rules = Vec::new();
@@ -21,6 +29,17 @@ pub fn create_default_rules() -> Vec<RuleEntry> {
     rules.append(&mut naming::create_default_rules());
     rules.append(&mut order::create_default_rules());
     rules.append(&mut security::create_default_rules());
+    rules.append(&mut natspec::create_default_rules());
+    rules.append(&mut upgradeability::create_default_rules());
+    // Gas rules are off by default, the gas and all presets enable them
+    rules.extend(
+        gas::create_default_rules()
+            .into_iter()
+            .map(|rule| RuleEntry {
+                severity: Severity::OFF,
+                ..rule
+            }),
+    );
 
     rules
 }
@@ -41,6 +60,9 @@ pub fn create_rules() -> RulesMap {
     add_rules(&mut rules, order::create_rules());
     add_rules(&mut rules, miscellaneous::create_rules());
     add_rules(&mut rules, security::create_rules());
+    add_rules(&mut rules, natspec::create_rules());
+    add_rules(&mut rules, upgradeability::create_rules());
+    add_rules(&mut rules, gas::create_rules());
 
     rules
 }
@@ -34,6 +34,7 @@ impl ConstNameSnakeCase {
             code: None,
             source: None,
             same_line_ranges: None,
+            fix: None,
             uri: file.path.clone(),
         }
     }
@@ -34,6 +34,7 @@ impl EventNameCamelCase {
             code: None,
             source: None,
             same_line_ranges: None,
+            fix: None,
             uri: file.path.clone(),
         }
     }
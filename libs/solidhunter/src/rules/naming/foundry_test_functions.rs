@@ -43,6 +43,7 @@ impl FoundryTestFunctions {
             code: None,
             source: None,
             same_line_ranges: None,
+            fix: None,
             uri: file.path.clone(),
         }
     }
@@ -113,7 +114,7 @@ impl RuleType for FoundryTestFunctions {
             test_link: "https://github.com/astrodevs-labs/osmium/tree/main/toolchains/solidity/core/crates/linter-lib/testdata/FoundryTestFunctions".to_string(),
             options: vec![Options {
                 description: "Array of function to be skipped".to_string(),
-                default: "[]".to_string(),
+                default: "[\"setUp\"]".to_string(),
             }],
             examples: Examples {
                 good: vec![
@@ -1,7 +1,12 @@
-use osmium_libs_solidity_ast_extractor::{LineColumn, Spanned};
+use osmium_libs_solidity_ast_extractor::retriever::{
+    retrieve_contract_nodes, retrieve_functions_nodes,
+};
+use osmium_libs_solidity_ast_extractor::Visibility::{Internal, Private};
+use osmium_libs_solidity_ast_extractor::{ItemContract, ItemFunction, LineColumn, Spanned};
 
 use crate::linter::SolidFile;
 use crate::rules::types::*;
+use crate::rules::utils::{function_range, is_used_outside, rename_contract_member, to_mixed_case};
 use crate::types::*;
 
 // global
@@ -34,11 +39,24 @@ impl FuncNameMixedCase {
             code: None,
             source: None,
             same_line_ranges: None,
+            fix: None,
             uri: file.path.clone(),
         }
     }
 }
 
+// Retrieve the function reported by a diagnostic, with the contract declaring it
+fn find_function(file: &SolidFile, range: &Range) -> Option<(ItemContract, ItemFunction)> {
+    retrieve_contract_nodes(&file.data)
+        .into_iter()
+        .find_map(|contract| {
+            retrieve_functions_nodes(&contract)
+                .into_iter()
+                .find(|function| function.name.is_some() && function_range(function) == *range)
+                .map(|function| (contract, function))
+        })
+}
+
 impl RuleType for FuncNameMixedCase {
     fn diagnose(&self, file: &SolidFile, _files: &[SolidFile]) -> Vec<LintDiag> {
         let mut res = Vec::new();
@@ -82,6 +100,26 @@ impl RuleType for FuncNameMixedCase {
             },
         }
     }
+
+    fn fix(&self, file: &SolidFile, files: &[SolidFile], diag: &LintDiag) -> Option<Fix> {
+        let name = diag.range.text(&file.content)?;
+        let new_name = to_mixed_case(name);
+
+        if new_name == name || new_name.trim_start_matches('_').is_empty() {
+            return None;
+        }
+        let (contract, function) = find_function(file, &diag.range)?;
+        // Renaming a public or external function would change the ABI of the contract
+        match function.attributes.visibility() {
+            Some(Private(_)) => {}
+            Some(Internal(_)) if !is_used_outside(file, files, &contract, name) => {}
+            _ => return None,
+        }
+        Some(Fix {
+            message: format!("Rename function to '{}'", new_name),
+            edits: rename_contract_member(file, &contract, name, &new_name),
+        })
+    }
 }
 
 impl FuncNameMixedCase {
@@ -58,6 +58,7 @@ impl NamedParametersMapping {
             code: None,
             source: None,
             same_line_ranges: None,
+            fix: None,
             uri: file.path.clone(),
         }
     }
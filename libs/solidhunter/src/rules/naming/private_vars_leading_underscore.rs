@@ -1,8 +1,14 @@
+use osmium_libs_solidity_ast_extractor::retriever::{
+    retrieve_contract_nodes, retrieve_functions_nodes,
+};
 use osmium_libs_solidity_ast_extractor::Visibility::{Internal, Private};
-use osmium_libs_solidity_ast_extractor::{Item, LineColumn, Spanned};
+use osmium_libs_solidity_ast_extractor::{
+    Item, ItemContract, ItemFunction, LineColumn, SolIdent, Spanned, Visibility,
+};
 
 use crate::linter::SolidFile;
 use crate::rules::types::*;
+use crate::rules::utils::{is_used_outside, rename_contract_member, rename_local, span_range};
 use crate::types::*;
 
 // global
@@ -46,11 +52,61 @@ impl PrivateVarsLeadingUnderscore {
             code: None,
             source: None,
             same_line_ranges: None,
+            fix: None,
             uri: file.path.clone(),
         }
     }
 }
 
+// Declaration reported by a diagnostic
+enum Declaration {
+    // Function or state variable, with its visibility
+    Member(ItemContract, Option<Visibility>),
+    // Parameter or return of a function
+    Local(ItemFunction),
+}
+
+fn is_at(name: &SolIdent, range: &Range) -> bool {
+    span_range(name.span()) == *range
+}
+
+fn find_declaration(file: &SolidFile, range: &Range) -> Option<Declaration> {
+    for contract in retrieve_contract_nodes(&file.data) {
+        for function in retrieve_functions_nodes(&contract) {
+            if function
+                .name
+                .as_ref()
+                .is_some_and(|name| is_at(name, range))
+            {
+                // Functions without visibility do not compile, their intent is unknown
+                let visibility = function.attributes.visibility();
+                return visibility
+                    .map(|visibility| Declaration::Member(contract, Some(visibility)));
+            }
+            let returns = function
+                .returns
+                .iter()
+                .flat_map(|returns| returns.returns.iter());
+            if function
+                .parameters
+                .iter()
+                .chain(returns)
+                .any(|arg| arg.name.as_ref().is_some_and(|name| is_at(name, range)))
+            {
+                return Some(Declaration::Local(function));
+            }
+        }
+        let visibility = contract.body.iter().find_map(|item| match item {
+            Item::Variable(var) if is_at(&var.name, range) => Some(var.attributes.visibility()),
+            _ => None,
+        });
+        if let Some(visibility) = visibility {
+            return Some(Declaration::Member(contract, visibility));
+        }
+    }
+    None
+}
+
 impl RuleType for PrivateVarsLeadingUnderscore {
     fn diagnose(&self, file: &SolidFile, _files: &[SolidFile]) -> Vec<LintDiag> {
         let mut res = Vec::new();
@@ -197,6 +253,35 @@ impl RuleType for PrivateVarsLeadingUnderscore {
             },
         }
     }
+
+    fn fix(&self, file: &SolidFile, files: &[SolidFile], diag: &LintDiag) -> Option<Fix> {
+        let name = diag.range.text(&file.content)?;
+        let new_name = if name.starts_with('_') {
+            name.trim_start_matches('_').to_string()
+        } else {
+            format!("_{}", name)
+        };
+
+        if new_name.is_empty() {
+            return None;
+        }
+        let edits = match find_declaration(file, &diag.range)? {
+            Declaration::Local(function) => rename_local(file, &function, name, &new_name),
+            // Renaming a public or external member would change the ABI of the contract, state
+            // variables without visibility are internal
+            Declaration::Member(contract, visibility) => match visibility {
+                Some(Private(_)) => rename_contract_member(file, &contract, name, &new_name),
+                Some(Internal(_)) | None if !is_used_outside(file, files, &contract, name) => {
+                    rename_contract_member(file, &contract, name, &new_name)
+                }
+                _ => return None,
+            },
+        };
+        Some(Fix {
+            message: format!("Rename to '{}'", new_name),
+            edits,
+        })
+    }
 }
 
 impl PrivateVarsLeadingUnderscore {
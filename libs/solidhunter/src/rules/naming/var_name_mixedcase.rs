@@ -34,6 +34,7 @@ impl VarNameMixedCase {
             code: None,
             source: None,
             same_line_ranges: None,
+            fix: None,
             uri: file.path.clone(),
         }
     }
@@ -59,8 +60,7 @@ impl RuleType for VarNameMixedCase {
                 &file.data,
             );
         for variable in variables_declaration {
-            if variable.name.is_some() {
-                let name = variable.name.unwrap();
+            if let Some(name) = variable.name {
                 if name.to_string()[1..].find('_').is_some() {
                     let span = name.span();
                     res.push(self.create_diag((span.start(), span.end()), file));
@@ -0,0 +1,33 @@
+use crate::rules::types::{RuleEntry, RulesMap};
+use std::collections::HashMap;
+
+pub(crate) mod natspec_contract;
+pub(crate) mod natspec_function;
+
+// List all rules
+use crate::rules::natspec::natspec_contract::NatspecContract;
+use crate::rules::natspec::natspec_function::NatspecFunction;
+
+use crate::rules::RuleBuilder;
+
+pub fn create_default_rules() -> Vec<RuleEntry> {
+    vec![
+        NatspecFunction::create_default(),
+        NatspecContract::create_default(),
+    ]
+}
+
+pub fn create_rules() -> RulesMap {
+    let mut rules: HashMap<String, RuleBuilder> = HashMap::new();
+
+    rules.insert(
+        natspec_function::RULE_ID.to_string(),
+        NatspecFunction::create,
+    );
+    rules.insert(
+        natspec_contract::RULE_ID.to_string(),
+        NatspecContract::create,
+    );
+
+    rules
+}
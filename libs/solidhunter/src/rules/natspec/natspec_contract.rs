@@ -0,0 +1,167 @@
+use crate::linter::SolidFile;
+use crate::rules::types::*;
+use crate::types::*;
+use osmium_libs_solidity_ast_extractor::natspec::*;
+use osmium_libs_solidity_ast_extractor::*;
+use serde::{Deserialize, Serialize};
+
+// global
+pub const RULE_ID: &str = "natspec-contract";
+
+// specific
+const DEFAULT_SEVERITY: Severity = Severity::INFO;
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+struct NatspecContractOptions {
+    #[serde(default = "default_true")]
+    title: bool,
+    #[serde(default = "default_true")]
+    author: bool,
+}
+
+fn default_true() -> bool {
+    true
+}
+
+impl Default for NatspecContractOptions {
+    fn default() -> Self {
+        NatspecContractOptions {
+            title: true,
+            author: true,
+        }
+    }
+}
+
+pub struct NatspecContract {
+    options: NatspecContractOptions,
+    data: RuleEntry,
+}
+
+impl NatspecContract {
+    fn create_diag(&self, file: &SolidFile, contract: &ItemContract, message: String) -> LintDiag {
+        LintDiag {
+            id: RULE_ID.to_string(),
+            range: Range {
+                start: Position {
+                    line: contract.name.span().start().line,
+                    character: contract.name.span().start().column,
+                },
+                end: Position {
+                    line: contract.name.span().end().line,
+                    character: contract.name.span().end().column,
+                },
+            },
+            same_line_ranges: None,
+            fix: None,
+            message,
+            severity: self.data.severity,
+            code: None,
+            source: None,
+            uri: file.path.clone(),
+        }
+    }
+}
+
+impl RuleType for NatspecContract {
+    fn diagnose(&self, file: &SolidFile, _files: &[SolidFile]) -> Vec<LintDiag> {
+        let mut res = Vec::new();
+        if !self.options.title && !self.options.author {
+            return res;
+        }
+        let comments = extract_doc_comments(&file.content);
+
+        for contract in retriever::retrieve_contract_nodes(&file.data) {
+            let comment = retrieve_doc_comment(&comments, contract.kind.span().start());
+            let natspec = match comment {
+                Some(comment) => parse_natspec(comment),
+                None => {
+                    res.push(self.create_diag(
+                        file,
+                        &contract,
+                        format!(
+                            "Missing NatSpec documentation for {} {}",
+                            contract.kind, contract.name
+                        ),
+                    ));
+                    continue;
+                }
+            };
+
+            let required = [
+                (self.options.title, NatSpecKind::Title, "@title"),
+                (self.options.author, NatSpecKind::Author, "@author"),
+            ];
+            for (enabled, kind, tag) in required {
+                if enabled && !natspec.has(&kind) {
+                    res.push(self.create_diag(
+                        file,
+                        &contract,
+                        format!(
+                            "Missing {} in the documentation of {} {}",
+                            tag, contract.kind, contract.name
+                        ),
+                    ));
+                }
+            }
+        }
+        res
+    }
+
+    fn get_documentation(&self) -> RuleDocumentation {
+        RuleDocumentation {
+            id: RULE_ID.to_string(),
+            severity: DEFAULT_SEVERITY,
+            description: "Contracts, interfaces and libraries must be documented with a NatSpec @title and @author.".to_string(),
+            category: "natspec".to_string(),
+            example_config: "{\"id\": \"natspec-contract\", \"severity\": \"INFO\", \"data\": {\"title\": true, \"author\": true}}".to_string(),
+            source_link: "https://github.com/astrodevs-labs/osmium/blob/main/toolchains/solidity/core/crates/linter-lib/src/rules/natspec/natspec_contract.rs".to_string(),
+            test_link: "https://github.com/astrodevs-labs/osmium/tree/main/toolchains/solidity/core/crates/linter-lib/testdata/NatspecContract".to_string(),
+            options: vec![Options {
+                description: "title requires a @title and author requires an @author".to_string(),
+                default: "{\"title\": true, \"author\": true}".to_string(),
+            }],
+            examples: Examples {
+                good: vec![Example {
+                    description: "Documented contract".to_string(),
+                    code: "/// @title Token\n/// @author Osmium\ncontract Token {}".to_string(),
+                }],
+                bad: vec![
+                    Example {
+                        description: "Undocumented contract".to_string(),
+                        code: "contract Token {}".to_string(),
+                    },
+                    Example {
+                        description: "Contract without @author".to_string(),
+                        code: "/// @title Token\ncontract Token {}".to_string(),
+                    },
+                ],
+            },
+        }
+    }
+}
+
+impl NatspecContract {
+    pub(crate) fn create(data: RuleEntry) -> Box<dyn RuleType> {
+        let mut options = NatspecContractOptions::default();
+
+        if let Some(data) = &data.data {
+            match serde_json::from_value(data.clone()) {
+                Ok(val) => options = val,
+                Err(_) => {
+                    eprintln!("{} rule : bad config data", RULE_ID);
+                }
+            }
+        }
+        let rule = NatspecContract { options, data };
+        Box::new(rule)
+    }
+
+    pub(crate) fn create_default() -> RuleEntry {
+        RuleEntry {
+            id: RULE_ID.to_string(),
+            severity: DEFAULT_SEVERITY,
+            data: Some(serde_json::to_value(NatspecContractOptions::default()).unwrap()),
+        }
+    }
+}
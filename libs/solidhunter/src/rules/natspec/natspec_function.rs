@@ -0,0 +1,270 @@
+use crate::linter::SolidFile;
+use crate::rules::types::*;
+use crate::types::*;
+use osmium_libs_solidity_ast_extractor::natspec::*;
+use osmium_libs_solidity_ast_extractor::*;
+use serde::{Deserialize, Serialize};
+
+// global
+pub const RULE_ID: &str = "natspec-function";
+
+// specific
+const DEFAULT_SEVERITY: Severity = Severity::INFO;
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+struct NatspecFunctionOptions {
+    #[serde(default = "default_true")]
+    notice: bool,
+    #[serde(default = "default_true")]
+    params: bool,
+    #[serde(default = "default_true")]
+    returns: bool,
+}
+
+fn default_true() -> bool {
+    true
+}
+
+impl Default for NatspecFunctionOptions {
+    fn default() -> Self {
+        NatspecFunctionOptions {
+            notice: true,
+            params: true,
+            returns: true,
+        }
+    }
+}
+
+pub struct NatspecFunction {
+    options: NatspecFunctionOptions,
+    data: RuleEntry,
+}
+
+fn is_exposed(function: &ItemFunction) -> bool {
+    matches!(function.kind, FunctionKind::Function(_))
+        && matches!(
+            function.attributes.visibility(),
+            Some(Visibility::Public(_) | Visibility::External(_))
+        )
+}
+
+impl NatspecFunction {
+    fn create_diag(
+        &self,
+        file: &SolidFile,
+        location: (LineColumn, LineColumn),
+        message: String,
+    ) -> LintDiag {
+        LintDiag {
+            id: RULE_ID.to_string(),
+            range: Range {
+                start: Position {
+                    line: location.0.line,
+                    character: location.0.column,
+                },
+                end: Position {
+                    line: location.1.line,
+                    character: location.1.column,
+                },
+            },
+            same_line_ranges: None,
+            fix: None,
+            message,
+            severity: self.data.severity,
+            code: None,
+            source: None,
+            uri: file.path.clone(),
+        }
+    }
+
+    // Whether the requirements of the configuration apply to the function
+    fn requires_documentation(&self, function: &ItemFunction) -> bool {
+        self.options.notice
+            || (self.options.params && !function.parameters.is_empty())
+            || (self.options.returns && function.returns.is_some())
+    }
+
+    fn check_function(
+        &self,
+        file: &SolidFile,
+        comments: &[DocComment],
+        function: &ItemFunction,
+        res: &mut Vec<LintDiag>,
+    ) {
+        let Some(name) = &function.name else {
+            return;
+        };
+        let name_location = (name.span().start(), name.span().end());
+        let comment = retrieve_doc_comment(comments, function.kind.span().start());
+        let natspec = match comment {
+            Some(comment) => parse_natspec(comment),
+            None => {
+                if self.requires_documentation(function) {
+                    res.push(self.create_diag(
+                        file,
+                        name_location,
+                        format!("Missing NatSpec documentation for function {}", name),
+                    ));
+                }
+                return;
+            }
+        };
+        // The documentation is inherited from the overridden function
+        if natspec.has(&NatSpecKind::Inheritdoc) {
+            return;
+        }
+
+        if self.options.notice && !natspec.has(&NatSpecKind::Notice) {
+            res.push(self.create_diag(
+                file,
+                name_location,
+                format!("Missing @notice in the documentation of function {}", name),
+            ));
+        }
+
+        let params: Vec<&SolIdent> = function
+            .parameters
+            .iter()
+            .filter_map(|param| param.name.as_ref())
+            .collect();
+        if self.options.params {
+            for param in &params {
+                let documented = natspec
+                    .tags_of(&NatSpecKind::Param)
+                    .any(|tag| tag.name.as_deref() == Some(&param.to_string()));
+                if !documented {
+                    res.push(self.create_diag(
+                        file,
+                        (param.span().start(), param.span().end()),
+                        format!(
+                            "Missing @param for parameter {} of function {}",
+                            param, name
+                        ),
+                    ));
+                }
+            }
+        }
+        for tag in natspec.tags_of(&NatSpecKind::Param) {
+            let tag_name = tag.name.clone().unwrap_or_default();
+            if !params.iter().any(|param| **param == tag_name) {
+                res.push(self.create_diag(
+                    file,
+                    (tag.start, tag.end),
+                    format!(
+                        "@param {} does not match any parameter of function {}",
+                        tag_name, name
+                    ),
+                ));
+            }
+        }
+
+        let returns: Vec<&VariableDeclaration> = match &function.returns {
+            Some(returns) => returns.returns.iter().collect(),
+            None => vec![],
+        };
+        let return_tags: Vec<&NatSpecTag> = natspec.tags_of(&NatSpecKind::Return).collect();
+        if self.options.returns {
+            for ret in returns.iter().skip(return_tags.len()) {
+                let described = match &ret.name {
+                    Some(ret_name) => format!("return value {}", ret_name),
+                    None => format!("return value of type {}", ret.ty),
+                };
+                res.push(self.create_diag(
+                    file,
+                    (ret.span().start(), ret.span().end()),
+                    format!("Missing @return for {} of function {}", described, name),
+                ));
+            }
+        }
+        for tag in return_tags.iter().skip(returns.len()) {
+            res.push(self.create_diag(
+                file,
+                (tag.start, tag.end),
+                format!(
+                    "@return exceeds the number of return values of function {}",
+                    name
+                ),
+            ));
+        }
+    }
+}
+
+impl RuleType for NatspecFunction {
+    fn diagnose(&self, file: &SolidFile, _files: &[SolidFile]) -> Vec<LintDiag> {
+        let mut res = Vec::new();
+        let comments = extract_doc_comments(&file.content);
+
+        for contract in retriever::retrieve_contract_nodes(&file.data) {
+            for function in retriever::retrieve_functions_nodes(&contract) {
+                if is_exposed(&function) {
+                    self.check_function(file, &comments, &function, &mut res);
+                }
+            }
+        }
+        res
+    }
+
+    fn get_documentation(&self) -> RuleDocumentation {
+        RuleDocumentation {
+            id: RULE_ID.to_string(),
+            severity: DEFAULT_SEVERITY,
+            description: "Public and external functions must be documented with NatSpec: a @notice, a @param for each parameter and a @return for each return value. @param tags must name a parameter of the function. Documentation using @inheritdoc is not checked.".to_string(),
+            category: "natspec".to_string(),
+            example_config: "{\"id\": \"natspec-function\", \"severity\": \"INFO\", \"data\": {\"notice\": true, \"params\": true, \"returns\": true}}".to_string(),
+            source_link: "https://github.com/astrodevs-labs/osmium/blob/main/toolchains/solidity/core/crates/linter-lib/src/rules/natspec/natspec_function.rs".to_string(),
+            test_link: "https://github.com/astrodevs-labs/osmium/tree/main/toolchains/solidity/core/crates/linter-lib/testdata/NatspecFunction".to_string(),
+            options: vec![Options {
+                description: "notice requires a @notice, params requires a @param for each parameter and returns a @return for each return value".to_string(),
+                default: "{\"notice\": true, \"params\": true, \"returns\": true}".to_string(),
+            }],
+            examples: Examples {
+                good: vec![
+                    Example {
+                        description: "Documented function".to_string(),
+                        code: "/// @notice Transfers tokens\n/// @param to The receiver\n/// @return Whether the transfer succeeded\nfunction transfer(address to) external returns (bool) {}".to_string(),
+                    },
+                    Example {
+                        description: "Inherited documentation".to_string(),
+                        code: "/// @inheritdoc IERC20\nfunction transfer(address to, uint256 amount) external returns (bool) {}".to_string(),
+                    },
+                ],
+                bad: vec![
+                    Example {
+                        description: "Undocumented function".to_string(),
+                        code: "function transfer(address to) external returns (bool) {}".to_string(),
+                    },
+                    Example {
+                        description: "@param naming a parameter that does not exist".to_string(),
+                        code: "/// @notice Transfers tokens\n/// @param receiver The receiver\nfunction transfer(address to) external {}".to_string(),
+                    },
+                ],
+            },
+        }
+    }
+}
+
+impl NatspecFunction {
+    pub(crate) fn create(data: RuleEntry) -> Box<dyn RuleType> {
+        let mut options = NatspecFunctionOptions::default();
+
+        if let Some(data) = &data.data {
+            match serde_json::from_value(data.clone()) {
+                Ok(val) => options = val,
+                Err(_) => {
+                    eprintln!("{} rule : bad config data", RULE_ID);
+                }
+            }
+        }
+        let rule = NatspecFunction { options, data };
+        Box::new(rule)
+    }
+
+    pub(crate) fn create_default() -> RuleEntry {
+        RuleEntry {
+            id: RULE_ID.to_string(),
+            severity: DEFAULT_SEVERITY,
+            data: Some(serde_json::to_value(NatspecFunctionOptions::default()).unwrap()),
+        }
+    }
+}
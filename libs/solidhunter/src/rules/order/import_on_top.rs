@@ -35,6 +35,7 @@ impl ImportOnTop {
             code: None,
             source: None,
             same_line_ranges: None,
+            fix: None,
             uri: file.path.clone(),
         }
     }
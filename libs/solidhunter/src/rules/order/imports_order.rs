@@ -0,0 +1,179 @@
+use crate::linter::SolidFile;
+use crate::rules::imports::{import_path, import_range, is_relative_import};
+use crate::rules::types::*;
+use crate::types::*;
+use osmium_libs_solidity_ast_extractor::*;
+use serde::{Deserialize, Serialize};
+
+// global
+pub const RULE_ID: &str = "imports-order";
+
+// specific
+const DEFAULT_SEVERITY: Severity = Severity::WARNING;
+// Group of the imports matching no other group
+const OTHERS_GROUP: &str = "*";
+// Group of the relative imports, `./` and `../`
+const RELATIVE_GROUP: &str = ".";
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+struct ImportsOrderOptions {
+    // Prefixes of the import paths of each group, in the order the groups must appear
+    #[serde(default = "default_groups")]
+    groups: Vec<String>,
+}
+
+fn default_groups() -> Vec<String> {
+    vec![
+        "forge-std".to_string(),
+        "@openzeppelin".to_string(),
+        OTHERS_GROUP.to_string(),
+        RELATIVE_GROUP.to_string(),
+    ]
+}
+
+impl Default for ImportsOrderOptions {
+    fn default() -> Self {
+        ImportsOrderOptions {
+            groups: default_groups(),
+        }
+    }
+}
+
+pub struct ImportsOrder {
+    options: ImportsOrderOptions,
+    data: RuleEntry,
+}
+
+impl ImportsOrder {
+    // Imports matching no group are placed in the `*` group, or after every group without it
+    fn group_of(&self, path: &str) -> usize {
+        let groups = &self.options.groups;
+
+        groups
+            .iter()
+            .position(|group| match group.as_str() {
+                OTHERS_GROUP => false,
+                RELATIVE_GROUP => is_relative_import(path),
+                prefix => path.starts_with(prefix),
+            })
+            .or_else(|| groups.iter().position(|group| group == OTHERS_GROUP))
+            .unwrap_or(groups.len())
+    }
+
+    // Index of the import expected at each position of the file
+    fn expected_order(&self, imports: &[ImportDirective]) -> Vec<usize> {
+        let mut order: Vec<usize> = (0..imports.len()).collect();
+        order.sort_by_cached_key(|index| {
+            let path = import_path(&imports[*index]);
+            (self.group_of(&path), path.to_lowercase(), path)
+        });
+        order
+    }
+
+    fn create_diag(&self, file: &SolidFile, import: &ImportDirective) -> LintDiag {
+        LintDiag {
+            id: RULE_ID.to_string(),
+            range: import_range(import),
+            same_line_ranges: None,
+            fix: None,
+            message: format!(
+                "Import of \"{}\" is out of order, imports are grouped by {} and sorted by path",
+                import_path(import),
+                self.options.groups.join(", ")
+            ),
+            severity: self.data.severity,
+            code: None,
+            source: None,
+            uri: file.path.clone(),
+        }
+    }
+}
+
+impl RuleType for ImportsOrder {
+    fn diagnose(&self, file: &SolidFile, _files: &[SolidFile]) -> Vec<LintDiag> {
+        let imports = retriever::retrieve_import_directive_nodes(&file.data);
+
+        self.expected_order(&imports)
+            .into_iter()
+            .enumerate()
+            .filter(|(position, index)| position != index)
+            .map(|(position, _)| self.create_diag(file, &imports[position]))
+            .collect()
+    }
+
+    fn get_documentation(&self) -> RuleDocumentation {
+        RuleDocumentation {
+            id: RULE_ID.to_string(),
+            severity: DEFAULT_SEVERITY,
+            description: "Imports must be grouped by the prefix of their path and sorted alphabetically within each group.".to_string(),
+            category: "order".to_string(),
+            example_config: "{\"id\": \"imports-order\", \"severity\": \"WARNING\", \"data\": {\"groups\": [\"forge-std\", \"@openzeppelin\", \"*\", \".\"]}}".to_string(),
+            source_link: "https://github.com/astrodevs-labs/osmium/blob/main/toolchains/solidity/core/crates/linter-lib/src/rules/order/imports_order.rs".to_string(),
+            test_link: "https://github.com/astrodevs-labs/osmium/tree/main/toolchains/solidity/core/crates/linter-lib/testdata/ImportsOrder".to_string(),
+            options: vec![Options {
+                description: "groups lists the path prefixes of each group in order, \"*\" is the group of the paths matching no other prefix and \".\" the group of the relative paths".to_string(),
+                default: "{\"groups\": [\"forge-std\", \"@openzeppelin\", \"*\", \".\"]}".to_string(),
+            }],
+            examples: Examples {
+                good: vec![Example {
+                    description: "Grouped and sorted imports".to_string(),
+                    code: "import {Test} from \"forge-std/Test.sol\";\nimport {ERC20} from \"@openzeppelin/contracts/token/ERC20/ERC20.sol\";\nimport {A} from \"./A.sol\";\nimport {B} from \"./B.sol\";".to_string(),
+                }],
+                bad: vec![Example {
+                    description: "Relative import before a dependency".to_string(),
+                    code: "import {A} from \"./A.sol\";\nimport {ERC20} from \"@openzeppelin/contracts/token/ERC20/ERC20.sol\";".to_string(),
+                }],
+            },
+        }
+    }
+
+    // Every import out of order is moved with the same edits, the duplicated fixes are applied once
+    fn fix(&self, file: &SolidFile, _files: &[SolidFile], _diag: &LintDiag) -> Option<Fix> {
+        let imports = retriever::retrieve_import_directive_nodes(&file.data);
+        let mut edits = Vec::new();
+
+        for (position, index) in self.expected_order(&imports).into_iter().enumerate() {
+            if position == index {
+                continue;
+            }
+            let new_text = import_range(&imports[index]).text(&file.content)?;
+            edits.push(TextEdit {
+                range: import_range(&imports[position]),
+                new_text: new_text.to_string(),
+            });
+        }
+        if edits.is_empty() {
+            return None;
+        }
+        Some(Fix {
+            message: "Sort imports".to_string(),
+            edits,
+        })
+    }
+}
+
+impl ImportsOrder {
+    pub(crate) fn create(data: RuleEntry) -> Box<dyn RuleType> {
+        let mut options = ImportsOrderOptions::default();
+
+        if let Some(data) = &data.data {
+            match serde_json::from_value(data.clone()) {
+                Ok(val) => options = val,
+                Err(_) => {
+                    eprintln!("{} rule : bad config data", RULE_ID);
+                }
+            }
+        }
+        let rule = ImportsOrder { options, data };
+        Box::new(rule)
+    }
+
+    pub(crate) fn create_default() -> RuleEntry {
+        RuleEntry {
+            id: RULE_ID.to_string(),
+            severity: DEFAULT_SEVERITY,
+            data: Some(serde_json::to_value(ImportsOrderOptions::default()).unwrap()),
+        }
+    }
+}
@@ -0,0 +1,132 @@
+use crate::linter::SolidFile;
+use crate::rules::imports::visible_bases;
+use crate::rules::types::*;
+use crate::types::*;
+use osmium_libs_solidity_ast_extractor::*;
+use std::collections::{HashMap, HashSet};
+
+// global
+pub const RULE_ID: &str = "inheritance-order";
+
+// specific
+const DEFAULT_SEVERITY: Severity = Severity::ERROR;
+
+pub struct InheritanceOrder {
+    data: RuleEntry,
+}
+
+// Whether `contract` inherits from `base`, directly or not
+fn inherits_from(bases: &HashMap<String, Vec<String>>, contract: &str, base: &str) -> bool {
+    let mut visited = HashSet::new();
+    let mut stack = vec![contract];
+
+    while let Some(current) = stack.pop() {
+        if !visited.insert(current) {
+            continue;
+        }
+        if let Some(parents) = bases.get(current) {
+            for parent in parents {
+                if parent == base {
+                    return true;
+                }
+                stack.push(parent);
+            }
+        }
+    }
+    false
+}
+
+impl InheritanceOrder {
+    fn create_diag(&self, file: &SolidFile, base: &Modifier, derived: &str) -> LintDiag {
+        LintDiag {
+            id: RULE_ID.to_string(),
+            range: Range {
+                start: Position {
+                    line: base.name.span().start().line,
+                    character: base.name.span().start().column,
+                },
+                end: Position {
+                    line: base.name.span().end().line,
+                    character: base.name.span().end().column,
+                },
+            },
+            same_line_ranges: None,
+            fix: None,
+            message: format!(
+                "{} must be listed before {} which inherits from it, bases are listed from the most base-like to the most derived",
+                base.name, derived
+            ),
+            severity: self.data.severity,
+            code: None,
+            source: None,
+            uri: file.path.clone(),
+        }
+    }
+}
+
+impl RuleType for InheritanceOrder {
+    fn diagnose(&self, file: &SolidFile, files: &[SolidFile]) -> Vec<LintDiag> {
+        let mut res = Vec::new();
+        let bases = visible_bases(file, files);
+
+        for contract in retriever::retrieve_contract_nodes(&file.data) {
+            let Some(inheritance) = &contract.inheritance else {
+                continue;
+            };
+            let listed: Vec<&Modifier> = inheritance.inheritance.iter().collect();
+            for (j, base) in listed.iter().enumerate() {
+                let name = base.name.last().to_string();
+                let derived = listed[..j]
+                    .iter()
+                    .map(|other| other.name.last().to_string())
+                    .find(|other| inherits_from(&bases, other, &name));
+                if let Some(derived) = derived {
+                    res.push(self.create_diag(file, base, &derived));
+                }
+            }
+        }
+        res
+    }
+
+    fn get_documentation(&self) -> RuleDocumentation {
+        RuleDocumentation {
+            id: RULE_ID.to_string(),
+            severity: DEFAULT_SEVERITY,
+            description: "Base contracts must be listed from the most base-like to the most derived, including the bases imported from other files. Otherwise the inheritance cannot be linearized and the contract does not compile.".to_string(),
+            category: "order".to_string(),
+            example_config: "{\"id\": \"inheritance-order\", \"severity\": \"ERROR\"}".to_string(),
+            source_link: "https://github.com/astrodevs-labs/osmium/blob/main/toolchains/solidity/core/crates/linter-lib/src/rules/order/inheritance_order.rs".to_string(),
+            test_link: "https://github.com/astrodevs-labs/osmium/tree/main/toolchains/solidity/core/crates/linter-lib/testdata/InheritanceOrder".to_string(),
+            options: vec![],
+            examples: Examples {
+                good: vec![Example {
+                    description: "Most base-like contract first".to_string(),
+                    code: "contract Ownable {}\ncontract Pausable is Ownable {}\ncontract Token is Ownable, Pausable {}".to_string(),
+                }],
+                bad: vec![Example {
+                    description: "Base listed after a contract inheriting from it".to_string(),
+                    code: "contract Ownable {}\ncontract Pausable is Ownable {}\ncontract Token is Pausable, Ownable {}".to_string(),
+                }],
+            },
+        }
+    }
+
+    fn is_cross_file(&self) -> bool {
+        true
+    }
+}
+
+impl InheritanceOrder {
+    pub(crate) fn create(data: RuleEntry) -> Box<dyn RuleType> {
+        let rule = InheritanceOrder { data };
+        Box::new(rule)
+    }
+
+    pub(crate) fn create_default() -> RuleEntry {
+        RuleEntry {
+            id: RULE_ID.to_string(),
+            severity: DEFAULT_SEVERITY,
+            data: None,
+        }
+    }
+}
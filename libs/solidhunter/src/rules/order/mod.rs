@@ -3,11 +3,15 @@ use std::collections::HashMap;
 
 #[macro_use]
 pub(crate) mod import_on_top;
+pub(crate) mod imports_order;
+pub(crate) mod inheritance_order;
 pub(crate) mod ordering;
 pub(crate) mod visibility_modifier_order;
 
 // List all rules
 use crate::rules::order::import_on_top::ImportOnTop;
+use crate::rules::order::imports_order::ImportsOrder;
+use crate::rules::order::inheritance_order::InheritanceOrder;
 use crate::rules::order::ordering::Ordering;
 use crate::rules::order::visibility_modifier_order::VisibilityModiferOrder;
 use crate::rules::RuleBuilder;
@@ -17,6 +21,8 @@ pub fn create_default_rules() -> Vec<RuleEntry> {
         ImportOnTop::create_default(),
         Ordering::create_default(),
         VisibilityModiferOrder::create_default(),
+        InheritanceOrder::create_default(),
+        ImportsOrder::create_default(),
     ]
 }
 
@@ -29,6 +35,11 @@ pub fn create_rules() -> RulesMap {
         visibility_modifier_order::RULE_ID.to_string(),
         VisibilityModiferOrder::create,
     );
+    rules.insert(
+        inheritance_order::RULE_ID.to_string(),
+        InheritanceOrder::create,
+    );
+    rules.insert(imports_order::RULE_ID.to_string(), ImportsOrder::create);
 
     rules
 }
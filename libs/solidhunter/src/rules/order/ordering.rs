@@ -298,6 +298,7 @@ impl OrderingVisitor {
             code: None,
             source: None,
             same_line_ranges: None,
+            fix: None,
             uri: file.path.clone(),
         }
     }
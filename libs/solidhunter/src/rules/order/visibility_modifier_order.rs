@@ -2,6 +2,7 @@ use osmium_libs_solidity_ast_extractor::retriever::{
     retrieve_contract_nodes, retrieve_functions_nodes,
 };
 use osmium_libs_solidity_ast_extractor::Spanned;
+use proc_macro2::Span;
 
 use crate::linter::SolidFile;
 use crate::rules::types::*;
@@ -33,6 +34,7 @@ impl RuleType for VisibilityModiferOrder {
                 message: DEFAULT_MESSAGE.to_string(),
                 uri: _file.path.clone(),
                 same_line_ranges: None,
+                fix: None,
             });
         }
         res
@@ -54,6 +56,58 @@ impl RuleType for VisibilityModiferOrder {
             },
         }
     }
+
+    fn fix(&self, file: &SolidFile, _files: &[SolidFile], diag: &LintDiag) -> Option<Fix> {
+        let contracts = retrieve_contract_nodes(&file.data);
+        for contract in contracts {
+            for function in retrieve_functions_nodes(&contract) {
+                let attributes: Vec<Range> = function
+                    .attributes
+                    .iter()
+                    .map(|attribute| range_from_span(attribute.span()))
+                    .collect();
+                let idx = match attributes.iter().position(|range| *range == diag.range) {
+                    Some(idx) if idx > 0 => idx,
+                    _ => continue,
+                };
+                let visibility = diag.range.text(&file.content)?;
+
+                return Some(Fix {
+                    message: format!("Move '{}' before other modifiers", visibility),
+                    edits: vec![
+                        TextEdit {
+                            range: Range {
+                                start: attributes[0].start.clone(),
+                                end: attributes[0].start.clone(),
+                            },
+                            new_text: format!("{} ", visibility),
+                        },
+                        TextEdit {
+                            range: Range {
+                                start: attributes[idx - 1].end.clone(),
+                                end: diag.range.end.clone(),
+                            },
+                            new_text: String::new(),
+                        },
+                    ],
+                });
+            }
+        }
+        None
+    }
+}
+
+fn range_from_span(span: Span) -> Range {
+    Range {
+        start: Position {
+            line: span.start().line,
+            character: span.start().column,
+        },
+        end: Position {
+            line: span.end().line,
+            character: span.end().column,
+        },
+    }
 }
 
 fn check_visibility_modifier_order(file: &SolidFile) -> Vec<Range> {
@@ -69,16 +123,7 @@ fn check_visibility_modifier_order(file: &SolidFile) -> Vec<Range> {
                     is_attributes = true;
                 }
                 if attributes.visibility().is_some() && is_attributes {
-                    reports.push(Range {
-                        start: Position {
-                            line: attributes.span().start().line,
-                            character: attributes.span().start().column,
-                        },
-                        end: Position {
-                            line: attributes.span().end().line,
-                            character: attributes.span().end().column,
-                        },
-                    });
+                    reports.push(range_from_span(attributes.span()));
                 }
             });
         }
@@ -0,0 +1,101 @@
+use crate::rules::rule_impl::merge_rules;
+use crate::rules::types::RuleEntry;
+use crate::rules::{create_default_rules, gas, natspec, security, upgradeability};
+
+pub const PRESET_PREFIX: &str = "solidhunter:";
+pub const RECOMMENDED_PRESET: &str = "solidhunter:recommended";
+pub const ALL_PRESET: &str = "solidhunter:all";
+pub const SECURITY_PRESET: &str = "solidhunter:security";
+pub const GAS_PRESET: &str = "solidhunter:gas";
+pub const UPGRADEABILITY_PRESET: &str = "solidhunter:upgradeability";
+pub const NATSPEC_PRESET: &str = "solidhunter:natspec";
+
+pub const PRESETS: [&str; 6] = [
+    RECOMMENDED_PRESET,
+    ALL_PRESET,
+    SECURITY_PRESET,
+    GAS_PRESET,
+    UPGRADEABILITY_PRESET,
+    NATSPEC_PRESET,
+];
+
+/// Rules of the recommended preset: the historical defaults and the rules catching likely bugs.
+/// Stricter or project specific rules are enabled by the all preset or their own preset.
+pub const RECOMMENDED_RULES: [&str; 45] = [
+    // best practises
+    "max-line-length",
+    "max-states-count",
+    "function-max-lines",
+    "reason-string",
+    "no-console",
+    "one-contract-per-file",
+    "custom-errors",
+    "no-global-import",
+    "no-empty-block",
+    "explicit-types",
+    "payable-fallback",
+    "duplicate-contract-name",
+    "circular-imports",
+    "duplicate-imports",
+    // miscellaneous
+    "quotes",
+    "no-unused-vars",
+    "no-unused-import",
+    "no-unused-import-symbols",
+    // naming
+    "contract-name-camelcase",
+    "func-name-mixedcase",
+    "func-param-name-mixedcase",
+    "use-forbidden-name",
+    "event-name-camelcase",
+    "const-name-snakecase",
+    "private-vars-leading-underscore",
+    "var-name-mixedcase",
+    "modifier-name-mixedcase",
+    "named-parameters-mapping",
+    "foundry-test-functions",
+    // order
+    "import-on-top",
+    "ordering",
+    "visibility-modifier-order",
+    // security
+    "no-inline-assembly",
+    "state-visibility",
+    "not-rely-on-time",
+    "func-visibility",
+    "avoid-tx-origin",
+    "avoid-selfdestruct",
+    "avoid-suicide",
+    "check-send-result",
+    "avoid-throw",
+    "avoid-sha3",
+    "multiple-sends",
+    "reentrancy",
+    "compiler-version",
+];
+
+/// Returns the rules of the recommended preset, which also apply without configuration.
+pub fn create_recommended_rules() -> Vec<RuleEntry> {
+    create_default_rules()
+        .into_iter()
+        .filter(|rule| RECOMMENDED_RULES.contains(&rule.id.as_str()))
+        .collect()
+}
+
+/// Returns the rules of a built-in preset, or `None` if `name` is not a known preset.
+pub fn create_preset_rules(name: &str) -> Option<Vec<RuleEntry>> {
+    match name {
+        RECOMMENDED_PRESET => Some(create_recommended_rules()),
+        // The rules off by default are enabled with their own severity
+        ALL_PRESET => {
+            let mut rules = create_default_rules();
+            merge_rules(&mut rules, gas::create_default_rules());
+            Some(rules)
+        }
+        SECURITY_PRESET => Some(security::create_default_rules()),
+        GAS_PRESET => Some(gas::create_default_rules()),
+        UPGRADEABILITY_PRESET => Some(upgradeability::create_default_rules()),
+        NATSPEC_PRESET => Some(natspec::create_default_rules()),
+        _ => None,
+    }
+}
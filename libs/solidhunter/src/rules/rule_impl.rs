@@ -1,11 +1,14 @@
-use crate::errors::SolidHunterError;
-use crate::rules::create_default_rules;
+use crate::errors::{RuleConfigError, SolidHunterError};
+use crate::rules::presets::{create_preset_rules, PRESETS, PRESET_PREFIX, RECOMMENDED_PRESET};
 use crate::rules::types::*;
+use std::path::{Path, PathBuf};
 
 pub fn create_rules_file(path: &str) {
     let rules = Rules {
         name: "solidhunter".to_string(),
-        rules: create_default_rules(),
+        extends: vec![RECOMMENDED_PRESET.to_string()],
+        rules: vec![],
+        overrides: vec![],
     };
     let serialized = serde_json::to_string_pretty(&rules).unwrap();
 
@@ -21,12 +24,109 @@ pub fn parse_rules(path: &str) -> Result<Rules, SolidHunterError> {
     }
     let file = std::fs::read_to_string(path)?;
     let parsed: Rules = serde_json::from_str(&file)?;
+    let path = Path::new(path);
+    let mut visited = vec![std::fs::canonicalize(path)?];
 
-    Ok(parsed)
+    resolve_extends(
+        parsed,
+        path.parent().unwrap_or(Path::new(".")),
+        &mut visited,
+    )
 }
 
 pub fn parse_rules_content(content: &str) -> Result<Rules, SolidHunterError> {
     let parsed: Rules = serde_json::from_str(content)?;
 
-    Ok(parsed)
+    resolve_extends(parsed, Path::new("."), &mut vec![])
+}
+
+/// Merges rule entries into `rules` by rule id. An entry overriding an existing rule replaces
+/// its severity and only replaces its options if the entry has some.
+pub fn merge_rules(rules: &mut Vec<RuleEntry>, entries: Vec<RuleEntry>) {
+    for entry in entries {
+        match rules.iter_mut().find(|rule| rule.id == entry.id) {
+            Some(rule) => {
+                rule.severity = entry.severity;
+                if entry.data.is_some() {
+                    rule.data = entry.data;
+                }
+            }
+            None => rules.push(entry),
+        }
+    }
+}
+
+fn extends_error(
+    config: Option<&PathBuf>,
+    index: usize,
+    extends: &str,
+    reason: String,
+) -> SolidHunterError {
+    SolidHunterError::RulesConfigError {
+        config: config.map(|config| config.to_string_lossy().to_string()),
+        errors: vec![RuleConfigError::InvalidExtends {
+            path: format!("extends[{}]", index),
+            extends: extends.to_string(),
+            reason,
+        }],
+    }
+}
+
+// Flattens the configurations a config extends, in order, before its own rules. Relative paths
+// are resolved from the directory of the extending config and `visited` holds the chain of
+// configs being resolved to detect cycles. Overrides of extended configs are kept before the
+// ones of the extending config, their patterns always apply from the linted project root.
+fn resolve_extends(
+    rules: Rules,
+    base_dir: &Path,
+    visited: &mut Vec<PathBuf>,
+) -> Result<Rules, SolidHunterError> {
+    let config = visited.last().cloned();
+    let mut merged = Vec::new();
+    let mut overrides = Vec::new();
+
+    for (i, extends) in rules.extends.iter().enumerate() {
+        if extends.starts_with(PRESET_PREFIX) {
+            let preset = create_preset_rules(extends).ok_or_else(|| {
+                extends_error(
+                    config.as_ref(),
+                    i,
+                    extends,
+                    format!("unknown preset, expected one of {}", PRESETS.join(", ")),
+                )
+            })?;
+            merge_rules(&mut merged, preset);
+            continue;
+        }
+
+        let path = base_dir.join(extends);
+        let path = std::fs::canonicalize(&path).map_err(|_| {
+            extends_error(config.as_ref(), i, extends, "file not found".to_string())
+        })?;
+        if visited.contains(&path) {
+            return Err(extends_error(
+                config.as_ref(),
+                i,
+                extends,
+                "configuration extends itself".to_string(),
+            ));
+        }
+        let content = std::fs::read_to_string(&path)?;
+        let parsed: Rules = serde_json::from_str(&content)?;
+
+        visited.push(path.clone());
+        let resolved = resolve_extends(parsed, path.parent().unwrap_or(Path::new(".")), visited)?;
+        visited.pop();
+        merge_rules(&mut merged, resolved.rules);
+        overrides.extend(resolved.overrides);
+    }
+    merge_rules(&mut merged, rules.rules);
+    overrides.extend(rules.overrides);
+
+    Ok(Rules {
+        name: rules.name,
+        extends: vec![],
+        rules: merged,
+        overrides,
+    })
 }
@@ -0,0 +1,97 @@
+use crate::linter::SolidFile;
+use crate::rules::types::*;
+use crate::rules::utils::called_member_ident;
+use crate::types::*;
+use osmium_libs_solidity_ast_extractor::*;
+
+// global
+pub const RULE_ID: &str = "avoid-call-value";
+
+// specific
+const DEFAULT_MESSAGE: &str = "Avoid to use \".call.value()()\"";
+const DEFAULT_SEVERITY: Severity = Severity::WARNING;
+
+pub struct AvoidCallValue {
+    data: RuleEntry,
+}
+
+impl AvoidCallValue {
+    fn create_diag(&self, location: (LineColumn, LineColumn), file: &SolidFile) -> LintDiag {
+        LintDiag {
+            id: RULE_ID.to_string(),
+            range: Range {
+                start: Position {
+                    line: location.0.line,
+                    character: location.0.column,
+                },
+                end: Position {
+                    line: location.1.line,
+                    character: location.1.column,
+                },
+            },
+            same_line_ranges: None,
+            fix: None,
+            message: DEFAULT_MESSAGE.to_string(),
+            severity: self.data.severity,
+            code: None,
+            source: None,
+            uri: file.path.clone(),
+        }
+    }
+}
+
+impl RuleType for AvoidCallValue {
+    fn diagnose(&self, file: &SolidFile, _files: &[SolidFile]) -> Vec<LintDiag> {
+        let mut res = Vec::new();
+
+        // `to.call.value(amount)("")` is read as the member `value(amount)("")` of `call`
+        for member in retriever::retrieve_expr_member_nodes(&file.data) {
+            if let (Expr::Ident(call), Some(value)) = (&*member.expr, called_member_ident(&member))
+            {
+                if call == "call" && value == "value" {
+                    let location = (call.span().start(), value.span().end());
+                    res.push(self.create_diag(location, file));
+                }
+            }
+        }
+        res
+    }
+
+    fn get_documentation(&self) -> RuleDocumentation {
+        RuleDocumentation {
+            id: RULE_ID.to_string(),
+            severity: DEFAULT_SEVERITY,
+            description: "Avoid to use \".call.value()()\", it forwards all the remaining gas and was removed in solidity 0.7.".to_string(),
+            category: "security".to_string(),
+            example_config: "{\"id\": \"avoid-call-value\", \"severity\": \"WARNING\"}".to_string(),
+            source_link: "https://github.com/astrodevs-labs/osmium/blob/main/toolchains/solidity/core/crates/linter-lib/src/rules/security/avoid_call_value.rs".to_string(),
+            test_link: "https://github.com/astrodevs-labs/osmium/tree/main/toolchains/solidity/core/crates/linter-lib/testdata/AvoidCallValue".to_string(),
+            options: vec![],
+            examples: Examples {
+                good: vec![Example {
+                    description: "Ether sent with transfer".to_string(),
+                    code: "payable(to).transfer(amount);".to_string(),
+                }],
+                bad: vec![Example {
+                    description: "Ether sent with call.value".to_string(),
+                    code: "to.call.value(amount)();".to_string(),
+                }],
+            },
+        }
+    }
+}
+
+impl AvoidCallValue {
+    pub(crate) fn create(data: RuleEntry) -> Box<dyn RuleType> {
+        let rule = AvoidCallValue { data };
+        Box::new(rule)
+    }
+
+    pub(crate) fn create_default() -> RuleEntry {
+        RuleEntry {
+            id: RULE_ID.to_string(),
+            severity: DEFAULT_SEVERITY,
+            data: None,
+        }
+    }
+}
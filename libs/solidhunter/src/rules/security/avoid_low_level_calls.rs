@@ -0,0 +1,106 @@
+use crate::linter::SolidFile;
+use crate::rules::types::*;
+use crate::rules::utils::called_member_ident;
+use crate::types::*;
+use osmium_libs_solidity_ast_extractor::*;
+
+// global
+pub const RULE_ID: &str = "avoid-low-level-calls";
+
+// specific
+const DEFAULT_SEVERITY: Severity = Severity::WARNING;
+const LOW_LEVEL_CALLS: [&str; 3] = ["call", "delegatecall", "staticcall"];
+
+pub struct AvoidLowLevelCalls {
+    data: RuleEntry,
+}
+
+impl AvoidLowLevelCalls {
+    fn create_diag(
+        &self,
+        location: (LineColumn, LineColumn),
+        file: &SolidFile,
+        call: &str,
+    ) -> LintDiag {
+        LintDiag {
+            id: RULE_ID.to_string(),
+            range: Range {
+                start: Position {
+                    line: location.0.line,
+                    character: location.0.column,
+                },
+                end: Position {
+                    line: location.1.line,
+                    character: location.1.column,
+                },
+            },
+            same_line_ranges: None,
+            fix: None,
+            message: format!("Avoid to use low level call '{}'", call),
+            severity: self.data.severity,
+            code: None,
+            source: None,
+            uri: file.path.clone(),
+        }
+    }
+}
+
+impl RuleType for AvoidLowLevelCalls {
+    fn diagnose(&self, file: &SolidFile, _files: &[SolidFile]) -> Vec<LintDiag> {
+        let mut res = Vec::new();
+
+        for member in retriever::retrieve_expr_member_nodes(&file.data) {
+            if let Some(ident) = called_member_ident(&member) {
+                if LOW_LEVEL_CALLS.iter().any(|name| ident == *name) {
+                    let location = (ident.span().start(), ident.span().end());
+                    res.push(self.create_diag(location, file, &ident.to_string()));
+                }
+            }
+        }
+        res
+    }
+
+    fn get_documentation(&self) -> RuleDocumentation {
+        RuleDocumentation {
+            id: RULE_ID.to_string(),
+            severity: DEFAULT_SEVERITY,
+            description: "Avoid to use low level calls (call, delegatecall and staticcall).".to_string(),
+            category: "security".to_string(),
+            example_config: "{\"id\": \"avoid-low-level-calls\", \"severity\": \"WARNING\"}".to_string(),
+            source_link: "https://github.com/astrodevs-labs/osmium/blob/main/toolchains/solidity/core/crates/linter-lib/src/rules/security/avoid_low_level_calls.rs".to_string(),
+            test_link: "https://github.com/astrodevs-labs/osmium/tree/main/toolchains/solidity/core/crates/linter-lib/testdata/AvoidLowLevelCalls".to_string(),
+            options: vec![],
+            examples: Examples {
+                good: vec![Example {
+                    description: "Call through the contract interface".to_string(),
+                    code: "IToken(token).transfer(to, amount);".to_string(),
+                }],
+                bad: vec![
+                    Example {
+                        description: "Low level call".to_string(),
+                        code: "(bool success, ) = to.call{value: amount}(\"\");".to_string(),
+                    },
+                    Example {
+                        description: "Low level delegatecall".to_string(),
+                        code: "implementation.delegatecall(data);".to_string(),
+                    },
+                ],
+            },
+        }
+    }
+}
+
+impl AvoidLowLevelCalls {
+    pub(crate) fn create(data: RuleEntry) -> Box<dyn RuleType> {
+        let rule = AvoidLowLevelCalls { data };
+        Box::new(rule)
+    }
+
+    pub(crate) fn create_default() -> RuleEntry {
+        RuleEntry {
+            id: RULE_ID.to_string(),
+            severity: DEFAULT_SEVERITY,
+            data: None,
+        }
+    }
+}
@@ -0,0 +1,91 @@
+use crate::linter::SolidFile;
+use crate::rules::types::*;
+use crate::types::*;
+use osmium_libs_solidity_ast_extractor::*;
+
+// global
+pub const RULE_ID: &str = "avoid-selfdestruct";
+
+// specific
+const DEFAULT_MESSAGE: &str = "Avoid to use selfdestruct";
+const DEFAULT_SEVERITY: Severity = Severity::WARNING;
+
+pub struct AvoidSelfdestruct {
+    data: RuleEntry,
+}
+
+impl AvoidSelfdestruct {
+    fn create_diag(&self, location: (LineColumn, LineColumn), file: &SolidFile) -> LintDiag {
+        LintDiag {
+            id: RULE_ID.to_string(),
+            range: Range {
+                start: Position {
+                    line: location.0.line,
+                    character: location.0.column,
+                },
+                end: Position {
+                    line: location.1.line,
+                    character: location.1.column,
+                },
+            },
+            same_line_ranges: None,
+            fix: None,
+            message: DEFAULT_MESSAGE.to_string(),
+            severity: self.data.severity,
+            code: None,
+            source: None,
+            uri: file.path.clone(),
+        }
+    }
+}
+
+impl RuleType for AvoidSelfdestruct {
+    fn diagnose(&self, file: &SolidFile, _files: &[SolidFile]) -> Vec<LintDiag> {
+        let mut res = Vec::new();
+
+        for call in retriever::retrieve_expr_call_nodes(&file.data) {
+            if let Expr::Ident(ident) = &*call.expr {
+                if ident == "selfdestruct" {
+                    let location = (call.span().start(), call.span().end());
+                    res.push(self.create_diag(location, file));
+                }
+            }
+        }
+        res
+    }
+
+    fn get_documentation(&self) -> RuleDocumentation {
+        RuleDocumentation {
+            id: RULE_ID.to_string(),
+            severity: DEFAULT_SEVERITY,
+            description: "Avoid to use selfdestruct, it is deprecated and its behavior depends on the network.".to_string(),
+            category: "security".to_string(),
+            example_config: "{\"id\": \"avoid-selfdestruct\", \"severity\": \"WARNING\"}".to_string(),
+            source_link: "https://github.com/astrodevs-labs/osmium/blob/main/toolchains/solidity/core/crates/linter-lib/src/rules/security/avoid_selfdestruct.rs".to_string(),
+            test_link: "https://github.com/astrodevs-labs/osmium/tree/main/toolchains/solidity/core/crates/linter-lib/testdata/AvoidSelfdestruct".to_string(),
+            options: vec![],
+            examples: Examples {
+                good: vec![],
+                bad: vec![Example {
+                    description: "Contract destroyed with selfdestruct".to_string(),
+                    code: "selfdestruct(payable(owner));".to_string(),
+                }],
+            },
+        }
+    }
+}
+
+impl AvoidSelfdestruct {
+    pub(crate) fn create(data: RuleEntry) -> Box<dyn RuleType> {
+        let rule = AvoidSelfdestruct { data };
+        Box::new(rule)
+    }
+
+    pub(crate) fn create_default() -> RuleEntry {
+        RuleEntry {
+            id: RULE_ID.to_string(),
+            severity: DEFAULT_SEVERITY,
+            data: None,
+        }
+    }
+}
@@ -0,0 +1,94 @@
+use crate::linter::SolidFile;
+use crate::rules::types::*;
+use crate::types::*;
+use osmium_libs_solidity_ast_extractor::*;
+
+// global
+pub const RULE_ID: &str = "avoid-sha3";
+
+// specific
+const DEFAULT_MESSAGE: &str = "Use keccak256 instead of the deprecated sha3";
+const DEFAULT_SEVERITY: Severity = Severity::WARNING;
+
+pub struct AvoidSha3 {
+    data: RuleEntry,
+}
+
+impl AvoidSha3 {
+    fn create_diag(&self, location: (LineColumn, LineColumn), file: &SolidFile) -> LintDiag {
+        LintDiag {
+            id: RULE_ID.to_string(),
+            range: Range {
+                start: Position {
+                    line: location.0.line,
+                    character: location.0.column,
+                },
+                end: Position {
+                    line: location.1.line,
+                    character: location.1.column,
+                },
+            },
+            same_line_ranges: None,
+            fix: None,
+            message: DEFAULT_MESSAGE.to_string(),
+            severity: self.data.severity,
+            code: None,
+            source: None,
+            uri: file.path.clone(),
+        }
+    }
+}
+
+impl RuleType for AvoidSha3 {
+    fn diagnose(&self, file: &SolidFile, _files: &[SolidFile]) -> Vec<LintDiag> {
+        let mut res = Vec::new();
+
+        for call in retriever::retrieve_expr_call_nodes(&file.data) {
+            if let Expr::Ident(ident) = &*call.expr {
+                if ident == "sha3" {
+                    let location = (call.span().start(), call.span().end());
+                    res.push(self.create_diag(location, file));
+                }
+            }
+        }
+        res
+    }
+
+    fn get_documentation(&self) -> RuleDocumentation {
+        RuleDocumentation {
+            id: RULE_ID.to_string(),
+            severity: DEFAULT_SEVERITY,
+            description: "Use keccak256 instead of sha3, a deprecated alias removed in solidity 0.5.".to_string(),
+            category: "security".to_string(),
+            example_config: "{\"id\": \"avoid-sha3\", \"severity\": \"WARNING\"}".to_string(),
+            source_link: "https://github.com/astrodevs-labs/osmium/blob/main/toolchains/solidity/core/crates/linter-lib/src/rules/security/avoid_sha3.rs".to_string(),
+            test_link: "https://github.com/astrodevs-labs/osmium/tree/main/toolchains/solidity/core/crates/linter-lib/testdata/AvoidSha3".to_string(),
+            options: vec![],
+            examples: Examples {
+                good: vec![Example {
+                    description: "Hash computed with keccak256".to_string(),
+                    code: "bytes32 hash = keccak256(data);".to_string(),
+                }],
+                bad: vec![Example {
+                    description: "Hash computed with sha3".to_string(),
+                    code: "bytes32 hash = sha3(data);".to_string(),
+                }],
+            },
+        }
+    }
+}
+
+impl AvoidSha3 {
+    pub(crate) fn create(data: RuleEntry) -> Box<dyn RuleType> {
+        let rule = AvoidSha3 { data };
+        Box::new(rule)
+    }
+
+    pub(crate) fn create_default() -> RuleEntry {
+        RuleEntry {
+            id: RULE_ID.to_string(),
+            severity: DEFAULT_SEVERITY,
+            data: None,
+        }
+    }
+}
@@ -0,0 +1,109 @@
+use crate::linter::SolidFile;
+use crate::rules::types::*;
+use crate::types::*;
+use osmium_libs_solidity_ast_extractor::*;
+
+// global
+pub const RULE_ID: &str = "avoid-throw";
+
+// specific
+const DEFAULT_MESSAGE: &str = "\"throw\" is deprecated, use revert() instead";
+const DEFAULT_SEVERITY: Severity = Severity::WARNING;
+
+// `throw;` is not a statement known by the parser, it is read as an identifier expression
+struct ThrowVisitor {
+    throws: Vec<SolIdent>,
+}
+
+impl<'ast> Visit<'ast> for ThrowVisitor {
+    fn visit_stmt(&mut self, stmt: &'ast Stmt) {
+        if let Stmt::Expr(stmt_expr) = stmt {
+            if let Expr::Ident(ident) = &stmt_expr.expr {
+                if ident == "throw" {
+                    self.throws.push(ident.clone());
+                }
+            }
+        }
+        visit::visit_stmt(self, stmt);
+    }
+}
+
+pub struct AvoidThrow {
+    data: RuleEntry,
+}
+
+impl AvoidThrow {
+    fn create_diag(&self, location: (LineColumn, LineColumn), file: &SolidFile) -> LintDiag {
+        LintDiag {
+            id: RULE_ID.to_string(),
+            range: Range {
+                start: Position {
+                    line: location.0.line,
+                    character: location.0.column,
+                },
+                end: Position {
+                    line: location.1.line,
+                    character: location.1.column,
+                },
+            },
+            same_line_ranges: None,
+            fix: None,
+            message: DEFAULT_MESSAGE.to_string(),
+            severity: self.data.severity,
+            code: None,
+            source: None,
+            uri: file.path.clone(),
+        }
+    }
+}
+
+impl RuleType for AvoidThrow {
+    fn diagnose(&self, file: &SolidFile, _files: &[SolidFile]) -> Vec<LintDiag> {
+        let mut visitor = ThrowVisitor { throws: Vec::new() };
+        visitor.visit_file(&file.data);
+
+        visitor
+            .throws
+            .iter()
+            .map(|ident| self.create_diag((ident.span().start(), ident.span().end()), file))
+            .collect()
+    }
+
+    fn get_documentation(&self) -> RuleDocumentation {
+        RuleDocumentation {
+            id: RULE_ID.to_string(),
+            severity: DEFAULT_SEVERITY,
+            description: "\"throw\" is deprecated and was removed in solidity 0.5, use revert() instead.".to_string(),
+            category: "security".to_string(),
+            example_config: "{\"id\": \"avoid-throw\", \"severity\": \"WARNING\"}".to_string(),
+            source_link: "https://github.com/astrodevs-labs/osmium/blob/main/toolchains/solidity/core/crates/linter-lib/src/rules/security/avoid_throw.rs".to_string(),
+            test_link: "https://github.com/astrodevs-labs/osmium/tree/main/toolchains/solidity/core/crates/linter-lib/testdata/AvoidThrow".to_string(),
+            options: vec![],
+            examples: Examples {
+                good: vec![Example {
+                    description: "Execution reverted with revert".to_string(),
+                    code: "if (msg.sender != owner) { revert(); }".to_string(),
+                }],
+                bad: vec![Example {
+                    description: "Execution reverted with throw".to_string(),
+                    code: "if (msg.sender != owner) { throw; }".to_string(),
+                }],
+            },
+        }
+    }
+}
+
+impl AvoidThrow {
+    pub(crate) fn create(data: RuleEntry) -> Box<dyn RuleType> {
+        let rule = AvoidThrow { data };
+        Box::new(rule)
+    }
+
+    pub(crate) fn create_default() -> RuleEntry {
+        RuleEntry {
+            id: RULE_ID.to_string(),
+            severity: DEFAULT_SEVERITY,
+            data: None,
+        }
+    }
+}
@@ -46,6 +46,7 @@ impl AvoidTxOrigin {
                 },
             },
             same_line_ranges: None,
+            fix: None,
             message: DEFAULT_MESSAGE.to_string(),
             severity: self.data.severity,
             code: None,
@@ -0,0 +1,120 @@
+use crate::linter::SolidFile;
+use crate::rules::types::*;
+use crate::rules::utils::called_member_ident;
+use crate::types::*;
+use osmium_libs_solidity_ast_extractor::*;
+
+// global
+pub const RULE_ID: &str = "check-send-result";
+
+// specific
+const DEFAULT_MESSAGE: &str = "Check the result of \"send\" call";
+const DEFAULT_SEVERITY: Severity = Severity::WARNING;
+
+// Collects the `send` calls used as statements, their result is discarded
+struct SendVisitor {
+    sends: Vec<ExprMember>,
+}
+
+impl<'ast> Visit<'ast> for SendVisitor {
+    fn visit_stmt(&mut self, stmt: &'ast Stmt) {
+        if let Stmt::Expr(stmt_expr) = stmt {
+            // `a.b.send(1)` is read as `a.(b.(send(1)))`
+            let mut expr = &stmt_expr.expr;
+            while let Expr::Member(member) = expr {
+                if called_member_ident(member).is_some_and(|ident| ident == "send") {
+                    self.sends.push(member.clone());
+                    break;
+                }
+                expr = &member.member;
+            }
+        }
+        visit::visit_stmt(self, stmt);
+    }
+}
+
+pub struct CheckSendResult {
+    data: RuleEntry,
+}
+
+impl CheckSendResult {
+    fn create_diag(&self, location: (LineColumn, LineColumn), file: &SolidFile) -> LintDiag {
+        LintDiag {
+            id: RULE_ID.to_string(),
+            range: Range {
+                start: Position {
+                    line: location.0.line,
+                    character: location.0.column,
+                },
+                end: Position {
+                    line: location.1.line,
+                    character: location.1.column,
+                },
+            },
+            same_line_ranges: None,
+            fix: None,
+            message: DEFAULT_MESSAGE.to_string(),
+            severity: self.data.severity,
+            code: None,
+            source: None,
+            uri: file.path.clone(),
+        }
+    }
+}
+
+impl RuleType for CheckSendResult {
+    fn diagnose(&self, file: &SolidFile, _files: &[SolidFile]) -> Vec<LintDiag> {
+        let mut visitor = SendVisitor { sends: Vec::new() };
+        visitor.visit_file(&file.data);
+
+        visitor
+            .sends
+            .iter()
+            .map(|send| self.create_diag((send.span().start(), send.span().end()), file))
+            .collect()
+    }
+
+    fn get_documentation(&self) -> RuleDocumentation {
+        RuleDocumentation {
+            id: RULE_ID.to_string(),
+            severity: DEFAULT_SEVERITY,
+            description: "Check the result of \"send\" call, it returns false instead of reverting on failure.".to_string(),
+            category: "security".to_string(),
+            example_config: "{\"id\": \"check-send-result\", \"severity\": \"WARNING\"}".to_string(),
+            source_link: "https://github.com/astrodevs-labs/osmium/blob/main/toolchains/solidity/core/crates/linter-lib/src/rules/security/check_send_result.rs".to_string(),
+            test_link: "https://github.com/astrodevs-labs/osmium/tree/main/toolchains/solidity/core/crates/linter-lib/testdata/CheckSendResult".to_string(),
+            options: vec![],
+            examples: Examples {
+                good: vec![
+                    Example {
+                        description: "Result checked in a condition".to_string(),
+                        code: "if (!to.send(amount)) { revert(); }".to_string(),
+                    },
+                    Example {
+                        description: "Result stored in a variable".to_string(),
+                        code: "bool success = to.send(amount);".to_string(),
+                    },
+                ],
+                bad: vec![Example {
+                    description: "Result of send ignored".to_string(),
+                    code: "to.send(amount);".to_string(),
+                }],
+            },
+        }
+    }
+}
+
+impl CheckSendResult {
+    pub(crate) fn create(data: RuleEntry) -> Box<dyn RuleType> {
+        let rule = CheckSendResult { data };
+        Box::new(rule)
+    }
+
+    pub(crate) fn create_default() -> RuleEntry {
+        RuleEntry {
+            id: RULE_ID.to_string(),
+            severity: DEFAULT_SEVERITY,
+            data: None,
+        }
+    }
+}
@@ -0,0 +1,148 @@
+use crate::linter::SolidFile;
+use crate::rules::semver::{pragma_version_range, VersionReq};
+use crate::rules::types::*;
+use crate::types::*;
+use osmium_libs_solidity_ast_extractor::*;
+
+// global
+pub const RULE_ID: &str = "compiler-version";
+
+// specific
+const DEFAULT_REQUIREMENT: &str = "^0.8.0";
+const DEFAULT_SEVERITY: Severity = Severity::WARNING;
+
+pub struct CompilerVersion {
+    requirement: String,
+    version_req: VersionReq,
+    data: RuleEntry,
+}
+
+impl CompilerVersion {
+    fn create_diag(&self, file: &SolidFile, range: Range, message: String) -> LintDiag {
+        LintDiag {
+            id: RULE_ID.to_string(),
+            range,
+            same_line_ranges: None,
+            fix: None,
+            message,
+            severity: self.data.severity,
+            code: None,
+            source: None,
+            uri: file.path.clone(),
+        }
+    }
+}
+
+impl RuleType for CompilerVersion {
+    fn diagnose(&self, file: &SolidFile, _files: &[SolidFile]) -> Vec<LintDiag> {
+        let mut res = Vec::new();
+
+        for item in &file.data.items {
+            let pragma = match item {
+                Item::Pragma(pragma) if matches!(pragma.tokens, PragmaTokens::Version(..)) => {
+                    pragma
+                }
+                _ => continue,
+            };
+            let range = Range {
+                start: Position {
+                    line: pragma.span().start().line,
+                    character: pragma.span().start().column,
+                },
+                end: Position {
+                    line: pragma.span().end().line,
+                    character: pragma.span().end().column,
+                },
+            };
+            let version = match range.text(&file.content).and_then(pragma_version_range) {
+                Some(version) => version.to_string(),
+                None => continue,
+            };
+
+            // The lowest version allowed by the pragma is the one the file is guaranteed to support
+            match VersionReq::parse(&version).and_then(|req| req.min_version()) {
+                Some(min_version) if self.version_req.matches(&min_version) => {}
+                Some(_) => res.push(self.create_diag(
+                    file,
+                    range,
+                    format!(
+                        "Compiler version {} does not satisfy the {} requirement",
+                        version, self.requirement
+                    ),
+                )),
+                None => res.push(self.create_diag(
+                    file,
+                    range,
+                    format!(
+                        "Invalid compiler version {}, it should satisfy the {} requirement",
+                        version, self.requirement
+                    ),
+                )),
+            }
+        }
+        res
+    }
+
+    fn get_documentation(&self) -> RuleDocumentation {
+        RuleDocumentation {
+            id: RULE_ID.to_string(),
+            severity: DEFAULT_SEVERITY,
+            description: "Compiler version must satisfy a semver requirement, the lowest version allowed by the pragma is checked.".to_string(),
+            category: "security".to_string(),
+            example_config: "{\"id\": \"compiler-version\", \"severity\": \"WARNING\", \"data\": \"^0.8.20\"}".to_string(),
+            source_link: "https://github.com/astrodevs-labs/osmium/blob/main/toolchains/solidity/core/crates/linter-lib/src/rules/security/compiler_version.rs".to_string(),
+            test_link: "https://github.com/astrodevs-labs/osmium/tree/main/toolchains/solidity/core/crates/linter-lib/testdata/CompilerVersion".to_string(),
+            options: vec![Options {
+                description: "Semver requirement of the compiler version".to_string(),
+                default: DEFAULT_REQUIREMENT.to_string(),
+            }],
+            examples: Examples {
+                good: vec![Example {
+                    description: "If ^0.8.0 is required".to_string(),
+                    code: "pragma solidity ^0.8.20;".to_string(),
+                }],
+                bad: vec![
+                    Example {
+                        description: "If ^0.8.0 is required".to_string(),
+                        code: "pragma solidity ^0.7.6;".to_string(),
+                    },
+                    Example {
+                        description: "If ^0.8.0 is required, the range allows older versions".to_string(),
+                        code: "pragma solidity >=0.6.0 <0.9.0;".to_string(),
+                    },
+                ],
+            },
+        }
+    }
+}
+
+impl CompilerVersion {
+    pub(crate) fn create(data: RuleEntry) -> Box<dyn RuleType> {
+        let mut requirement = DEFAULT_REQUIREMENT.to_string();
+
+        if let Some(data) = &data.data {
+            let parsed: Result<String, serde_json::Error> = serde_json::from_value(data.clone());
+            match parsed {
+                Ok(val) if VersionReq::parse(&val).is_some() => requirement = val,
+                _ => {
+                    eprintln!("{} rule : bad config data", RULE_ID);
+                }
+            }
+        }
+        let version_req = VersionReq::parse(&requirement).unwrap();
+        let rule = CompilerVersion {
+            requirement,
+            version_req,
+            data,
+        };
+        Box::new(rule)
+    }
+
+    pub(crate) fn create_default() -> RuleEntry {
+        RuleEntry {
+            id: RULE_ID.to_string(),
+            severity: DEFAULT_SEVERITY,
+            data: Some(DEFAULT_REQUIREMENT.into()),
+        }
+    }
+}
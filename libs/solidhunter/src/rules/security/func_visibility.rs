@@ -43,6 +43,7 @@ impl FuncVisibility {
             code: None,
             source: None,
             same_line_ranges: None,
+            fix: None,
             uri: file.path.clone(),
         }
     }
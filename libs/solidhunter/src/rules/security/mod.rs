@@ -3,16 +3,38 @@ use std::collections::HashMap;
 
 #[macro_use]
 pub(crate) mod no_inline_assembly;
+pub(crate) mod avoid_call_value;
+pub(crate) mod avoid_low_level_calls;
+pub(crate) mod avoid_selfdestruct;
+pub(crate) mod avoid_sha3;
+pub(crate) mod avoid_suicide;
+pub(crate) mod avoid_throw;
 pub(crate) mod avoid_tx_origin;
+pub(crate) mod check_send_result;
+pub(crate) mod compiler_version;
 pub(crate) mod func_visibility;
+pub(crate) mod multiple_sends;
+pub(crate) mod no_floating_pragma;
 pub(crate) mod not_rely_on_time;
+pub(crate) mod reentrancy;
 pub(crate) mod state_visibility;
 
 // List all rules
+use crate::rules::security::avoid_call_value::AvoidCallValue;
+use crate::rules::security::avoid_low_level_calls::AvoidLowLevelCalls;
+use crate::rules::security::avoid_selfdestruct::AvoidSelfdestruct;
+use crate::rules::security::avoid_sha3::AvoidSha3;
+use crate::rules::security::avoid_suicide::AvoidSuicide;
+use crate::rules::security::avoid_throw::AvoidThrow;
 use crate::rules::security::avoid_tx_origin::AvoidTxOrigin;
+use crate::rules::security::check_send_result::CheckSendResult;
+use crate::rules::security::compiler_version::CompilerVersion;
 use crate::rules::security::func_visibility::FuncVisibility;
+use crate::rules::security::multiple_sends::MultipleSends;
+use crate::rules::security::no_floating_pragma::NoFloatingPragma;
 use crate::rules::security::no_inline_assembly::NoInlineAssembly;
 use crate::rules::security::not_rely_on_time::NotRelyOnTime;
+use crate::rules::security::reentrancy::Reentrancy;
 use crate::rules::security::state_visibility::StateVisibility;
 
 use crate::rules::RuleBuilder;
@@ -24,6 +46,17 @@ pub fn create_default_rules() -> Vec<RuleEntry> {
         NotRelyOnTime::create_default(),
         FuncVisibility::create_default(),
         AvoidTxOrigin::create_default(),
+        AvoidLowLevelCalls::create_default(),
+        AvoidSelfdestruct::create_default(),
+        AvoidSuicide::create_default(),
+        CheckSendResult::create_default(),
+        AvoidCallValue::create_default(),
+        AvoidThrow::create_default(),
+        AvoidSha3::create_default(),
+        MultipleSends::create_default(),
+        Reentrancy::create_default(),
+        CompilerVersion::create_default(),
+        NoFloatingPragma::create_default(),
     ]
 }
 
@@ -41,6 +74,35 @@ pub fn create_rules() -> RulesMap {
     );
     rules.insert(avoid_tx_origin::RULE_ID.to_string(), AvoidTxOrigin::create);
     rules.insert(not_rely_on_time::RULE_ID.to_string(), NotRelyOnTime::create);
+    rules.insert(
+        avoid_low_level_calls::RULE_ID.to_string(),
+        AvoidLowLevelCalls::create,
+    );
+    rules.insert(
+        avoid_selfdestruct::RULE_ID.to_string(),
+        AvoidSelfdestruct::create,
+    );
+    rules.insert(avoid_suicide::RULE_ID.to_string(), AvoidSuicide::create);
+    rules.insert(
+        check_send_result::RULE_ID.to_string(),
+        CheckSendResult::create,
+    );
+    rules.insert(
+        avoid_call_value::RULE_ID.to_string(),
+        AvoidCallValue::create,
+    );
+    rules.insert(avoid_throw::RULE_ID.to_string(), AvoidThrow::create);
+    rules.insert(avoid_sha3::RULE_ID.to_string(), AvoidSha3::create);
+    rules.insert(multiple_sends::RULE_ID.to_string(), MultipleSends::create);
+    rules.insert(reentrancy::RULE_ID.to_string(), Reentrancy::create);
+    rules.insert(
+        compiler_version::RULE_ID.to_string(),
+        CompilerVersion::create,
+    );
+    rules.insert(
+        no_floating_pragma::RULE_ID.to_string(),
+        NoFloatingPragma::create,
+    );
 
     rules
 }
@@ -0,0 +1,108 @@
+use crate::linter::SolidFile;
+use crate::rules::types::*;
+use crate::rules::utils::called_member_ident;
+use crate::types::*;
+use osmium_libs_solidity_ast_extractor::*;
+
+// global
+pub const RULE_ID: &str = "multiple-sends";
+
+// specific
+const DEFAULT_MESSAGE: &str = "Avoid multiple calls of \"send\" method in single transaction";
+const DEFAULT_SEVERITY: Severity = Severity::WARNING;
+
+struct SendVisitor {
+    sends: Vec<ExprMember>,
+}
+
+impl<'ast> Visit<'ast> for SendVisitor {
+    fn visit_expr_member(&mut self, member: &'ast ExprMember) {
+        if called_member_ident(member).is_some_and(|ident| ident == "send") {
+            self.sends.push(member.clone());
+        }
+        visit::visit_expr_member(self, member);
+    }
+}
+
+pub struct MultipleSends {
+    data: RuleEntry,
+}
+
+impl MultipleSends {
+    fn create_diag(&self, location: (LineColumn, LineColumn), file: &SolidFile) -> LintDiag {
+        LintDiag {
+            id: RULE_ID.to_string(),
+            range: Range {
+                start: Position {
+                    line: location.0.line,
+                    character: location.0.column,
+                },
+                end: Position {
+                    line: location.1.line,
+                    character: location.1.column,
+                },
+            },
+            same_line_ranges: None,
+            fix: None,
+            message: DEFAULT_MESSAGE.to_string(),
+            severity: self.data.severity,
+            code: None,
+            source: None,
+            uri: file.path.clone(),
+        }
+    }
+}
+
+impl RuleType for MultipleSends {
+    fn diagnose(&self, file: &SolidFile, _files: &[SolidFile]) -> Vec<LintDiag> {
+        let mut res = Vec::new();
+
+        for contract in retriever::retrieve_contract_nodes(&file.data) {
+            for function in retriever::retrieve_functions_nodes(&contract) {
+                let mut visitor = SendVisitor { sends: Vec::new() };
+                visitor.visit_item_function(&function);
+
+                // The first send is fine, a failure of the following ones could block it
+                for send in visitor.sends.iter().skip(1) {
+                    res.push(self.create_diag((send.span().start(), send.span().end()), file));
+                }
+            }
+        }
+        res
+    }
+
+    fn get_documentation(&self) -> RuleDocumentation {
+        RuleDocumentation {
+            id: RULE_ID.to_string(),
+            severity: DEFAULT_SEVERITY,
+            description: "Avoid multiple calls of \"send\" method in single transaction, prefer letting each recipient withdraw its funds.".to_string(),
+            category: "security".to_string(),
+            example_config: "{\"id\": \"multiple-sends\", \"severity\": \"WARNING\"}".to_string(),
+            source_link: "https://github.com/astrodevs-labs/osmium/blob/main/toolchains/solidity/core/crates/linter-lib/src/rules/security/multiple_sends.rs".to_string(),
+            test_link: "https://github.com/astrodevs-labs/osmium/tree/main/toolchains/solidity/core/crates/linter-lib/testdata/MultipleSends".to_string(),
+            options: vec![],
+            examples: Examples {
+                good: vec![],
+                bad: vec![Example {
+                    description: "Two sends in the same function".to_string(),
+                    code: "function pay() public {\n    require(alice.send(1));\n    require(bob.send(1));\n}".to_string(),
+                }],
+            },
+        }
+    }
+}
+
+impl MultipleSends {
+    pub(crate) fn create(data: RuleEntry) -> Box<dyn RuleType> {
+        let rule = MultipleSends { data };
+        Box::new(rule)
+    }
+
+    pub(crate) fn create_default() -> RuleEntry {
+        RuleEntry {
+            id: RULE_ID.to_string(),
+            severity: DEFAULT_SEVERITY,
+            data: None,
+        }
+    }
+}
@@ -0,0 +1,181 @@
+use crate::linter::SolidFile;
+use crate::rules::semver::{pragma_version_range, VersionReq};
+use crate::rules::types::*;
+use crate::types::*;
+use osmium_libs_solidity_ast_extractor::*;
+use serde::{Deserialize, Serialize};
+use std::path::{Component, Path};
+
+// global
+pub const RULE_ID: &str = "no-floating-pragma";
+
+// specific
+const DEFAULT_SEVERITY: Severity = Severity::WARNING;
+const LIB_DIRECTORY: &str = "lib";
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+struct NoFloatingPragmaOptions {
+    // Dependencies installed in a `lib/` directory are not ours to lock
+    #[serde(default = "default_true")]
+    allow_in_lib: bool,
+    // Interfaces are meant to be imported by code compiled with other versions
+    #[serde(default = "default_true")]
+    allow_in_interfaces: bool,
+}
+
+fn default_true() -> bool {
+    true
+}
+
+impl Default for NoFloatingPragmaOptions {
+    fn default() -> Self {
+        NoFloatingPragmaOptions {
+            allow_in_lib: true,
+            allow_in_interfaces: true,
+        }
+    }
+}
+
+pub struct NoFloatingPragma {
+    options: NoFloatingPragmaOptions,
+    data: RuleEntry,
+}
+
+impl NoFloatingPragma {
+    fn create_diag(&self, file: &SolidFile, range: Range, message: String) -> LintDiag {
+        LintDiag {
+            id: RULE_ID.to_string(),
+            range,
+            same_line_ranges: None,
+            fix: None,
+            message,
+            severity: self.data.severity,
+            code: None,
+            source: None,
+            uri: file.path.clone(),
+        }
+    }
+
+    fn is_allowed(&self, file: &SolidFile) -> bool {
+        if self.options.allow_in_lib
+            && Path::new(&file.path)
+                .components()
+                .any(|component| component == Component::Normal(LIB_DIRECTORY.as_ref()))
+        {
+            return true;
+        }
+        if self.options.allow_in_interfaces {
+            let contracts = retriever::retrieve_contract_nodes(&file.data);
+            return !contracts.is_empty()
+                && contracts.iter().all(|contract| contract.is_interface());
+        }
+        false
+    }
+}
+
+impl RuleType for NoFloatingPragma {
+    fn diagnose(&self, file: &SolidFile, _files: &[SolidFile]) -> Vec<LintDiag> {
+        let mut res = Vec::new();
+
+        if self.is_allowed(file) {
+            return res;
+        }
+        for item in &file.data.items {
+            let pragma = match item {
+                Item::Pragma(pragma) if matches!(pragma.tokens, PragmaTokens::Version(..)) => {
+                    pragma
+                }
+                _ => continue,
+            };
+            let range = Range {
+                start: Position {
+                    line: pragma.span().start().line,
+                    character: pragma.span().start().column,
+                },
+                end: Position {
+                    line: pragma.span().end().line,
+                    character: pragma.span().end().column,
+                },
+            };
+            let version = match range.text(&file.content).and_then(pragma_version_range) {
+                Some(version) => version.to_string(),
+                None => continue,
+            };
+            let req = match VersionReq::parse(&version) {
+                Some(req) if req.is_floating() => req,
+                _ => continue,
+            };
+
+            let message = match req.min_version() {
+                Some(min_version) => format!(
+                    "Floating pragma {} is not allowed, lock the compiler version like {}",
+                    version, min_version
+                ),
+                None => format!(
+                    "Floating pragma {} is not allowed, lock the compiler version",
+                    version
+                ),
+            };
+            res.push(self.create_diag(file, range, message));
+        }
+        res
+    }
+
+    fn get_documentation(&self) -> RuleDocumentation {
+        RuleDocumentation {
+            id: RULE_ID.to_string(),
+            severity: DEFAULT_SEVERITY,
+            description: "Pragma must lock the compiler version instead of allowing a range like ^0.8.0 or >=0.8.0, so that the contracts are deployed with the compiler they were tested with.".to_string(),
+            category: "security".to_string(),
+            example_config: "{\"id\": \"no-floating-pragma\", \"severity\": \"WARNING\", \"data\": {\"allowInLib\": true, \"allowInInterfaces\": false}}".to_string(),
+            source_link: "https://github.com/astrodevs-labs/osmium/blob/main/toolchains/solidity/core/crates/linter-lib/src/rules/security/no_floating_pragma.rs".to_string(),
+            test_link: "https://github.com/astrodevs-labs/osmium/tree/main/toolchains/solidity/core/crates/linter-lib/testdata/NoFloatingPragma".to_string(),
+            options: vec![Options {
+                description: "allowInLib allows floating pragmas in files of a lib directory, allowInInterfaces allows them in files only declaring interfaces".to_string(),
+                default: "{\"allowInLib\": true, \"allowInInterfaces\": true}".to_string(),
+            }],
+            examples: Examples {
+                good: vec![Example {
+                    description: "Locked compiler version".to_string(),
+                    code: "pragma solidity 0.8.20;".to_string(),
+                }],
+                bad: vec![
+                    Example {
+                        description: "Caret range".to_string(),
+                        code: "pragma solidity ^0.8.20;".to_string(),
+                    },
+                    Example {
+                        description: "Open range".to_string(),
+                        code: "pragma solidity >=0.8.0;".to_string(),
+                    },
+                ],
+            },
+        }
+    }
+}
+
+impl NoFloatingPragma {
+    pub(crate) fn create(data: RuleEntry) -> Box<dyn RuleType> {
+        let mut options = NoFloatingPragmaOptions::default();
+
+        if let Some(data) = &data.data {
+            match serde_json::from_value(data.clone()) {
+                Ok(val) => options = val,
+                Err(_) => {
+                    eprintln!("{} rule : bad config data", RULE_ID);
+                }
+            }
+        }
+        let rule = NoFloatingPragma { options, data };
+        Box::new(rule)
+    }
+
+    pub(crate) fn create_default() -> RuleEntry {
+        RuleEntry {
+            id: RULE_ID.to_string(),
+            severity: DEFAULT_SEVERITY,
+            data: Some(serde_json::to_value(NoFloatingPragmaOptions::default()).unwrap()),
+        }
+    }
+}
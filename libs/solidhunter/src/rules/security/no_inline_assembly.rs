@@ -33,6 +33,7 @@ impl NoInlineAssembly {
             code: None,
             source: None,
             same_line_ranges: None,
+            fix: None,
             uri: file.path.clone(),
         }
     }
@@ -33,6 +33,7 @@ impl NotRelyOnTime {
             code: None,
             source: None,
             same_line_ranges: None,
+            fix: None,
             uri: file.path.clone(),
         }
     }
@@ -41,9 +42,8 @@ impl NotRelyOnTime {
 impl RuleType for NotRelyOnTime {
     fn diagnose(&self, file: &SolidFile, _files: &[SolidFile]) -> Vec<LintDiag> {
         let mut res = Vec::new();
-        let mut i = 1;
 
-        for line in file.content.lines() {
+        for (i, line) in (1..).zip(file.content.lines()) {
             if let Some(index) = line.find("now") {
                 res.push(self.create_diag(
                     (
@@ -74,7 +74,6 @@ impl RuleType for NotRelyOnTime {
                     file,
                 ));
             }
-            i += 1;
         }
         res
     }
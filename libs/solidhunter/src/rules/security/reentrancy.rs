@@ -0,0 +1,474 @@
+use crate::linter::SolidFile;
+use crate::rules::types::*;
+use crate::rules::utils::{callee_ident, is_assign, is_delete, root_ident};
+use crate::types::*;
+use osmium_libs_solidity_ast_extractor::*;
+use std::collections::{HashMap, HashSet};
+
+// global
+pub const RULE_ID: &str = "reentrancy";
+
+// specific
+const DEFAULT_SEVERITY: Severity = Severity::WARNING;
+const DEFAULT_GUARDS: [&str; 1] = ["nonReentrant"];
+// Members always calling another contract, whatever the type they are called on
+const EXTERNAL_CALLS: [&str; 5] = ["call", "delegatecall", "staticcall", "send", "transfer"];
+// Members modifying the storage array they are called on
+const ARRAY_WRITES: [&str; 2] = ["push", "pop"];
+
+// Variables visible in a function, the parser has no type information so calls are classified
+// from the declared type of the variable they are made on
+struct FunctionScope<'a> {
+    state_types: &'a HashMap<String, Type>,
+    writable: &'a HashSet<String>,
+    // Structs, enums and user defined value types of the file, they are not contracts
+    internal_types: &'a HashSet<String>,
+    locals: HashMap<String, Type>,
+}
+
+impl FunctionScope<'_> {
+    fn type_of(&self, name: &str) -> Option<&Type> {
+        self.locals.get(name).or_else(|| self.state_types.get(name))
+    }
+
+    fn is_state_variable(&self, name: &str) -> bool {
+        !self.locals.contains_key(name) && self.writable.contains(name)
+    }
+
+    fn is_contract_type(&self, ty: &Type) -> bool {
+        match ty {
+            // Library-qualified types like `EnumerableSet.AddressSet` are structs
+            Type::Custom(path) => {
+                path.len() == 1 && !self.internal_types.contains(&path.first().to_string())
+            }
+            _ => false,
+        }
+    }
+
+    // Whether calling `name` on `target` interacts with another contract
+    fn is_external_call(&self, target: &Expr, name: &SolIdent) -> bool {
+        if EXTERNAL_CALLS.iter().any(|call| name == *call) {
+            return true;
+        }
+        if ARRAY_WRITES.iter().any(|call| name == *call) {
+            return false;
+        }
+        match target {
+            Expr::Ident(ident) if ident == "this" => true,
+            Expr::Ident(ident) => self
+                .type_of(&ident.to_string())
+                .is_some_and(|ty| self.is_contract_type(ty)),
+            // Interface casts like `IERC20(token).transfer(to, amount)`
+            Expr::Call(call) => callee_ident(&call.expr).is_some_and(|ident| {
+                let name = ident.to_string();
+                name.starts_with(char::is_uppercase) && !self.internal_types.contains(&name)
+            }),
+            _ => false,
+        }
+    }
+}
+
+// Collects the external calls and state variable writes of an expression
+struct EffectsVisitor<'a, 'b> {
+    scope: &'a FunctionScope<'b>,
+    calls: bool,
+    writes: Vec<SolIdent>,
+}
+
+impl EffectsVisitor<'_, '_> {
+    fn write(&mut self, target: &Expr) {
+        if let Expr::Tuple(tuple) = target {
+            for elem in tuple.elems.iter() {
+                self.write(elem);
+            }
+        } else if let Some(ident) = root_ident(target) {
+            if self.scope.is_state_variable(&ident.to_string()) {
+                self.writes.push(ident.clone());
+            }
+        }
+    }
+
+    // The parser reads `a.b = c` as the member `b = c` of `a` and `a.b(c)` as the member
+    // `b(c)` of `a`, so the accessed expression is carried along the member chain
+    fn member_access(&mut self, target: &Expr, member: &Expr) {
+        match member {
+            Expr::Ident(_) => {}
+            Expr::Call(call) => {
+                visit::visit_expr_call(self, call);
+                if let Some(name) = callee_ident(&call.expr) {
+                    if self.scope.is_external_call(target, name) {
+                        self.calls = true;
+                    }
+                    if ARRAY_WRITES.iter().any(|write| name == *write) {
+                        self.write(target);
+                    }
+                }
+            }
+            Expr::Member(inner) => {
+                self.member_access(target, &inner.expr);
+                self.member_access(target, &inner.member);
+            }
+            Expr::Binary(binary) if is_assign(&binary.op) => {
+                self.visit_expr(&binary.right);
+                self.write(target);
+            }
+            Expr::Binary(binary) => {
+                self.member_access(target, &binary.left);
+                self.visit_expr(&binary.right);
+            }
+            Expr::Postfix(postfix) => {
+                self.member_access(target, &postfix.expr);
+                self.write(target);
+            }
+            Expr::Index(index) => {
+                self.member_access(target, &index.expr);
+                visit::visit_expr_index(self, index);
+            }
+            _ => self.visit_expr(member),
+        }
+    }
+}
+
+impl<'ast> Visit<'ast> for EffectsVisitor<'_, '_> {
+    fn visit_expr_binary(&mut self, binary: &'ast ExprBinary) {
+        if is_assign(&binary.op) {
+            self.visit_expr(&binary.right);
+            self.visit_expr(&binary.left);
+            self.write(&binary.left);
+        } else {
+            visit::visit_expr_binary(self, binary);
+        }
+    }
+
+    fn visit_expr_postfix(&mut self, postfix: &'ast ExprPostfix) {
+        visit::visit_expr_postfix(self, postfix);
+        self.write(&postfix.expr);
+    }
+
+    fn visit_expr_unary(&mut self, unary: &'ast ExprUnary) {
+        visit::visit_expr_unary(self, unary);
+        if matches!(unary.op, UnOp::Increment(..) | UnOp::Decrement(..)) {
+            self.write(&unary.expr);
+        }
+    }
+
+    fn visit_expr_member(&mut self, member: &'ast ExprMember) {
+        self.visit_expr(&member.expr);
+        self.member_access(&member.expr, &member.member);
+    }
+}
+
+struct VarDeclVisitor {
+    locals: HashMap<String, Type>,
+}
+
+impl<'ast> Visit<'ast> for VarDeclVisitor {
+    fn visit_variable_declaration(&mut self, var: &'ast VariableDeclaration) {
+        if let (Some(name), false) = (&var.name, is_delete(&var.ty)) {
+            self.locals.insert(name.to_string(), var.ty.clone());
+        }
+    }
+}
+
+// Walks the statements of a function in execution order, tracking whether an external call
+// may have happened before each of them
+struct InteractionsWalker<'a, 'b> {
+    scope: &'a FunctionScope<'b>,
+    writes: Vec<SolIdent>,
+}
+
+impl InteractionsWalker<'_, '_> {
+    // Writes made by the statement doing the call, like `x = token.balanceOf(this)`, are
+    // checked against the state before it
+    fn check(&mut self, expr: &Expr, called: bool) -> bool {
+        let mut visitor = EffectsVisitor {
+            scope: self.scope,
+            calls: false,
+            writes: vec![],
+        };
+        visitor.visit_expr(expr);
+        if called {
+            self.report(visitor.writes);
+        }
+        called || visitor.calls
+    }
+
+    fn report(&mut self, writes: Vec<SolIdent>) {
+        for write in writes {
+            let location = (write.span().start().line, write.span().start().column);
+            if !self.writes.iter().any(|reported| {
+                (reported.span().start().line, reported.span().start().column) == location
+            }) {
+                self.writes.push(write);
+            }
+        }
+    }
+
+    fn walk_block(&mut self, block: &Block, mut called: bool) -> bool {
+        for stmt in &block.stmts {
+            called = self.walk(stmt, called);
+        }
+        called
+    }
+
+    fn walk_var_decl(&mut self, var_decl: &StmtVarDecl, called: bool) -> bool {
+        if let VarDeclDecl::VarDecl(VariableDeclaration {
+            ty,
+            name: Some(name),
+            ..
+        }) = &var_decl.declaration
+        {
+            if is_delete(ty) {
+                if called && self.scope.is_state_variable(&name.to_string()) {
+                    self.report(vec![name.clone()]);
+                }
+                return called;
+            }
+        }
+        match &var_decl.assignment {
+            Some((_, expr)) => self.check(expr, called),
+            None => called,
+        }
+    }
+
+    // A call at the end of a loop body happens before the writes at its start on the next
+    // iteration, so bodies doing a call are walked a second time
+    fn walk_loop(&mut self, body: &Stmt, post: Option<&Expr>, called: bool) -> bool {
+        let mut after = self.walk(body, called);
+        if let Some(post) = post {
+            after = self.check(post, after);
+        }
+        if after && !called {
+            self.walk(body, true);
+        }
+        after
+    }
+
+    fn walk(&mut self, stmt: &Stmt, called: bool) -> bool {
+        match stmt {
+            Stmt::Block(block) => self.walk_block(block, called),
+            Stmt::UncheckedBlock(unchecked) => self.walk_block(&unchecked.block, called),
+            Stmt::Expr(stmt_expr) => self.check(&stmt_expr.expr, called),
+            Stmt::VarDecl(var_decl) => self.walk_var_decl(var_decl, called),
+            Stmt::If(stmt_if) => {
+                let called = self.check(&stmt_if.cond, called);
+                let then_called = self.walk(&stmt_if.then_branch, called);
+                let else_called = match &stmt_if.else_branch {
+                    Some((_, else_branch)) => self.walk(else_branch, called),
+                    None => called,
+                };
+                then_called || else_called
+            }
+            Stmt::While(stmt_while) => {
+                let called = self.check(&stmt_while.cond, called);
+                self.walk_loop(&stmt_while.body, Some(&stmt_while.cond), called)
+            }
+            Stmt::DoWhile(stmt_do_while) => {
+                self.walk_loop(&stmt_do_while.body, Some(&stmt_do_while.cond), called)
+            }
+            Stmt::For(stmt_for) => {
+                let mut called = match &stmt_for.init {
+                    ForInitStmt::VarDecl(var_decl) => self.walk_var_decl(var_decl, called),
+                    ForInitStmt::Expr(stmt_expr) => self.check(&stmt_expr.expr, called),
+                    ForInitStmt::Empty(_) => called,
+                };
+                if let Some(cond) = &stmt_for.cond {
+                    called = self.check(cond, called);
+                }
+                self.walk_loop(&stmt_for.body, stmt_for.post.as_deref(), called)
+            }
+            Stmt::Try(stmt_try) => {
+                // The expression of a try statement is always an external call
+                self.check(&stmt_try.expr, called);
+                self.walk_block(&stmt_try.block, true);
+                for catch in &stmt_try.catch {
+                    self.walk_block(&catch.block, true);
+                }
+                true
+            }
+            Stmt::Return(stmt_return) => match &stmt_return.expr {
+                Some(expr) => self.check(expr, called),
+                None => called,
+            },
+            Stmt::Emit(emit) => self.check(&emit.expr, called),
+            _ => called,
+        }
+    }
+}
+
+pub struct Reentrancy {
+    guards: Vec<String>,
+    data: RuleEntry,
+}
+
+impl Reentrancy {
+    fn create_diag(&self, file: &SolidFile, variable: &SolIdent) -> LintDiag {
+        LintDiag {
+            id: RULE_ID.to_string(),
+            range: Range {
+                start: Position {
+                    line: variable.span().start().line,
+                    character: variable.span().start().column,
+                },
+                end: Position {
+                    line: variable.span().end().line,
+                    character: variable.span().end().column,
+                },
+            },
+            same_line_ranges: None,
+            fix: None,
+            message: format!(
+                "State variable '{}' is written after an external call, update it before the call or use a reentrancy guard",
+                variable
+            ),
+            severity: self.data.severity,
+            code: None,
+            source: None,
+            uri: file.path.clone(),
+        }
+    }
+
+    fn is_guarded(&self, function: &ItemFunction) -> bool {
+        function.attributes.iter().any(|attribute| match attribute {
+            FunctionAttribute::Modifier(modifier) => self
+                .guards
+                .iter()
+                .any(|guard| modifier.name.last() == guard.as_str()),
+            _ => false,
+        })
+    }
+}
+
+impl RuleType for Reentrancy {
+    fn diagnose(&self, file: &SolidFile, _files: &[SolidFile]) -> Vec<LintDiag> {
+        let mut res = Vec::new();
+        let mut internal_types = HashSet::new();
+
+        for item in retriever::retrieve_structs_file_nodes(&file.data) {
+            internal_types.insert(item.name.to_string());
+        }
+        for item in retriever::retrieve_enums_file_nodes(&file.data) {
+            internal_types.insert(item.name.to_string());
+        }
+        for item in retriever::retrieve_udts_nodes(&file.data) {
+            internal_types.insert(item.name.to_string());
+        }
+
+        for contract in retriever::retrieve_contract_nodes(&file.data) {
+            if contract.is_interface() || contract.is_library() {
+                continue;
+            }
+            let mut state_types = HashMap::new();
+            let mut writable = HashSet::new();
+            for item in &contract.body {
+                if let Item::Variable(var) = item {
+                    state_types.insert(var.name.to_string(), var.ty.clone());
+                    if !var.attributes.has_constant() && !var.attributes.has_immutable() {
+                        writable.insert(var.name.to_string());
+                    }
+                }
+            }
+
+            for function in retriever::retrieve_functions_nodes(&contract) {
+                let block = match &function.body {
+                    FunctionBody::Block(block) => block,
+                    FunctionBody::Empty(_) => continue,
+                };
+                if matches!(
+                    function.kind,
+                    FunctionKind::Constructor(_) | FunctionKind::Modifier(_)
+                ) || self.is_guarded(&function)
+                {
+                    continue;
+                }
+
+                let mut locals = VarDeclVisitor {
+                    locals: HashMap::new(),
+                };
+                locals.visit_parameter_list(&function.parameters);
+                if let Some(returns) = &function.returns {
+                    locals.visit_parameter_list(&returns.returns);
+                }
+                locals.visit_block(block);
+
+                let scope = FunctionScope {
+                    state_types: &state_types,
+                    writable: &writable,
+                    internal_types: &internal_types,
+                    locals: locals.locals,
+                };
+                let mut walker = InteractionsWalker {
+                    scope: &scope,
+                    writes: vec![],
+                };
+                walker.walk_block(block, false);
+                for write in &walker.writes {
+                    res.push(self.create_diag(file, write));
+                }
+            }
+        }
+        res
+    }
+
+    fn get_documentation(&self) -> RuleDocumentation {
+        RuleDocumentation {
+            id: RULE_ID.to_string(),
+            severity: DEFAULT_SEVERITY,
+            description: "State variables must not be written after an external call (checks-effects-interactions pattern), unless the function is protected by a reentrancy guard modifier. External calls are detected from low level calls, transfer and send, and calls made on contract typed variables or interface casts.".to_string(),
+            category: "security".to_string(),
+            example_config: "{\"id\": \"reentrancy\", \"severity\": \"WARNING\", \"data\": [\"nonReentrant\"]}".to_string(),
+            source_link: "https://github.com/astrodevs-labs/osmium/blob/main/toolchains/solidity/core/crates/linter-lib/src/rules/security/reentrancy.rs".to_string(),
+            test_link: "https://github.com/astrodevs-labs/osmium/tree/main/toolchains/solidity/core/crates/linter-lib/testdata/Reentrancy".to_string(),
+            options: vec![Options {
+                description: "Array of the modifiers protecting a function against reentrancy".to_string(),
+                default: "[\"nonReentrant\"]".to_string(),
+            }],
+            examples: Examples {
+                good: vec![
+                    Example {
+                        description: "State updated before the external call".to_string(),
+                        code: "function withdraw() public {\n    uint256 amount = balances[msg.sender];\n    balances[msg.sender] = 0;\n    (bool success, ) = msg.sender.call{value: amount}(\"\");\n    require(success);\n}".to_string(),
+                    },
+                    Example {
+                        description: "Function protected by a reentrancy guard".to_string(),
+                        code: "function withdraw() public nonReentrant {\n    (bool success, ) = msg.sender.call{value: balances[msg.sender]}(\"\");\n    require(success);\n    balances[msg.sender] = 0;\n}".to_string(),
+                    },
+                ],
+                bad: vec![Example {
+                    description: "State updated after the external call".to_string(),
+                    code: "function withdraw() public {\n    (bool success, ) = msg.sender.call{value: balances[msg.sender]}(\"\");\n    require(success);\n    balances[msg.sender] = 0;\n}".to_string(),
+                }],
+            },
+        }
+    }
+}
+
+impl Reentrancy {
+    pub(crate) fn create(data: RuleEntry) -> Box<dyn RuleType> {
+        let mut guards: Vec<String> = DEFAULT_GUARDS
+            .iter()
+            .map(|guard| guard.to_string())
+            .collect();
+
+        if let Some(data) = &data.data {
+            let parsed: Result<Vec<String>, serde_json::Error> =
+                serde_json::from_value(data.clone());
+            match parsed {
+                Ok(val) => guards = val,
+                Err(_) => {
+                    eprintln!("{} rule : bad config data", RULE_ID);
+                }
+            }
+        }
+        let rule = Reentrancy { guards, data };
+        Box::new(rule)
+    }
+
+    pub(crate) fn create_default() -> RuleEntry {
+        RuleEntry {
+            id: RULE_ID.to_string(),
+            severity: DEFAULT_SEVERITY,
+            data: Some(DEFAULT_GUARDS.to_vec().into()),
+        }
+    }
+}
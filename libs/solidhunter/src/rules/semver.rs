@@ -0,0 +1,249 @@
+use std::fmt;
+
+// Semver ranges of `pragma solidity` directives, following the syntax of the solidity compiler:
+// space separated comparators that must all match, alternatives separated by `||`, hyphen ranges
+// and partial versions like `0.8`
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Version {
+    pub major: u64,
+    pub minor: u64,
+    pub patch: u64,
+}
+
+impl fmt::Display for Version {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.patch)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Op {
+    Exact,
+    Greater,
+    GreaterEq,
+    Less,
+    LessEq,
+    Caret,
+    Tilde,
+}
+
+#[derive(Debug, Clone)]
+struct Comparator {
+    op: Op,
+    version: Version,
+    // Number of components written, `0.8` has 2
+    parts: usize,
+}
+
+impl Comparator {
+    // First version after the ones matching a partial version, `0.8` stops at `0.9.0`
+    fn partial_end(&self) -> Version {
+        let Version { major, minor, .. } = self.version;
+        match self.parts {
+            1 => Version {
+                major: major + 1,
+                minor: 0,
+                patch: 0,
+            },
+            _ => Version {
+                major,
+                minor: minor + 1,
+                patch: 0,
+            },
+        }
+    }
+
+    fn matches(&self, version: &Version) -> bool {
+        let exact = self.parts == 3;
+        match self.op {
+            Op::Exact if exact => *version == self.version,
+            Op::Exact => *version >= self.version && *version < self.partial_end(),
+            Op::Greater if exact => *version > self.version,
+            Op::Greater => *version >= self.partial_end(),
+            Op::GreaterEq => *version >= self.version,
+            Op::Less => *version < self.version,
+            Op::LessEq if exact => *version <= self.version,
+            Op::LessEq => *version < self.partial_end(),
+            Op::Caret => {
+                let Version {
+                    major,
+                    minor,
+                    patch,
+                } = self.version;
+                let end = if major > 0 {
+                    Version {
+                        major: major + 1,
+                        minor: 0,
+                        patch: 0,
+                    }
+                } else if minor > 0 || !exact {
+                    self.partial_end()
+                } else {
+                    Version {
+                        major,
+                        minor,
+                        patch: patch + 1,
+                    }
+                };
+                *version >= self.version && *version < end
+            }
+            Op::Tilde => *version >= self.version && *version < self.partial_end(),
+        }
+    }
+
+    // Smallest version the comparator lets through, if it has a lower bound
+    fn lower_bound(&self) -> Option<Version> {
+        match self.op {
+            Op::Less | Op::LessEq => None,
+            Op::Greater if self.parts == 3 => Some(Version {
+                patch: self.version.patch + 1,
+                ..self.version
+            }),
+            Op::Greater => Some(self.partial_end()),
+            _ => Some(self.version),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct VersionReq {
+    alternatives: Vec<Vec<Comparator>>,
+}
+
+fn parse_version(text: &str) -> Option<(Version, usize)> {
+    let mut components = Vec::new();
+
+    for component in text.split('.') {
+        if matches!(component, "x" | "X" | "*") {
+            break;
+        }
+        components.push(component.parse::<u64>().ok()?);
+    }
+    if components.is_empty() || components.len() > 3 {
+        return None;
+    }
+    let parts = components.len();
+    components.resize(3, 0);
+    Some((
+        Version {
+            major: components[0],
+            minor: components[1],
+            patch: components[2],
+        },
+        parts,
+    ))
+}
+
+fn parse_comparator(text: &str) -> Option<Comparator> {
+    let version_start = text.find(|c: char| c.is_ascii_digit())?;
+    let op = match &text[..version_start] {
+        "" | "=" => Op::Exact,
+        ">" => Op::Greater,
+        ">=" => Op::GreaterEq,
+        "<" => Op::Less,
+        "<=" => Op::LessEq,
+        "^" => Op::Caret,
+        "~" => Op::Tilde,
+        _ => return None,
+    };
+    let (version, parts) = parse_version(&text[version_start..])?;
+
+    Some(Comparator { op, version, parts })
+}
+
+fn parse_alternative(text: &str) -> Option<Vec<Comparator>> {
+    let mut words: Vec<String> = Vec::new();
+
+    // Operators can be separated from their version, like `>= 0.8.0`
+    for word in text.split_whitespace() {
+        match words.last_mut() {
+            Some(last) if last.chars().all(|c| "<>=^~".contains(c)) => last.push_str(word),
+            _ => words.push(word.to_string()),
+        }
+    }
+
+    if let [from, dash, to] = words.as_slice() {
+        if dash == "-" {
+            let from = parse_comparator(from)?;
+            let to = parse_comparator(to)?;
+            return Some(vec![
+                Comparator {
+                    op: Op::GreaterEq,
+                    ..from
+                },
+                Comparator {
+                    op: Op::LessEq,
+                    ..to
+                },
+            ]);
+        }
+    }
+    words.iter().map(|word| parse_comparator(word)).collect()
+}
+
+impl VersionReq {
+    pub fn parse(text: &str) -> Option<VersionReq> {
+        let alternatives = text
+            .split("||")
+            .map(parse_alternative)
+            .collect::<Option<Vec<Vec<Comparator>>>>()?;
+
+        if alternatives
+            .iter()
+            .any(|alternative| alternative.is_empty())
+        {
+            return None;
+        }
+        Some(VersionReq { alternatives })
+    }
+
+    pub fn matches(&self, version: &Version) -> bool {
+        self.alternatives.iter().any(|alternative| {
+            alternative
+                .iter()
+                .all(|comparator| comparator.matches(version))
+        })
+    }
+
+    /// Returns the smallest version matching the range, `None` if nothing matches.
+    pub fn min_version(&self) -> Option<Version> {
+        self.alternatives
+            .iter()
+            .map(|alternative| {
+                alternative
+                    .iter()
+                    .filter_map(Comparator::lower_bound)
+                    .max()
+                    .unwrap_or(Version {
+                        major: 0,
+                        minor: 0,
+                        patch: 0,
+                    })
+            })
+            .filter(|lower| self.matches(lower))
+            .min()
+    }
+
+    /// Whether the range allows more than a single version.
+    pub fn is_floating(&self) -> bool {
+        match self.alternatives.as_slice() {
+            [alternative] => match alternative.as_slice() {
+                [comparator] => comparator.op != Op::Exact || comparator.parts < 3,
+                _ => true,
+            },
+            _ => true,
+        }
+    }
+}
+
+/// Retrieves the version range of a `pragma solidity` directive from its source text.
+pub fn pragma_version_range(directive: &str) -> Option<&str> {
+    let range = directive
+        .trim()
+        .strip_prefix("pragma")?
+        .trim_start()
+        .strip_prefix("solidity")?;
+
+    Some(range.trim().trim_end_matches(';').trim())
+}
@@ -43,15 +43,39 @@ pub struct RuleEntry {
     pub data: Option<Value>,
 }
 
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct RulesOverride {
+    pub files: Vec<String>,
+    #[serde(default)]
+    pub rules: Vec<RuleEntry>,
+}
+
 #[derive(Serialize, Deserialize, Debug)]
 pub struct Rules {
     pub name: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub extends: Vec<String>,
     pub rules: Vec<RuleEntry>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub overrides: Vec<RulesOverride>,
 }
 
 pub trait RuleType: Send + Sync + 'static {
     fn diagnose(&self, file: &SolidFile, files: &[SolidFile]) -> Vec<LintDiag>;
     fn get_documentation(&self) -> RuleDocumentation;
+
+    /// Computes the edits fixing a diagnostic previously reported by this rule, the other loaded
+    /// files are passed like in `diagnose`.
+    /// Rules that cannot be fixed automatically keep the default implementation.
+    fn fix(&self, _file: &SolidFile, _files: &[SolidFile], _diag: &LintDiag) -> Option<Fix> {
+        None
+    }
+
+    /// Whether the rule reads the other files passed to `diagnose`. These rules run once every
+    /// file is loaded, the other rules only receive the file they lint.
+    fn is_cross_file(&self) -> bool {
+        false
+    }
 }
 
 pub type RulesMap = HashMap<String, fn(RuleEntry) -> Box<dyn RuleType>>;
@@ -0,0 +1,55 @@
+use crate::rules::types::{RuleEntry, RulesMap};
+use std::collections::HashMap;
+
+pub(crate) mod upgradeable;
+pub(crate) mod upgradeable_constructor;
+pub(crate) mod upgradeable_disable_initializers;
+pub(crate) mod upgradeable_state_initializer;
+pub(crate) mod upgradeable_storage_gap;
+pub(crate) mod upgradeable_unsafe_operations;
+
+// List all rules
+use crate::rules::upgradeability::upgradeable_constructor::UpgradeableConstructor;
+use crate::rules::upgradeability::upgradeable_disable_initializers::UpgradeableDisableInitializers;
+use crate::rules::upgradeability::upgradeable_state_initializer::UpgradeableStateInitializer;
+use crate::rules::upgradeability::upgradeable_storage_gap::UpgradeableStorageGap;
+use crate::rules::upgradeability::upgradeable_unsafe_operations::UpgradeableUnsafeOperations;
+
+use crate::rules::RuleBuilder;
+
+pub fn create_default_rules() -> Vec<RuleEntry> {
+    vec![
+        UpgradeableConstructor::create_default(),
+        UpgradeableDisableInitializers::create_default(),
+        UpgradeableStateInitializer::create_default(),
+        UpgradeableUnsafeOperations::create_default(),
+        UpgradeableStorageGap::create_default(),
+    ]
+}
+
+pub fn create_rules() -> RulesMap {
+    let mut rules: HashMap<String, RuleBuilder> = HashMap::new();
+
+    rules.insert(
+        upgradeable_constructor::RULE_ID.to_string(),
+        UpgradeableConstructor::create,
+    );
+    rules.insert(
+        upgradeable_disable_initializers::RULE_ID.to_string(),
+        UpgradeableDisableInitializers::create,
+    );
+    rules.insert(
+        upgradeable_state_initializer::RULE_ID.to_string(),
+        UpgradeableStateInitializer::create,
+    );
+    rules.insert(
+        upgradeable_unsafe_operations::RULE_ID.to_string(),
+        UpgradeableUnsafeOperations::create,
+    );
+    rules.insert(
+        upgradeable_storage_gap::RULE_ID.to_string(),
+        UpgradeableStorageGap::create,
+    );
+
+    rules
+}
@@ -0,0 +1,136 @@
+use crate::linter::SolidFile;
+use crate::rules::imports::visible_bases;
+use crate::rules::types::Options;
+use crate::rules::utils::callee_ident;
+use crate::types::{Position, Range};
+use osmium_libs_solidity_ast_extractor::*;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+
+const DISABLE_INITIALIZERS: &str = "_disableInitializers";
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct UpgradeabilityOptions {
+    // Names of the bases making a contract upgradeable, a leading `*` matches a suffix
+    #[serde(default = "default_base_contracts")]
+    pub base_contracts: Vec<String>,
+}
+
+fn default_base_contracts() -> Vec<String> {
+    vec!["Initializable".to_string(), "*Upgradeable".to_string()]
+}
+
+impl Default for UpgradeabilityOptions {
+    fn default() -> Self {
+        UpgradeabilityOptions {
+            base_contracts: default_base_contracts(),
+        }
+    }
+}
+
+impl UpgradeabilityOptions {
+    pub fn from_data(rule_id: &str, data: &Option<serde_json::Value>) -> Self {
+        match data {
+            Some(data) => serde_json::from_value(data.clone()).unwrap_or_else(|_| {
+                eprintln!("{} rule : bad config data", rule_id);
+                UpgradeabilityOptions::default()
+            }),
+            None => UpgradeabilityOptions::default(),
+        }
+    }
+
+    pub fn documentation() -> Options {
+        Options {
+            description: "baseContracts lists the bases making a contract upgradeable, directly or through other bases, a leading * matches the end of the name".to_string(),
+            default: "{\"baseContracts\": [\"Initializable\", \"*Upgradeable\"]}".to_string(),
+        }
+    }
+
+    fn is_base_contract(&self, name: &str) -> bool {
+        self.base_contracts
+            .iter()
+            .any(|base| match base.strip_prefix('*') {
+                Some(suffix) => name.ends_with(suffix),
+                None => name == base,
+            })
+    }
+
+    /// Whether the contract is one of the bases listed by name, like OpenZeppelin's
+    /// `Initializable` which keeps its state out of the sequential storage layout.
+    pub fn is_listed_base(&self, name: &str) -> bool {
+        self.base_contracts.iter().any(|base| base == name)
+    }
+
+    fn is_upgradeable(&self, bases: &HashMap<String, Vec<String>>, contract: &str) -> bool {
+        let mut visited = HashSet::new();
+        let mut stack = vec![contract];
+
+        while let Some(current) = stack.pop() {
+            if self.is_base_contract(current) {
+                return true;
+            }
+            if visited.insert(current) {
+                if let Some(parents) = bases.get(current) {
+                    stack.extend(parents.iter().map(|parent| parent.as_str()));
+                }
+            }
+        }
+        false
+    }
+
+    /// The contracts of the file inheriting from one of the base contracts, the bases are looked
+    /// up in the files it imports.
+    pub fn upgradeable_contracts(
+        &self,
+        file: &SolidFile,
+        files: &[SolidFile],
+    ) -> Vec<ItemContract> {
+        let bases = visible_bases(file, files);
+
+        retriever::retrieve_contract_nodes(&file.data)
+            .into_iter()
+            .filter(|contract| contract.is_contract() || contract.is_abstract_contract())
+            .filter(|contract| self.is_upgradeable(&bases, &contract.name.to_string()))
+            .collect()
+    }
+}
+
+pub(crate) fn constructor(contract: &ItemContract) -> Option<ItemFunction> {
+    contract.body.iter().find_map(|item| match item {
+        Item::Function(function) if matches!(function.kind, FunctionKind::Constructor(_)) => {
+            Some(function.clone())
+        }
+        _ => None,
+    })
+}
+
+pub(crate) fn is_disable_initializers(stmt: &Stmt) -> bool {
+    match stmt {
+        Stmt::Expr(stmt) => {
+            matches!(&stmt.expr, Expr::Call(_))
+                && callee_ident(&stmt.expr).is_some_and(|ident| ident == DISABLE_INITIALIZERS)
+        }
+        _ => false,
+    }
+}
+
+pub(crate) fn constructor_stmts(constructor: &ItemFunction) -> Vec<Stmt> {
+    match &constructor.body {
+        FunctionBody::Block(block) => block.stmts.clone(),
+        _ => vec![],
+    }
+}
+
+pub(crate) fn span_range(node: &impl Spanned) -> Range {
+    Range {
+        start: Position {
+            line: node.span().start().line,
+            character: node.span().start().column,
+        },
+        end: Position {
+            line: node.span().end().line,
+            character: node.span().end().column,
+        },
+    }
+}
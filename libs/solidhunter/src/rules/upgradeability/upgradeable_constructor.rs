@@ -0,0 +1,104 @@
+use crate::linter::SolidFile;
+use crate::rules::types::*;
+use crate::rules::upgradeability::upgradeable::{
+    constructor, constructor_stmts, is_disable_initializers, span_range, UpgradeabilityOptions,
+};
+use crate::types::*;
+use osmium_libs_solidity_ast_extractor::*;
+
+// global
+pub const RULE_ID: &str = "upgradeable-constructor";
+
+// specific
+const DEFAULT_SEVERITY: Severity = Severity::WARNING;
+
+pub struct UpgradeableConstructor {
+    options: UpgradeabilityOptions,
+    data: RuleEntry,
+}
+
+impl UpgradeableConstructor {
+    fn create_diag(
+        &self,
+        file: &SolidFile,
+        contract: &ItemContract,
+        kind: &FunctionKind,
+    ) -> LintDiag {
+        LintDiag {
+            id: RULE_ID.to_string(),
+            range: span_range(kind),
+            same_line_ranges: None,
+            fix: None,
+            message: format!(
+                "{} is upgradeable, its constructor only runs on the implementation and must only call _disableInitializers(), move its logic to an initializer",
+                contract.name
+            ),
+            severity: self.data.severity,
+            code: None,
+            source: None,
+            uri: file.path.clone(),
+        }
+    }
+}
+
+impl RuleType for UpgradeableConstructor {
+    fn diagnose(&self, file: &SolidFile, files: &[SolidFile]) -> Vec<LintDiag> {
+        let mut res = Vec::new();
+
+        for contract in self.options.upgradeable_contracts(file, files) {
+            let Some(constructor) = constructor(&contract) else {
+                continue;
+            };
+            if constructor_stmts(&constructor)
+                .iter()
+                .any(|stmt| !is_disable_initializers(stmt))
+            {
+                res.push(self.create_diag(file, &contract, &constructor.kind));
+            }
+        }
+        res
+    }
+
+    fn get_documentation(&self) -> RuleDocumentation {
+        RuleDocumentation {
+            id: RULE_ID.to_string(),
+            severity: DEFAULT_SEVERITY,
+            description: "Constructors of upgradeable contracts run on the implementation, not on the proxy. They must only call _disableInitializers(), the state of the proxy is set up by an initializer.".to_string(),
+            category: "upgradeability".to_string(),
+            example_config: "{\"id\": \"upgradeable-constructor\", \"severity\": \"WARNING\"}".to_string(),
+            source_link: "https://github.com/astrodevs-labs/osmium/blob/main/toolchains/solidity/core/crates/linter-lib/src/rules/upgradeability/upgradeable_constructor.rs".to_string(),
+            test_link: "https://github.com/astrodevs-labs/osmium/tree/main/toolchains/solidity/core/crates/linter-lib/testdata/UpgradeableConstructor".to_string(),
+            options: vec![UpgradeabilityOptions::documentation()],
+            examples: Examples {
+                good: vec![Example {
+                    description: "State set in an initializer".to_string(),
+                    code: "constructor() {\n    _disableInitializers();\n}\n\nfunction initialize(address owner_) external initializer {\n    owner = owner_;\n}".to_string(),
+                }],
+                bad: vec![Example {
+                    description: "State set in the constructor".to_string(),
+                    code: "constructor(address owner_) {\n    owner = owner_;\n}".to_string(),
+                }],
+            },
+        }
+    }
+
+    fn is_cross_file(&self) -> bool {
+        true
+    }
+}
+
+impl UpgradeableConstructor {
+    pub(crate) fn create(data: RuleEntry) -> Box<dyn RuleType> {
+        let options = UpgradeabilityOptions::from_data(RULE_ID, &data.data);
+        let rule = UpgradeableConstructor { options, data };
+        Box::new(rule)
+    }
+
+    pub(crate) fn create_default() -> RuleEntry {
+        RuleEntry {
+            id: RULE_ID.to_string(),
+            severity: DEFAULT_SEVERITY,
+            data: Some(serde_json::to_value(UpgradeabilityOptions::default()).unwrap()),
+        }
+    }
+}
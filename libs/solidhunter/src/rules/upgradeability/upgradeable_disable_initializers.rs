@@ -0,0 +1,102 @@
+use crate::linter::SolidFile;
+use crate::rules::types::*;
+use crate::rules::upgradeability::upgradeable::{
+    constructor, constructor_stmts, is_disable_initializers, span_range, UpgradeabilityOptions,
+};
+use crate::types::*;
+use osmium_libs_solidity_ast_extractor::*;
+
+// global
+pub const RULE_ID: &str = "upgradeable-disable-initializers";
+
+// specific
+const DEFAULT_SEVERITY: Severity = Severity::WARNING;
+
+pub struct UpgradeableDisableInitializers {
+    options: UpgradeabilityOptions,
+    data: RuleEntry,
+}
+
+impl UpgradeableDisableInitializers {
+    fn create_diag(&self, file: &SolidFile, contract: &ItemContract) -> LintDiag {
+        LintDiag {
+            id: RULE_ID.to_string(),
+            range: span_range(&contract.name),
+            same_line_ranges: None,
+            fix: None,
+            message: format!(
+                "{} is an upgradeable implementation, its constructor must call _disableInitializers() so that nobody can initialize it",
+                contract.name
+            ),
+            severity: self.data.severity,
+            code: None,
+            source: None,
+            uri: file.path.clone(),
+        }
+    }
+}
+
+impl RuleType for UpgradeableDisableInitializers {
+    fn diagnose(&self, file: &SolidFile, files: &[SolidFile]) -> Vec<LintDiag> {
+        let mut res = Vec::new();
+
+        // Abstract contracts are bases, only the deployed implementations need a constructor
+        for contract in self.options.upgradeable_contracts(file, files) {
+            if !contract.is_contract() {
+                continue;
+            }
+            let disabled = constructor(&contract).is_some_and(|constructor| {
+                constructor_stmts(&constructor)
+                    .iter()
+                    .any(is_disable_initializers)
+            });
+            if !disabled {
+                res.push(self.create_diag(file, &contract));
+            }
+        }
+        res
+    }
+
+    fn get_documentation(&self) -> RuleDocumentation {
+        RuleDocumentation {
+            id: RULE_ID.to_string(),
+            severity: DEFAULT_SEVERITY,
+            description: "Upgradeable implementations must call _disableInitializers() in their constructor. Otherwise anyone can initialize the implementation and take control of it.".to_string(),
+            category: "upgradeability".to_string(),
+            example_config: "{\"id\": \"upgradeable-disable-initializers\", \"severity\": \"WARNING\"}".to_string(),
+            source_link: "https://github.com/astrodevs-labs/osmium/blob/main/toolchains/solidity/core/crates/linter-lib/src/rules/upgradeability/upgradeable_disable_initializers.rs".to_string(),
+            test_link: "https://github.com/astrodevs-labs/osmium/tree/main/toolchains/solidity/core/crates/linter-lib/testdata/UpgradeableDisableInitializers".to_string(),
+            options: vec![UpgradeabilityOptions::documentation()],
+            examples: Examples {
+                good: vec![Example {
+                    description: "Initializers disabled on the implementation".to_string(),
+                    code: "contract Vault is Initializable {\n    constructor() {\n        _disableInitializers();\n    }\n}".to_string(),
+                }],
+                bad: vec![Example {
+                    description: "Implementation without constructor".to_string(),
+                    code: "contract Vault is Initializable {\n    function initialize() external initializer {}\n}".to_string(),
+                }],
+            },
+        }
+    }
+
+    fn is_cross_file(&self) -> bool {
+        true
+    }
+}
+
+impl UpgradeableDisableInitializers {
+    pub(crate) fn create(data: RuleEntry) -> Box<dyn RuleType> {
+        let options = UpgradeabilityOptions::from_data(RULE_ID, &data.data);
+        let rule = UpgradeableDisableInitializers { options, data };
+        Box::new(rule)
+    }
+
+    pub(crate) fn create_default() -> RuleEntry {
+        RuleEntry {
+            id: RULE_ID.to_string(),
+            severity: DEFAULT_SEVERITY,
+            data: Some(serde_json::to_value(UpgradeabilityOptions::default()).unwrap()),
+        }
+    }
+}
@@ -0,0 +1,105 @@
+use crate::linter::SolidFile;
+use crate::rules::types::*;
+use crate::rules::upgradeability::upgradeable::{span_range, UpgradeabilityOptions};
+use crate::types::*;
+use osmium_libs_solidity_ast_extractor::*;
+
+// global
+pub const RULE_ID: &str = "upgradeable-state-initializer";
+
+// specific
+const DEFAULT_SEVERITY: Severity = Severity::WARNING;
+
+pub struct UpgradeableStateInitializer {
+    options: UpgradeabilityOptions,
+    data: RuleEntry,
+}
+
+impl UpgradeableStateInitializer {
+    fn create_diag(
+        &self,
+        file: &SolidFile,
+        contract: &ItemContract,
+        var: &VariableDefinition,
+    ) -> LintDiag {
+        LintDiag {
+            id: RULE_ID.to_string(),
+            range: span_range(&var.name),
+            same_line_ranges: None,
+            fix: None,
+            message: format!(
+                "{} is upgradeable, the initial value of {} is only set on the implementation, set it in an initializer",
+                contract.name, var.name
+            ),
+            severity: self.data.severity,
+            code: None,
+            source: None,
+            uri: file.path.clone(),
+        }
+    }
+}
+
+impl RuleType for UpgradeableStateInitializer {
+    fn diagnose(&self, file: &SolidFile, files: &[SolidFile]) -> Vec<LintDiag> {
+        let mut res = Vec::new();
+
+        for contract in self.options.upgradeable_contracts(file, files) {
+            for item in &contract.body {
+                let Item::Variable(var) = item else {
+                    continue;
+                };
+                // Constants and immutables live in the code of the implementation, not in storage
+                if var.initializer.is_some()
+                    && !var.attributes.has_constant()
+                    && !var.attributes.has_immutable()
+                {
+                    res.push(self.create_diag(file, &contract, var));
+                }
+            }
+        }
+        res
+    }
+
+    fn get_documentation(&self) -> RuleDocumentation {
+        RuleDocumentation {
+            id: RULE_ID.to_string(),
+            severity: DEFAULT_SEVERITY,
+            description: "State variables of upgradeable contracts must not have an initial value, it is set by the constructor of the implementation and never reaches the storage of the proxy. Constants and immutables are allowed.".to_string(),
+            category: "upgradeability".to_string(),
+            example_config: "{\"id\": \"upgradeable-state-initializer\", \"severity\": \"WARNING\"}".to_string(),
+            source_link: "https://github.com/astrodevs-labs/osmium/blob/main/toolchains/solidity/core/crates/linter-lib/src/rules/upgradeability/upgradeable_state_initializer.rs".to_string(),
+            test_link: "https://github.com/astrodevs-labs/osmium/tree/main/toolchains/solidity/core/crates/linter-lib/testdata/UpgradeableStateInitializer".to_string(),
+            options: vec![UpgradeabilityOptions::documentation()],
+            examples: Examples {
+                good: vec![Example {
+                    description: "Value set in an initializer".to_string(),
+                    code: "uint256 public fee;\n\nfunction initialize() external initializer {\n    fee = 100;\n}".to_string(),
+                }],
+                bad: vec![Example {
+                    description: "Inline initial value".to_string(),
+                    code: "uint256 public fee = 100;".to_string(),
+                }],
+            },
+        }
+    }
+
+    fn is_cross_file(&self) -> bool {
+        true
+    }
+}
+
+impl UpgradeableStateInitializer {
+    pub(crate) fn create(data: RuleEntry) -> Box<dyn RuleType> {
+        let options = UpgradeabilityOptions::from_data(RULE_ID, &data.data);
+        let rule = UpgradeableStateInitializer { options, data };
+        Box::new(rule)
+    }
+
+    pub(crate) fn create_default() -> RuleEntry {
+        RuleEntry {
+            id: RULE_ID.to_string(),
+            severity: DEFAULT_SEVERITY,
+            data: Some(serde_json::to_value(UpgradeabilityOptions::default()).unwrap()),
+        }
+    }
+}
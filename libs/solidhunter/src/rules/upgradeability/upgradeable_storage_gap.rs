@@ -0,0 +1,103 @@
+use crate::linter::SolidFile;
+use crate::rules::types::*;
+use crate::rules::upgradeability::upgradeable::{span_range, UpgradeabilityOptions};
+use crate::types::*;
+use osmium_libs_solidity_ast_extractor::*;
+
+// global
+pub const RULE_ID: &str = "upgradeable-storage-gap";
+
+// specific
+const DEFAULT_SEVERITY: Severity = Severity::WARNING;
+const GAP_NAME: &str = "__gap";
+
+pub struct UpgradeableStorageGap {
+    options: UpgradeabilityOptions,
+    data: RuleEntry,
+}
+
+// `uint256[50] private __gap;`
+fn has_storage_gap(contract: &ItemContract) -> bool {
+    contract.body.iter().any(|item| match item {
+        Item::Variable(var) => var.name == GAP_NAME && matches!(var.ty, Type::Array(_)),
+        _ => false,
+    })
+}
+
+impl UpgradeableStorageGap {
+    fn create_diag(&self, file: &SolidFile, contract: &ItemContract) -> LintDiag {
+        LintDiag {
+            id: RULE_ID.to_string(),
+            range: span_range(&contract.name),
+            same_line_ranges: None,
+            fix: None,
+            message: format!(
+                "{} is an upgradeable base contract without {} array, adding state variables to it will shift the storage of the contracts inheriting from it",
+                contract.name, GAP_NAME
+            ),
+            severity: self.data.severity,
+            code: None,
+            source: None,
+            uri: file.path.clone(),
+        }
+    }
+}
+
+impl RuleType for UpgradeableStorageGap {
+    fn diagnose(&self, file: &SolidFile, files: &[SolidFile]) -> Vec<LintDiag> {
+        let mut res = Vec::new();
+
+        for contract in self.options.upgradeable_contracts(file, files) {
+            if contract.is_abstract_contract()
+                && !self.options.is_listed_base(&contract.name.to_string())
+                && !has_storage_gap(&contract)
+            {
+                res.push(self.create_diag(file, &contract));
+            }
+        }
+        res
+    }
+
+    fn get_documentation(&self) -> RuleDocumentation {
+        RuleDocumentation {
+            id: RULE_ID.to_string(),
+            severity: DEFAULT_SEVERITY,
+            description: "Upgradeable base contracts, the abstract ones, must reserve storage slots with a __gap array so that new versions can add state variables without shifting the storage of the contracts inheriting from them.".to_string(),
+            category: "upgradeability".to_string(),
+            example_config: "{\"id\": \"upgradeable-storage-gap\", \"severity\": \"WARNING\"}".to_string(),
+            source_link: "https://github.com/astrodevs-labs/osmium/blob/main/toolchains/solidity/core/crates/linter-lib/src/rules/upgradeability/upgradeable_storage_gap.rs".to_string(),
+            test_link: "https://github.com/astrodevs-labs/osmium/tree/main/toolchains/solidity/core/crates/linter-lib/testdata/UpgradeableStorageGap".to_string(),
+            options: vec![UpgradeabilityOptions::documentation()],
+            examples: Examples {
+                good: vec![Example {
+                    description: "Reserved storage slots".to_string(),
+                    code: "abstract contract FeesUpgradeable is Initializable {\n    uint256 public fee;\n    uint256[49] private __gap;\n}".to_string(),
+                }],
+                bad: vec![Example {
+                    description: "Base contract without gap".to_string(),
+                    code: "abstract contract FeesUpgradeable is Initializable {\n    uint256 public fee;\n}".to_string(),
+                }],
+            },
+        }
+    }
+
+    fn is_cross_file(&self) -> bool {
+        true
+    }
+}
+
+impl UpgradeableStorageGap {
+    pub(crate) fn create(data: RuleEntry) -> Box<dyn RuleType> {
+        let options = UpgradeabilityOptions::from_data(RULE_ID, &data.data);
+        let rule = UpgradeableStorageGap { options, data };
+        Box::new(rule)
+    }
+
+    pub(crate) fn create_default() -> RuleEntry {
+        RuleEntry {
+            id: RULE_ID.to_string(),
+            severity: DEFAULT_SEVERITY,
+            data: Some(serde_json::to_value(UpgradeabilityOptions::default()).unwrap()),
+        }
+    }
+}
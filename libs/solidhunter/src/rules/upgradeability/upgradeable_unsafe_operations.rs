@@ -0,0 +1,130 @@
+use crate::linter::SolidFile;
+use crate::rules::types::*;
+use crate::rules::upgradeability::upgradeable::{span_range, UpgradeabilityOptions};
+use crate::rules::utils::called_member_ident;
+use crate::types::*;
+use osmium_libs_solidity_ast_extractor::*;
+
+// global
+pub const RULE_ID: &str = "upgradeable-unsafe-operations";
+
+// specific
+const DEFAULT_SEVERITY: Severity = Severity::WARNING;
+const UNSAFE_CALLS: [&str; 2] = ["selfdestruct", "suicide"];
+const UNSAFE_MEMBERS: [&str; 1] = ["delegatecall"];
+
+pub struct UpgradeableUnsafeOperations {
+    options: UpgradeabilityOptions,
+    data: RuleEntry,
+}
+
+struct UnsafeOperationsVisitor {
+    operations: Vec<SolIdent>,
+}
+
+impl<'ast> Visit<'ast> for UnsafeOperationsVisitor {
+    fn visit_expr_call(&mut self, call: &'ast ExprCall) {
+        if let Expr::Ident(ident) = &*call.expr {
+            if UNSAFE_CALLS.iter().any(|name| ident == *name) {
+                self.operations.push(ident.clone());
+            }
+        }
+        visit::visit_expr_call(self, call);
+    }
+
+    fn visit_expr_member(&mut self, member: &'ast ExprMember) {
+        if let Some(ident) = called_member_ident(member) {
+            if UNSAFE_MEMBERS.iter().any(|name| ident == *name) {
+                self.operations.push(ident.clone());
+            }
+        }
+        visit::visit_expr_member(self, member);
+    }
+}
+
+impl UpgradeableUnsafeOperations {
+    fn create_diag(
+        &self,
+        file: &SolidFile,
+        contract: &ItemContract,
+        operation: &SolIdent,
+    ) -> LintDiag {
+        LintDiag {
+            id: RULE_ID.to_string(),
+            range: span_range(operation),
+            same_line_ranges: None,
+            fix: None,
+            message: format!(
+                "{} is upgradeable, {} can destroy the implementation and brick every proxy using it",
+                contract.name, operation
+            ),
+            severity: self.data.severity,
+            code: None,
+            source: None,
+            uri: file.path.clone(),
+        }
+    }
+}
+
+impl RuleType for UpgradeableUnsafeOperations {
+    fn diagnose(&self, file: &SolidFile, files: &[SolidFile]) -> Vec<LintDiag> {
+        let mut res = Vec::new();
+
+        for contract in self.options.upgradeable_contracts(file, files) {
+            let mut visitor = UnsafeOperationsVisitor {
+                operations: Vec::new(),
+            };
+            visitor.visit_item_contract(&contract);
+            for operation in &visitor.operations {
+                res.push(self.create_diag(file, &contract, operation));
+            }
+        }
+        res
+    }
+
+    fn get_documentation(&self) -> RuleDocumentation {
+        RuleDocumentation {
+            id: RULE_ID.to_string(),
+            severity: DEFAULT_SEVERITY,
+            description: "Upgradeable contracts must not use selfdestruct or delegatecall. Called on the implementation directly, they can destroy it and leave every proxy without code.".to_string(),
+            category: "upgradeability".to_string(),
+            example_config: "{\"id\": \"upgradeable-unsafe-operations\", \"severity\": \"WARNING\"}".to_string(),
+            source_link: "https://github.com/astrodevs-labs/osmium/blob/main/toolchains/solidity/core/crates/linter-lib/src/rules/upgradeability/upgradeable_unsafe_operations.rs".to_string(),
+            test_link: "https://github.com/astrodevs-labs/osmium/tree/main/toolchains/solidity/core/crates/linter-lib/testdata/UpgradeableUnsafeOperations".to_string(),
+            options: vec![UpgradeabilityOptions::documentation()],
+            examples: Examples {
+                good: vec![],
+                bad: vec![
+                    Example {
+                        description: "Selfdestruct in an implementation".to_string(),
+                        code: "function kill() external onlyOwner {\n    selfdestruct(payable(owner));\n}".to_string(),
+                    },
+                    Example {
+                        description: "Delegatecall in an implementation".to_string(),
+                        code: "function execute(address target, bytes calldata data) external {\n    target.delegatecall(data);\n}".to_string(),
+                    },
+                ],
+            },
+        }
+    }
+
+    fn is_cross_file(&self) -> bool {
+        true
+    }
+}
+
+impl UpgradeableUnsafeOperations {
+    pub(crate) fn create(data: RuleEntry) -> Box<dyn RuleType> {
+        let options = UpgradeabilityOptions::from_data(RULE_ID, &data.data);
+        let rule = UpgradeableUnsafeOperations { options, data };
+        Box::new(rule)
+    }
+
+    pub(crate) fn create_default() -> RuleEntry {
+        RuleEntry {
+            id: RULE_ID.to_string(),
+            severity: DEFAULT_SEVERITY,
+            data: Some(serde_json::to_value(UpgradeabilityOptions::default()).unwrap()),
+        }
+    }
+}
@@ -1,4 +1,11 @@
-use osmium_libs_solidity_ast_extractor::LineColumn;
+use crate::linter::SolidFile;
+use crate::types::{Position, Range, TextEdit};
+use osmium_libs_solidity_ast_extractor::{
+    visit, BinOp, Expr, ExprMember, FunctionBody, Item, ItemContract, ItemFunction, LineColumn,
+    SolIdent, Spanned, Type, VariableDeclaration, Visit,
+};
+use proc_macro2::{Span, TokenStream, TokenTree};
+use std::str::FromStr;
 
 pub fn absolute_index_from_location(location: LineColumn, content: &str) -> usize {
     let mut index = 0;
@@ -19,3 +26,360 @@ pub fn absolute_index_from_location(location: LineColumn, content: &str) -> usiz
     }
     index
 }
+
+// Functions are reported on their name, or their kind when they have none
+pub fn function_range(function: &ItemFunction) -> Range {
+    match &function.name {
+        Some(name) => span_range(name.span()),
+        None => span_range(function.kind.span()),
+    }
+}
+
+// Convert a name to mixedCase, leading underscores are kept as is
+pub fn to_mixed_case(name: &str) -> String {
+    let trimmed = name.trim_start_matches('_');
+    let mut res = name[..name.len() - trimmed.len()].to_string();
+    let mut first = true;
+    let mut upper_next = false;
+
+    for c in trimmed.chars() {
+        if c == '_' || c == '-' {
+            upper_next = !first;
+        } else if first {
+            res.push(c.to_ascii_lowercase());
+            first = false;
+        } else if upper_next {
+            res.push(c.to_ascii_uppercase());
+            upper_next = false;
+        } else {
+            res.push(c);
+        }
+    }
+    res
+}
+
+// Retrieve the name of the function called, through call options and curried calls
+pub fn callee_ident(expr: &Expr) -> Option<&SolIdent> {
+    match expr {
+        Expr::Ident(ident) => Some(ident),
+        Expr::Call(call) => callee_ident(&call.expr),
+        Expr::CallOptions(options) => callee_ident(&options.expr),
+        _ => None,
+    }
+}
+
+// The parser reads `to.send(amount)` as the member `send(amount)` of `to`, retrieve the name of
+// the member called, `send` here, or `None` if the member is not a call
+pub fn called_member_ident(member: &ExprMember) -> Option<&SolIdent> {
+    match &*member.member {
+        Expr::Call(call) => callee_ident(&call.expr),
+        _ => None,
+    }
+}
+
+// Retrieve the variable accessed by an expression like `a`, `a[i]` or `a.b`
+pub fn root_ident(expr: &Expr) -> Option<&SolIdent> {
+    match expr {
+        Expr::Ident(ident) => Some(ident),
+        Expr::Index(index) => root_ident(&index.expr),
+        Expr::Member(member) => root_ident(&member.expr),
+        _ => None,
+    }
+}
+
+// `delete x;` is read as the declaration of `x` with the type `delete`
+pub fn is_delete(ty: &Type) -> bool {
+    matches!(ty, Type::Custom(path) if path.len() == 1 && path.first() == "delete")
+}
+
+pub fn is_assign(op: &BinOp) -> bool {
+    matches!(
+        op,
+        BinOp::Assign(..)
+            | BinOp::AddAssign(..)
+            | BinOp::SubAssign(..)
+            | BinOp::MulAssign(..)
+            | BinOp::DivAssign(..)
+            | BinOp::RemAssign(..)
+            | BinOp::BitAndAssign(..)
+            | BinOp::BitOrAssign(..)
+            | BinOp::BitXorAssign(..)
+            | BinOp::SarAssign(..)
+            | BinOp::ShlAssign(..)
+            | BinOp::ShrAssign(..)
+    )
+}
+
+pub fn span_range(span: Span) -> Range {
+    Range {
+        start: Position {
+            line: span.start().line,
+            character: span.start().column,
+        },
+        end: Position {
+            line: span.end().line,
+            character: span.end().column,
+        },
+    }
+}
+
+fn spanning(start: Span, end: Span) -> Range {
+    Range {
+        start: span_range(start).start,
+        end: span_range(end).end,
+    }
+}
+
+// The spans of the parser only cover the names of the items, their whole ranges are computed
+// from the delimiters enclosing their bodies
+fn contract_scope(contract: &ItemContract) -> Range {
+    spanning(contract.name.span(), contract.brace_token.span.join())
+}
+
+fn function_scope(function: &ItemFunction) -> Range {
+    let end = match &function.body {
+        FunctionBody::Block(block) => block.brace_token.span.join(),
+        FunctionBody::Empty(semi) => semi.spans[0],
+    };
+    spanning(function.kind.span(), end)
+}
+
+fn item_scope(item: &Item) -> Option<Range> {
+    match item {
+        Item::Function(function) => Some(function_scope(function)),
+        Item::Struct(strukt) => Some(spanning(strukt.name.span(), strukt.brace_token.span.join())),
+        Item::Enum(enumm) => Some(spanning(enumm.name.span(), enumm.brace_token.span.join())),
+        Item::Event(event) => Some(spanning(event.name.span(), event.paren_token.span.join())),
+        Item::Error(error) => Some(spanning(error.name.span(), error.paren_token.span.join())),
+        _ => None,
+    }
+}
+
+fn contains(outer: &Range, inner: &Range) -> bool {
+    (outer.start.line, outer.start.character) <= (inner.start.line, inner.start.character)
+        && (inner.end.line, inner.end.character) <= (outer.end.line, outer.end.character)
+}
+
+// Members accessed with `.` belong to another value, like `other.balance`, and are skipped
+// unless `members` is set
+fn collect_identifier_ranges(
+    tokens: TokenStream,
+    name: &str,
+    members: bool,
+    ranges: &mut Vec<Range>,
+) {
+    let mut after_dot = false;
+
+    for token in tokens {
+        match &token {
+            TokenTree::Ident(ident) if ident == name && (members || !after_dot) => {
+                ranges.push(span_range(ident.span()))
+            }
+            TokenTree::Group(group) => {
+                collect_identifier_ranges(group.stream(), name, members, ranges)
+            }
+            _ => {}
+        }
+        after_dot = matches!(&token, TokenTree::Punct(punct) if punct.as_char() == '.');
+    }
+}
+
+fn identifier_ranges(file: &SolidFile, name: &str, members: bool) -> Vec<Range> {
+    let mut ranges = Vec::new();
+
+    if let Ok(tokens) = TokenStream::from_str(&file.content) {
+        collect_identifier_ranges(tokens, name, members, &mut ranges);
+    }
+    ranges
+}
+
+struct LocalNamesVisitor {
+    names: Vec<String>,
+}
+
+impl<'ast> Visit<'ast> for LocalNamesVisitor {
+    fn visit_variable_declaration(&mut self, var: &'ast VariableDeclaration) {
+        if let Some(name) = &var.name {
+            self.names.push(name.to_string());
+        }
+        visit::visit_variable_declaration(self, var);
+    }
+}
+
+// Names of the parameters, returns and local variables of a function
+fn local_names(function: &ItemFunction) -> Vec<String> {
+    let mut visitor = LocalNamesVisitor { names: Vec::new() };
+    visitor.visit_item_function(function);
+    visitor.names
+}
+
+// Whether an identifier with this name appears outside of the contract, in a contract
+// inheriting from it for instance. The other files are searched too, along with the members
+// accessed with `.` like `Base.compute()`.
+pub fn is_used_outside(
+    file: &SolidFile,
+    files: &[SolidFile],
+    contract: &ItemContract,
+    name: &str,
+) -> bool {
+    let scope = contract_scope(contract);
+    let used_in_file = identifier_ranges(file, name, true)
+        .iter()
+        .any(|range| !contains(&scope, range));
+
+    used_in_file
+        || files
+            .iter()
+            .filter(|other| other.path != file.path)
+            .any(|other| !identifier_ranges(other, name, true).is_empty())
+}
+
+// Compute the edits renaming a member of a contract: its declaration and the references to it
+// in the contract. Functions declaring a local with the same name and nested type declarations
+// are left untouched.
+pub fn rename_contract_member(
+    file: &SolidFile,
+    contract: &ItemContract,
+    name: &str,
+    new_name: &str,
+) -> Vec<TextEdit> {
+    let scope = contract_scope(contract);
+    let shadowed: Vec<Range> = contract
+        .body
+        .iter()
+        .filter(|item| match item {
+            Item::Function(function) => local_names(function).iter().any(|local| local == name),
+            _ => true,
+        })
+        .filter_map(item_scope)
+        .collect();
+
+    identifier_ranges(file, name, false)
+        .into_iter()
+        .filter(|range| contains(&scope, range))
+        .filter(|range| !shadowed.iter().any(|item| contains(item, range)))
+        .map(|range| TextEdit {
+            range,
+            new_text: new_name.to_string(),
+        })
+        .collect()
+}
+
+// Compute the edits renaming a parameter, a return or a local variable of a function
+pub fn rename_local(
+    file: &SolidFile,
+    function: &ItemFunction,
+    name: &str,
+    new_name: &str,
+) -> Vec<TextEdit> {
+    let scope = function_scope(function);
+    identifier_ranges(file, name, false)
+        .into_iter()
+        .filter(|range| contains(&scope, range))
+        .map(|range| TextEdit {
+            range,
+            new_text: new_name.to_string(),
+        })
+        .collect()
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CodeTokenKind {
+    Identifier,
+    // String literal and the quote delimiting it
+    String(char),
+}
+
+#[derive(Debug, Clone)]
+pub struct CodeToken {
+    pub kind: CodeTokenKind,
+    pub text: String,
+    pub range: Range,
+}
+
+fn is_identifier_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || c == '_' || c == '$'
+}
+
+// Split the content in identifiers and string literals, comments and everything else is
+// skipped. Unlike the Rust lexer it accepts single-quoted strings and yul code.
+pub fn tokenize_code(content: &str) -> Vec<CodeToken> {
+    let mut tokens = Vec::new();
+    let mut chars = content.chars().peekable();
+    let mut line = 1;
+    let mut character = 0;
+
+    let advance = |c: char, line: &mut usize, character: &mut usize| {
+        if c == '\n' {
+            *line += 1;
+            *character = 0;
+        } else {
+            *character += 1;
+        }
+    };
+
+    while let Some(c) = chars.next() {
+        let start = Position { line, character };
+        advance(c, &mut line, &mut character);
+
+        if c == '/' && chars.peek() == Some(&'/') {
+            while let Some(&next) = chars.peek() {
+                if next == '\n' {
+                    break;
+                }
+                advance(next, &mut line, &mut character);
+                chars.next();
+            }
+        } else if c == '/' && chars.peek() == Some(&'*') {
+            let mut previous = ' ';
+            for next in chars.by_ref() {
+                advance(next, &mut line, &mut character);
+                if previous == '*' && next == '/' {
+                    break;
+                }
+                previous = next;
+            }
+        } else if c == '"' || c == '\'' {
+            let mut text = String::from(c);
+            let mut escaped = false;
+            for next in chars.by_ref() {
+                advance(next, &mut line, &mut character);
+                text.push(next);
+                if next == c && !escaped {
+                    break;
+                }
+                escaped = next == '\\' && !escaped;
+            }
+            tokens.push(CodeToken {
+                kind: CodeTokenKind::String(c),
+                text,
+                range: Range {
+                    start,
+                    end: Position { line, character },
+                },
+            });
+        } else if is_identifier_char(c) {
+            let mut text = String::from(c);
+            while let Some(&next) = chars.peek() {
+                if !is_identifier_char(next) {
+                    break;
+                }
+                advance(next, &mut line, &mut character);
+                text.push(next);
+                chars.next();
+            }
+            // Numbers like `0x12ab` are not identifiers
+            if !c.is_ascii_digit() {
+                tokens.push(CodeToken {
+                    kind: CodeTokenKind::Identifier,
+                    text,
+                    range: Range {
+                        start,
+                        end: Position { line, character },
+                    },
+                });
+            }
+        }
+    }
+    tokens
+}
@@ -0,0 +1,112 @@
+use crate::errors::RuleConfigError;
+use crate::rules::types::Options;
+use serde_json::Value;
+
+// Maximum edit distance for an unknown rule id to be considered a typo of an existing one
+const MAX_SUGGESTION_DISTANCE: usize = 3;
+
+fn levenshtein(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+
+    for (i, a_char) in a.chars().enumerate() {
+        let mut current = vec![i + 1; b.len() + 1];
+        for (j, b_char) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(a_char != *b_char);
+            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+        previous = current;
+    }
+    previous[b.len()]
+}
+
+/// Returns the known rule id closest to `id`, if it is close enough to be a typo.
+pub fn suggest_rule_id<'a>(id: &str, known: impl Iterator<Item = &'a String>) -> Option<String> {
+    known
+        .map(|candidate| (levenshtein(id, candidate), candidate))
+        .filter(|(distance, _)| *distance <= MAX_SUGGESTION_DISTANCE)
+        .min_by(|a, b| a.cmp(b))
+        .map(|(_, candidate)| candidate.clone())
+}
+
+fn value_type(value: &Value) -> &'static str {
+    match value {
+        Value::Null => "null",
+        Value::Bool(_) => "a boolean",
+        Value::Number(number) if number.is_u64() => "a positive integer",
+        Value::Number(_) => "a number",
+        Value::String(_) => "a string",
+        Value::Array(_) => "an array",
+        Value::Object(_) => "an object",
+    }
+}
+
+fn validate_value(
+    path: &str,
+    id: &str,
+    value: &Value,
+    schema: &Value,
+    errors: &mut Vec<RuleConfigError>,
+) {
+    let matches = match (schema, value) {
+        (Value::Number(expected), Value::Number(found)) => !expected.is_u64() || found.is_u64(),
+        (Value::Array(expected), Value::Array(found)) => {
+            if let Some(item_schema) = expected.first() {
+                for (i, item) in found.iter().enumerate() {
+                    validate_value(&format!("{}[{}]", path, i), id, item, item_schema, errors);
+                }
+            }
+            true
+        }
+        (Value::Object(expected), Value::Object(found)) => {
+            for (key, item) in found {
+                let item_path = format!("{}.{}", path, key);
+                match expected.get(key) {
+                    Some(item_schema) => validate_value(&item_path, id, item, item_schema, errors),
+                    None => errors.push(RuleConfigError::InvalidOption {
+                        path: item_path,
+                        id: id.to_string(),
+                        expected: format!(
+                            "one of the properties {}",
+                            expected
+                                .keys()
+                                .map(|key| format!("\"{}\"", key))
+                                .collect::<Vec<String>>()
+                                .join(", ")
+                        ),
+                        found: format!("\"{}\"", key),
+                    }),
+                }
+            }
+            true
+        }
+        (expected, found) => std::mem::discriminant(expected) == std::mem::discriminant(found),
+    };
+
+    if !matches {
+        errors.push(RuleConfigError::InvalidOption {
+            path: path.to_string(),
+            id: id.to_string(),
+            expected: value_type(schema).to_string(),
+            found: value_type(value).to_string(),
+        });
+    }
+}
+
+/// Checks the `data` of a rule entry against the default value documented in its options.
+/// Defaults that are not valid JSON, like `explicit`, are documented as plain strings.
+pub fn validate_options(
+    path: &str,
+    id: &str,
+    data: &Value,
+    options: &[Options],
+) -> Vec<RuleConfigError> {
+    let mut errors = Vec::new();
+
+    if let Some(option) = options.first() {
+        let schema = serde_json::from_str(&option.default)
+            .unwrap_or_else(|_| Value::String(option.default.clone()));
+        validate_value(path, id, data, &schema, &mut errors);
+    }
+    errors
+}
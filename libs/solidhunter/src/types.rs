@@ -12,6 +12,8 @@ mod file_diags;
 pub use file_diags::FileDiags;
 mod ignore;
 pub use ignore::*;
+mod text_edit;
+pub use text_edit::{Fix, TextEdit};
 
 pub type LintResult = Result<FileDiags, SolidHunterError>;
 
@@ -93,9 +93,9 @@ impl fmt::Display for FileDiags {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         for (idx, diag) in self.diags.iter().enumerate() {
             write!(f, "{}\n{}", diag, self.format_highlighted_lines(idx))?;
-            write!(
+            writeln!(
                 f,
-                "   {} {}\n",
+                "   {} {}",
                 "=".to_string().cyan(),
                 diag.message.to_string().color(diag.severity.to_color())
             )?;
@@ -1,4 +1,4 @@
-use super::{Range, Severity};
+use super::{Fix, Range, Severity};
 use colored::Colorize;
 use serde::{Deserialize, Serialize};
 use std::fmt;
@@ -28,6 +28,10 @@ pub struct LintDiag {
     pub id: String,
 
     pub uri: Uri,
+
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    /// The edits that fix the diagnostic, if the rule provides one.
+    pub fix: Option<Fix>,
 }
 
 impl fmt::Display for LintDiag {
@@ -6,6 +6,24 @@ pub struct Position {
     pub character: usize,
 }
 
+impl Position {
+    // Compute the byte offset of the position in the content, lines start at 1 and characters at 0
+    pub fn to_offset(&self, content: &str) -> Option<usize> {
+        let mut offset = 0;
+        for (idx, line) in content.split('\n').enumerate() {
+            if idx + 1 == self.line {
+                return match line.char_indices().nth(self.character) {
+                    Some((char_offset, _)) => Some(offset + char_offset),
+                    None if line.chars().count() == self.character => Some(offset + line.len()),
+                    None => None,
+                };
+            }
+            offset += line.len() + 1;
+        }
+        None
+    }
+}
+
 impl PartialEq for Position {
     fn eq(&self, other: &Self) -> bool {
         self.line == other.line && self.character == other.character
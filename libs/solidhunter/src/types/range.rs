@@ -1,7 +1,7 @@
 use super::Position;
 use serde::{Deserialize, Serialize};
 
-#[derive(Clone, Serialize, Deserialize, Debug)]
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq)]
 pub struct Range {
     pub start: Position,
     pub end: Position,
@@ -26,4 +26,11 @@ impl Range {
             length
         }
     }
+
+    // Retrieve the text covered by the range
+    pub fn text<'a>(&self, content: &'a str) -> Option<&'a str> {
+        let start = self.start.to_offset(content)?;
+        let end = self.end.to_offset(content)?;
+        content.get(start..end)
+    }
 }
@@ -12,27 +12,34 @@ pub enum Severity {
     INFO = 3,
     /// Reports a hint.
     HINT = 4,
+    /// Disables the rule, only used in configurations.
+    OFF = 5,
 }
 
 impl Severity {
+    pub fn to_str(&self) -> &'static str {
+        match self {
+            Severity::ERROR => "error",
+            Severity::WARNING => "warning",
+            Severity::INFO => "info",
+            Severity::HINT => "hint",
+            Severity::OFF => "off",
+        }
+    }
+
     pub fn to_color(&self) -> colored::Color {
         match self {
             Severity::ERROR => colored::Color::Red,
             Severity::WARNING => colored::Color::Yellow,
             Severity::INFO => colored::Color::Blue,
             Severity::HINT => colored::Color::Green,
+            Severity::OFF => colored::Color::White,
         }
     }
 }
 
 impl fmt::Display for Severity {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let severity = match self {
-            Severity::ERROR => "error".to_string().red(),
-            Severity::WARNING => "warning".to_string().yellow(),
-            Severity::INFO => "info".to_string().blue(),
-            Severity::HINT => "hint".to_string().green(),
-        };
-        write!(f, "{}", severity)
+        write!(f, "{}", self.to_str().color(self.to_color()))
     }
 }
@@ -0,0 +1,21 @@
+use super::Range;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq)]
+pub struct TextEdit {
+    /// The range of the text to replace.
+    pub range: Range,
+
+    /// The text that replaces the range. Empty to delete it.
+    #[serde(rename = "newText")]
+    pub new_text: String,
+}
+
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq)]
+pub struct Fix {
+    /// A human-readable description of the fix.
+    pub message: String,
+
+    /// The edits to apply together for this fix.
+    pub edits: Vec<TextEdit>,
+}
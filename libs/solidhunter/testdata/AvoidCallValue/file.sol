@@ -0,0 +1,9 @@
+pragma solidity 0.6.0;
+
+contract Test {
+    function pay(address to, uint256 amount) public {
+        (bool success, ) = to.call.value(amount)("");
+        require(success);
+        (success, ) = to.call{value: amount}("");
+    }
+}
@@ -0,0 +1,11 @@
+pragma solidity 0.8.0;
+
+contract Test {
+    function forward(address to, bytes memory data) public payable {
+        (bool success, ) = to.call{value: msg.value}("");
+        require(success);
+        to.delegatecall(data);
+        to.staticcall(data);
+        IERC20(to).transfer(msg.sender, 1);
+    }
+}
@@ -0,0 +1,9 @@
+pragma solidity 0.8.0;
+
+contract Test {
+    address payable owner;
+
+    function kill() public {
+        selfdestruct(owner);
+    }
+}
@@ -0,0 +1,8 @@
+pragma solidity 0.4.24;
+
+contract Test {
+    function hash(bytes data) public pure returns (bytes32) {
+        bytes32 legacy = sha3(data);
+        return keccak256(data) ^ legacy;
+    }
+}
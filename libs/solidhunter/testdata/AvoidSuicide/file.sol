@@ -0,0 +1,10 @@
+pragma solidity 0.4.24;
+
+contract Test {
+    address owner;
+
+    function kill() public {
+        suicide(owner);
+        selfdestruct(owner);
+    }
+}
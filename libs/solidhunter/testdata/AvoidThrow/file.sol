@@ -0,0 +1,14 @@
+pragma solidity 0.4.24;
+
+contract Test {
+    address owner;
+
+    function restricted() public {
+        if (msg.sender != owner) {
+            throw;
+        }
+        if (msg.sender == address(0)) {
+            revert();
+        }
+    }
+}
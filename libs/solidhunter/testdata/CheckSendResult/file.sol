@@ -0,0 +1,12 @@
+pragma solidity 0.8.0;
+
+contract Test {
+    function pay(address payable to, uint256 amount) public {
+        to.send(amount);
+        bool success = to.send(amount);
+        require(to.send(amount));
+        if (!to.send(amount)) {
+            to.send(amount);
+        }
+    }
+}
@@ -0,0 +1,32 @@
+pragma solidity ^0.8.0;
+
+contract Complexity {
+    function simple(uint256 a) public pure returns (uint256) {
+        if (a > 1) {
+            return a;
+        }
+        return 0;
+    }
+
+    function complex(uint256 a, bool b) public pure returns (uint256) {
+        if (a > 1 && b) {
+            return a;
+        } else if (a == 0 || !b) {
+            return 1;
+        }
+        for (uint256 i = 0; i < a; ++i) {
+            a += i > 2 ? 1 : 2;
+        }
+        return a;
+    }
+
+    function caller(address target) public returns (bool) {
+        try Complexity(target).simple(1) returns (uint256) {
+            return true;
+        } catch Error(string memory) {
+            return false;
+        } catch {
+            return false;
+        }
+    }
+}
@@ -0,0 +1,7 @@
+pragma solidity ^0.8.20;
+pragma solidity ^0.7.0;
+pragma solidity >=0.8.0 <0.9.0;
+pragma solidity 0.8.24;
+pragma abicoder v2;
+
+contract Test {}
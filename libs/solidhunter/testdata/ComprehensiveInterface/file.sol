@@ -0,0 +1,27 @@
+pragma solidity 0.8.0;
+
+interface IToken {
+    function mint() external;
+}
+
+library Math {
+    function add(uint256 a, uint256 b) public pure returns (uint256) {
+        return a + b;
+    }
+}
+
+contract Token is IToken {
+    constructor() {}
+
+    function mint() external override {}
+
+    function burn() external {}
+
+    function balance() public view returns (uint256) {
+        return 0;
+    }
+
+    function _transfer() internal {}
+
+    receive() external payable {}
+}
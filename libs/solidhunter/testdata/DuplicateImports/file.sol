@@ -0,0 +1,10 @@
+pragma solidity ^0.8.0;
+
+import {A} from "./A.sol";
+import { B, C as D } from "./B.sol";
+import {E} from "./lib/../A.sol";
+import { B } from "./B.sol";
+import "./C.sol";
+import "./C.sol";
+
+contract Token is A, B, D, E {}
@@ -0,0 +1,7 @@
+pragma solidity ^0.8.0;
+
+import {A, E} from "./A.sol";
+import { B, C as D } from "./B.sol";
+import "./C.sol";
+
+contract Token is A, B, D, E {}
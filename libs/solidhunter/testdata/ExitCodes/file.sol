@@ -0,0 +1,9 @@
+pragma solidity 0.8.0;
+
+contract Test {
+    function test_a(uint a) public pure returns (uint256) {
+        return a;
+    }
+
+    function test_b() public pure {}
+}
@@ -0,0 +1,10 @@
+pragma solidity 0.8.0;
+
+contract Test {
+    uint256 public constant var1;
+    int256 public constant var2;
+    uint256 public constant var3;
+    int256 public constant var4;
+    uint256 public constant var5 = uint256(1);
+    int8 public constant var6 = int8(1);
+}
\ No newline at end of file
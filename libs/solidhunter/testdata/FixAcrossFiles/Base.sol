@@ -0,0 +1,7 @@
+pragma solidity ^0.8.0;
+
+contract Base {
+    function compute_total(uint256 amount) internal pure returns (uint256) {
+        return amount * 2;
+    }
+}
@@ -0,0 +1,9 @@
+pragma solidity ^0.8.0;
+
+import "./Base.sol";
+
+contract Derived is Base {
+    function total(uint256 amount) public pure returns (uint256) {
+        return compute_total(amount);
+    }
+}
@@ -7,6 +7,10 @@ contract Test {
     }
 
     function test_shbs() public pure returns (uint) {
-        return 1;
+        return compute_total(1) + Math.compute_total(2);
+    }
+
+    function compute_total(uint amount) private pure returns (uint) {
+        return amount;
     }
 }
@@ -0,0 +1,16 @@
+pragma solidity 0.8.0;
+
+contract Test {
+
+    modifier test_suhshus() {
+        _;
+    }
+
+    function test_shbs() public pure returns (uint) {
+        return computeTotal(1) + Math.compute_total(2);
+    }
+
+    function computeTotal(uint amount) private pure returns (uint) {
+        return amount;
+    }
+}
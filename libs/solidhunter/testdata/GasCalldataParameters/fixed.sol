@@ -0,0 +1,30 @@
+pragma solidity ^0.8.0;
+
+contract Test {
+    struct Point {
+        uint256 x;
+        uint256 y;
+    }
+
+    uint256 total;
+
+    function readOnly(uint256[] calldata values, string calldata name) external {
+        total = values.length + bytes(name).length;
+    }
+
+    function modified(uint256[] memory values, Point memory point) external {
+        values[0] = 1;
+        point.x = 2;
+        total = values[0] + point.x;
+    }
+
+    function alreadyCalldata(uint256[] calldata values) external {
+        total = values.length;
+    }
+
+    function notExternal(uint256[] memory values) public {
+        total = values.length;
+    }
+
+    function declaration(uint256[] memory values) external;
+}
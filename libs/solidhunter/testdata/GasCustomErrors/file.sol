@@ -0,0 +1,22 @@
+pragma solidity ^0.8.0;
+
+contract Test {
+    error Unauthorized();
+
+    address owner;
+
+    function withMessage(uint256 amount) public {
+        require(amount > 0, "Amount is zero");
+        if (msg.sender != owner) {
+            revert("Not owner");
+        }
+    }
+
+    function withoutMessage(uint256 amount) public {
+        require(amount > 0);
+        if (msg.sender != owner) {
+            revert Unauthorized();
+        }
+        revert();
+    }
+}
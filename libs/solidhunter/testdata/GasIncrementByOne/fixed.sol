@@ -0,0 +1,27 @@
+pragma solidity ^0.8.0;
+
+contract Test {
+    struct Counter {
+        uint256 count;
+    }
+
+    Counter counter;
+    uint256 nextId;
+    uint256 total;
+
+    function loop(uint256 length) public {
+        for (uint256 i = 0; i < length; ++i) {
+            total += i;
+        }
+        for (uint256 j = length; j > 0; --j) {
+            total += j;
+        }
+    }
+
+    function counters() public returns (uint256 id) {
+        --total;
+        ++counter.count;
+        ++nextId;
+        id = nextId++;
+    }
+}
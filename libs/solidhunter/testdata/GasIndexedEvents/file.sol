@@ -0,0 +1,10 @@
+pragma solidity ^0.8.0;
+
+event Transfer(address indexed from, address indexed to, uint256 value);
+
+contract Test {
+    event Full(address indexed a, address indexed b, uint256 indexed c, uint256 d);
+    event Message(address indexed sender, string text, bytes32 hash);
+    event Anonymous(address indexed a, address indexed b, uint256 indexed c, uint256 d) anonymous;
+    event Unnamed(uint256);
+}
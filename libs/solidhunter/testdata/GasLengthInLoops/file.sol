@@ -0,0 +1,25 @@
+pragma solidity ^0.8.0;
+
+contract Test {
+    struct Data {
+        uint256[] values;
+    }
+
+    uint256[] values;
+    Data data;
+    uint256 total;
+
+    function loops() public {
+        for (uint256 i = 0; i < values.length; ++i) {
+            total += values[i];
+        }
+        uint256 j = 0;
+        while (j < data.values.length && total > 0) {
+            ++j;
+        }
+        uint256 length = values.length;
+        for (uint256 k = 0; k < length; ++k) {
+            total += k;
+        }
+    }
+}
@@ -0,0 +1,15 @@
+pragma solidity ^0.8.0;
+
+contract Test {
+    mapping(address => uint256) balances;
+
+    function named(address owner) external view returns (uint256 amount) {
+        amount = balances[owner];
+    }
+
+    function unnamed(address owner) external view returns (uint256, bool) {
+        return (balances[owner], true);
+    }
+
+    function declaration() external view returns (uint256);
+}
@@ -0,0 +1,35 @@
+pragma solidity ^0.8.0;
+
+enum Status {
+    Active,
+    Closed
+}
+
+type Price is uint128;
+
+struct Unpacked {
+    address owner;
+    uint256 amount;
+    bool active;
+}
+
+contract Test {
+    struct Packed {
+        uint256 amount;
+        address owner;
+        bool active;
+    }
+
+    struct CustomTypes {
+        Price price;
+        uint256 amount;
+        Status status;
+        uint64 timestamp;
+    }
+
+    struct Unknown {
+        IERC20 token;
+        uint256 amount;
+        bool active;
+    }
+}
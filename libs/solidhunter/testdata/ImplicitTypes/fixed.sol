@@ -0,0 +1,10 @@
+pragma solidity 0.8.0;
+
+contract Test {
+    uint public constant var1;
+    int public constant var2;
+    uint public constant var3;
+    int public constant var4;
+    uint public constant var5 = uint(1);
+    int8 public constant var6 = int8(1);
+}
\ No newline at end of file
@@ -0,0 +1,6 @@
+pragma solidity ^0.8.0;
+
+import {Token} from "../tokens/Token.sol";
+import {Lib} from "./Lib.sol";
+
+contract Vault {}
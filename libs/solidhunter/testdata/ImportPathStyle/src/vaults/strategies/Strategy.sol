@@ -0,0 +1,6 @@
+pragma solidity ^0.8.0;
+
+import {Vault} from '../Vault.sol';
+import {Token} from "src/tokens/Token.sol";
+
+contract Strategy {}
@@ -0,0 +1,10 @@
+pragma solidity ^0.8.0;
+
+import {Vault} from "./Vault.sol";
+import {ERC20} from "@openzeppelin/contracts/token/ERC20/ERC20.sol";
+import {Test} from "forge-std/Test.sol";
+import {Base} from "../Base.sol";
+import {Solmate} from "solmate/tokens/ERC20.sol";
+import {Ownable} from "@openzeppelin/contracts/access/Ownable.sol";
+
+contract Token is ERC20, Ownable, Vault, Base, Solmate, Test {}
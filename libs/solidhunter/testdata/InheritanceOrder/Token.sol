@@ -0,0 +1,10 @@
+pragma solidity ^0.8.0;
+
+import {Ownable} from "./Ownable.sol";
+import {Pausable} from "./Pausable.sol";
+
+contract Token is Pausable, Ownable {}
+
+contract Ordered is Ownable, Pausable {}
+
+contract Unrelated is Ownable, Token {}
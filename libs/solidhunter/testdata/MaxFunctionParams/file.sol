@@ -0,0 +1,13 @@
+pragma solidity ^0.8.0;
+
+contract Params {
+    constructor(address a, address b, uint256 c) {}
+
+    function few(uint256 a, uint256 b) public {}
+
+    function tooMany(uint256 a, uint256 b, uint256 c) public {}
+
+    modifier guarded(uint256 a, uint256 b, uint256 c) {
+        _;
+    }
+}
@@ -0,0 +1,9 @@
+pragma solidity ^0.8.0;
+
+import {B} from "./Base.sol";
+
+contract C is B {}
+
+contract D is C {}
+
+contract E is Unknown {}
@@ -0,0 +1,28 @@
+pragma solidity ^0.8.0;
+
+contract Nesting {
+    function chained(uint256 a) public pure returns (uint256) {
+        if (a == 0) {
+            return 0;
+        } else if (a == 1) {
+            if (a > 0) {
+                return 1;
+            }
+        } else if (a == 2) {
+            return 2;
+        }
+        return 3;
+    }
+
+    function deep(uint256 a) public pure returns (uint256) {
+        for (uint256 i = 0; i < a; ++i) {
+            while (a > i) {
+                if (a % 2 == 0) {
+                    a -= 2;
+                }
+                a -= 1;
+            }
+        }
+        return a;
+    }
+}
@@ -0,0 +1,7 @@
+pragma solidity ^0.8.0;
+
+abstract contract Base {
+    function check(uint256 a) internal pure returns (bool) {
+        return a > 0 && a < 10;
+    }
+}
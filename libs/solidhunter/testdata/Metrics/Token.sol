@@ -0,0 +1,21 @@
+pragma solidity ^0.8.0;
+
+import {Base} from "./Base.sol";
+
+contract Token is Base {
+    uint256 public total;
+
+    constructor(uint256 initial) {
+        total = initial;
+    }
+
+    function mint(uint256 amount, address to) external {
+        if (check(amount)) {
+            for (uint256 i = 0; i < amount; ++i) {
+                if (to != address(0)) {
+                    total += 1;
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,15 @@
+pragma solidity 0.8.0;
+
+contract Test {
+    function payOne(address payable to) public {
+        require(to.send(1));
+    }
+
+    function payAll(address payable alice, address payable bob) public {
+        require(alice.send(1));
+        require(bob.send(1));
+        if (!alice.send(2)) {
+            revert();
+        }
+    }
+}
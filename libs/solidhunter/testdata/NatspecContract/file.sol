@@ -0,0 +1,17 @@
+pragma solidity ^0.8.0;
+
+/// @title Documented
+/// @author Osmium
+contract Documented {}
+
+/**
+ * @title Without author
+ */
+abstract contract WithoutAuthor {}
+
+// @title Plain comment
+library Undocumented {}
+
+/// @author Osmium
+/// Interface without title
+interface WithoutTitle {}
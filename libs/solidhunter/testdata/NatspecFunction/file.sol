@@ -0,0 +1,44 @@
+pragma solidity ^0.8.0;
+
+interface IToken {
+    /// @notice Transfers tokens
+    /// @param to The receiver
+    /// @param amount The amount of tokens
+    /// @return Whether the transfer succeeded
+    function transfer(address to, uint256 amount) external returns (bool);
+}
+
+contract Token is IToken {
+    mapping(address => uint256) balances;
+
+    /// @inheritdoc IToken
+    function transfer(address to, uint256 amount) external override returns (bool) {
+        balances[to] += amount;
+        return true;
+    }
+
+    /**
+     * @notice Mints tokens
+     * @param receiver The receiver
+     * @param amount The amount of tokens
+     */
+    function mint(address to, uint256 amount) public {
+        balances[to] += amount;
+    }
+
+    /// @notice Reads a balance
+    /// @return balance The balance
+    /// @return Something else
+    function balanceOf(address owner) external view returns (uint256 balance) {
+        balance = balances[owner];
+    }
+
+    /// @dev Only for developers
+    function burn() external returns (uint256, bool) {
+        return (0, true);
+    }
+
+    function undocumented() public {}
+
+    function internalFunction(uint256 amount) internal {}
+}
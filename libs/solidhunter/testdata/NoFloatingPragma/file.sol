@@ -0,0 +1,5 @@
+pragma solidity ^0.8.20;
+pragma solidity >= 0.8.0;
+pragma solidity 0.8.24;
+
+contract Test {}
@@ -0,0 +1,14 @@
+pragma solidity 0.8.0;
+
+import "./Plain.sol";
+import {A} from "./A.sol";
+import {B, C as D} from "./B.sol";
+import {E} from "./E.sol";
+import * as Lib from "./Lib.sol";
+import "./F.sol" as F;
+
+contract Test is A {
+    function get() public pure returns (uint256) {
+        return Lib.value();
+    }
+}
@@ -0,0 +1,13 @@
+pragma solidity ^0.8.0;
+
+import {A, B} from "./A.sol";
+import {C as D, E} from "./C.sol";
+import {F} from "./F.sol";
+import "./G.sol" as G;
+
+contract Token is A, E {
+    function f() public pure returns (uint256) {
+        // B and D are only named in comments
+        return G.value();
+    }
+}
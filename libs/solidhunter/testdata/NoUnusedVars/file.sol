@@ -0,0 +1,33 @@
+pragma solidity 0.8.0;
+
+contract Test {
+    uint256 public total;
+
+    modifier onlyAbove(uint256 value) {
+        require(value > 0);
+        _;
+    }
+
+    function add(uint256 a, uint256 b) public pure returns (uint256) {
+        uint256 sum = a + b;
+        return sum;
+    }
+
+    function double(uint256 a, uint256 b) public pure returns (uint256) {
+        uint256 unused = 2;
+        return a * 2;
+    }
+
+    function deposit(uint256 amount, address) public onlyAbove(amount) {
+        (uint256 x, uint256 y) = (1, 2);
+        total += x;
+    }
+
+    function loop(uint256 count) public {
+        for (uint256 i = 0; i < count; i++) {
+            assembly {
+                sstore(0, i)
+            }
+        }
+    }
+}
@@ -28,4 +28,18 @@ contract Test {
     function _thisIsInternal() internal returns (uint256 _bar) {}
     function _thisIsPrivate() private returns (uint256 _bar) {}
     function _thisIsPrivate() returns (uint256 _bar) {}
+}
+
+contract Vault {
+    uint256 private balance;
+    uint256 public total;
+
+    function deposit(uint256 _amount) public {
+        balance += _amount;
+        total = vault.balance;
+    }
+
+    function report(uint256 _share) public pure returns (uint256 balance) {
+        balance = _share;
+    }
 }
\ No newline at end of file
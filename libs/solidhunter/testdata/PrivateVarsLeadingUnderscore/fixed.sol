@@ -0,0 +1,45 @@
+pragma solidity 0.8.0;
+
+contract Test {
+    function _thisIsInternal() internal {}
+    function _thisIsPrivate() private {}
+    function _thisIsPrivate() {}
+    uint256 internal _thisIsInternalVariable;
+    uint256 _thisIsInternalVariable;
+
+    function _thisIsInternal() internal {}
+    function _thisIsPrivate() private {}
+    function _thisIsPrivate() {}
+    uint256 internal _thisIsInternalVariable;
+    uint256 _thisIsInternalVariable;
+
+    function _thisIsInternal(uint _test) internal {}
+    function _thisIsPrivate(uint _test) private {}
+    function _thisIsPrivate(uint _test) {}
+
+    function _thisIsInternal(uint _test) internal {}
+    function _thisIsPrivate(uint _test) private {}
+    function _thisIsPrivate(uint _test) {}
+
+    function _thisIsInternal() internal returns (uint256 _bar) {}
+    function _thisIsPrivate() private returns (uint256 _bar) {}
+    function _thisIsPrivate() returns (uint256 _bar) {}
+
+    function _thisIsInternal() internal returns (uint256 _bar) {}
+    function _thisIsPrivate() private returns (uint256 _bar) {}
+    function _thisIsPrivate() returns (uint256 _bar) {}
+}
+
+contract Vault {
+    uint256 private _balance;
+    uint256 public total;
+
+    function deposit(uint256 _amount) public {
+        _balance += _amount;
+        total = vault.balance;
+    }
+
+    function report(uint256 _share) public pure returns (uint256 _balance) {
+        _balance = _share;
+    }
+}
\ No newline at end of file
@@ -0,0 +1,12 @@
+pragma solidity 0.8.0;
+
+contract Test {
+    string public name = "Token";
+    string public symbol = "TKN";
+    // 'ignored' in comments
+    string public message = 'Say "hello"';
+
+    function greet() public pure returns (string memory) {
+        return "hello";
+    }
+}
@@ -0,0 +1,69 @@
+pragma solidity 0.8.0;
+
+interface IERC20 {
+    function transfer(address to, uint256 amount) external returns (bool);
+}
+
+contract Vault {
+    struct Position {
+        uint256 amount;
+    }
+
+    mapping(address => uint256) public balances;
+    mapping(address => Position) public positions;
+    IERC20 public immutable token;
+    uint256 public total;
+    address[] public holders;
+
+    modifier lock() {
+        _;
+    }
+
+    function withdraw() public {
+        uint256 amount = balances[msg.sender];
+        (bool success, ) = msg.sender.call{value: amount}("");
+        require(success);
+        balances[msg.sender] = 0;
+    }
+
+    function safeWithdraw() public {
+        uint256 amount = balances[msg.sender];
+        balances[msg.sender] = 0;
+        (bool success, ) = msg.sender.call{value: amount}("");
+        require(success);
+    }
+
+    function guarded() public lock {
+        token.transfer(msg.sender, 1);
+        total -= 1;
+    }
+
+    function branches(bool pay) public {
+        if (pay) {
+            token.transfer(msg.sender, 1);
+        } else {
+            total += 1;
+        }
+        positions[msg.sender].amount = 0;
+    }
+
+    function loop(address[] memory users) public {
+        for (uint256 i = 0; i < users.length; i++) {
+            holders.push(users[i]);
+            IERC20(users[i]).transfer(users[i], 1);
+        }
+    }
+
+    function shadowed() public {
+        payable(msg.sender).transfer(1);
+        uint256 total = 2;
+        total++;
+        delete holders;
+    }
+
+    function internalCalls(Position memory position) public {
+        holders.push(msg.sender);
+        position.amount = 1;
+        total = position.amount;
+    }
+}
@@ -0,0 +1,6 @@
+pragma solidity ^0.8.0;
+
+import {IToken} from "./IToken.sol";
+import {LibMath} from "UnusedFile/LibMath.sol";
+
+contract Token is IToken {}
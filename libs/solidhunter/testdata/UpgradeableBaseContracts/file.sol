@@ -0,0 +1,13 @@
+pragma solidity 0.8.19;
+
+abstract contract Initializable {}
+
+abstract contract ProxyStorage {}
+
+contract Vault is Initializable {
+    uint256 public fee = 100;
+}
+
+contract Treasury is ProxyStorage {
+    uint256 public fee = 100;
+}
@@ -0,0 +1,36 @@
+pragma solidity 0.8.19;
+
+abstract contract Initializable {
+    function _disableInitializers() internal {}
+}
+
+contract Owned {
+    address public owner;
+
+    constructor() {
+        owner = msg.sender;
+    }
+}
+
+contract Vault is Initializable {
+    constructor() {
+        _disableInitializers();
+    }
+}
+
+contract Treasury is Initializable {
+    address public owner;
+
+    constructor() {
+        _disableInitializers();
+        owner = msg.sender;
+    }
+}
+
+abstract contract FeesUpgradeable {
+    uint256 public fee;
+
+    constructor(uint256 fee_) {
+        fee = fee_;
+    }
+}
@@ -0,0 +1,29 @@
+pragma solidity 0.8.19;
+
+abstract contract Initializable {
+    function _disableInitializers() internal {}
+}
+
+abstract contract OwnableUpgradeable is Initializable {
+    address public owner;
+}
+
+contract Plain {
+    uint256 public value;
+}
+
+contract Vault is OwnableUpgradeable {
+    constructor() {
+        _disableInitializers();
+    }
+}
+
+contract Treasury is OwnableUpgradeable {
+    function initialize() external {
+        owner = msg.sender;
+    }
+}
+
+contract Staking is Initializable {
+    constructor() {}
+}
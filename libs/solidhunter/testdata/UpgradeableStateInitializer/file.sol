@@ -0,0 +1,20 @@
+pragma solidity 0.8.19;
+
+abstract contract Initializable {
+    function _disableInitializers() internal {}
+}
+
+contract Plain {
+    uint256 public fee = 100;
+}
+
+contract Vault is Initializable {
+    uint256 public constant MAX_FEE = 1000;
+    address public immutable token = address(0);
+    uint256 public fee = 100;
+    address public owner;
+
+    constructor() {
+        _disableInitializers();
+    }
+}
@@ -0,0 +1,24 @@
+pragma solidity 0.8.19;
+
+abstract contract Initializable {
+    function _disableInitializers() internal {}
+}
+
+abstract contract Base {
+    uint256 public value;
+}
+
+abstract contract FeesUpgradeable is Initializable {
+    uint256 public fee;
+    uint256[49] private __gap;
+}
+
+abstract contract OwnableUpgradeable is Initializable {
+    address public owner;
+}
+
+contract Vault is FeesUpgradeable, OwnableUpgradeable {
+    constructor() {
+        _disableInitializers();
+    }
+}
@@ -0,0 +1,30 @@
+pragma solidity 0.8.19;
+
+abstract contract Initializable {
+    function _disableInitializers() internal {}
+}
+
+contract Plain {
+    function kill() external {
+        selfdestruct(payable(msg.sender));
+    }
+}
+
+contract Vault is Initializable {
+    constructor() {
+        _disableInitializers();
+    }
+
+    function execute(address target, bytes calldata data) external {
+        (bool success, ) = target.delegatecall(data);
+        require(success);
+    }
+
+    function kill() external {
+        selfdestruct(payable(msg.sender));
+    }
+
+    function forward(address target, bytes calldata data) external {
+        target.call(data);
+    }
+}
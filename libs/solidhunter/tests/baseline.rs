@@ -0,0 +1,81 @@
+use solidhunter::baseline::Baseline;
+use solidhunter::linter::SolidLinter;
+
+const CONFIG: &str = r#"{
+  "name": "solidhunter",
+  "rules": [
+    { "id": "func-name-mixedcase", "severity": "WARNING" }
+  ]
+}"#;
+
+const SOURCE: &str = "pragma solidity 0.8.0;
+
+contract Test {
+    function test_a() public {}
+}
+";
+
+const SOURCE_WITH_NEW_FINDING: &str = "pragma solidity 0.8.0;
+
+contract Test {
+    function test_b() public {}
+
+    function test_a() public {}
+}
+";
+
+fn create_linter() -> SolidLinter {
+    let mut linter = SolidLinter::new();
+    linter.initialize_rules_content(CONFIG).unwrap();
+    linter
+}
+
+fn baseline_path(name: &str) -> String {
+    let dir = std::env::temp_dir().join(format!("solidhunter-baseline-{}", name));
+    std::fs::create_dir_all(&dir).unwrap();
+    dir.join(".solidhunter-baseline.json")
+        .to_string_lossy()
+        .to_string()
+}
+
+#[test]
+fn baseline_suppresses_existing_findings() {
+    let mut linter = create_linter();
+    let path = baseline_path("existing");
+    let results = vec![linter.parse_content("src/Test.sol", SOURCE)];
+    Baseline::from_results(&path, &results).save(&path).unwrap();
+
+    linter.initialize_baseline(&path).unwrap();
+    let diags = linter.parse_content("src/Test.sol", SOURCE).unwrap();
+
+    assert!(diags.diags.is_empty());
+}
+
+#[test]
+fn baseline_reports_new_findings_after_lines_moved() {
+    let mut linter = create_linter();
+    let path = baseline_path("moved");
+    let results = vec![linter.parse_content("src/Test.sol", SOURCE)];
+    Baseline::from_results(&path, &results).save(&path).unwrap();
+
+    linter.initialize_baseline(&path).unwrap();
+    let diags = linter
+        .parse_content("src/Test.sol", SOURCE_WITH_NEW_FINDING)
+        .unwrap();
+
+    assert_eq!(diags.diags.len(), 1);
+    assert_eq!(diags.diags[0].range.start.line, 4);
+}
+
+#[test]
+fn baseline_is_scoped_to_its_file() {
+    let mut linter = create_linter();
+    let path = baseline_path("scoped");
+    let results = vec![linter.parse_content("src/Test.sol", SOURCE)];
+    Baseline::from_results(&path, &results).save(&path).unwrap();
+
+    linter.initialize_baseline(&path).unwrap();
+    let diags = linter.parse_content("src/Other.sol", SOURCE).unwrap();
+
+    assert_eq!(diags.diags.len(), 1);
+}
@@ -0,0 +1,110 @@
+use solidhunter::linter::SolidLinter;
+use std::path::{Path, PathBuf};
+
+const CONFIG: &str = r#"{
+  "name": "solidhunter",
+  "rules": [
+    { "id": "func-name-mixedcase", "severity": "WARNING" }
+  ]
+}"#;
+
+const OTHER_CONFIG: &str = r#"{
+  "name": "solidhunter",
+  "rules": [
+    { "id": "func-name-mixedcase", "severity": "ERROR" }
+  ]
+}"#;
+
+const SOURCE: &str = "pragma solidity 0.8.0;
+
+contract Test {
+    function test_a() public {}
+}
+";
+
+fn create_project(name: &str) -> (PathBuf, String) {
+    let dir = std::env::temp_dir().join(format!("solidhunter-cache-{}", name));
+    let _ = std::fs::remove_dir_all(&dir);
+    std::fs::create_dir_all(dir.join("src")).unwrap();
+    for i in 0..8 {
+        std::fs::write(dir.join("src").join(format!("Test{}.sol", i)), SOURCE).unwrap();
+    }
+    let cache = dir.join(".solidhunter-cache").to_string_lossy().to_string();
+    (dir, cache)
+}
+
+fn lint(config: &str, dir: &Path, cache: &str) -> Vec<String> {
+    let mut linter = SolidLinter::new();
+    linter.initialize_rules_content(config).unwrap();
+    linter.initialize_cache(cache);
+
+    let results = linter.parse_folder(dir.to_str().unwrap());
+    linter.save_cache().unwrap();
+    results
+        .into_iter()
+        .flat_map(|result| result.unwrap().diags)
+        .map(|diag| diag.message)
+        .collect()
+}
+
+// Marks the cached diagnostics so that the results coming from the cache can be told apart
+fn tamper_cache(cache: &str) {
+    let content = std::fs::read_to_string(cache).unwrap();
+    std::fs::write(cache, content.replace("mixedCase", "cached")).unwrap();
+}
+
+#[test]
+fn parse_folder_lints_every_file() {
+    let (dir, cache) = create_project("every-file");
+
+    let messages = lint(CONFIG, &dir, &cache);
+
+    assert_eq!(messages.len(), 8);
+}
+
+#[test]
+fn cache_skips_unchanged_files() {
+    let (dir, cache) = create_project("unchanged");
+    lint(CONFIG, &dir, &cache);
+    tamper_cache(&cache);
+    std::fs::write(
+        dir.join("src").join("Test0.sol"),
+        SOURCE.replace("test_a", "test_b"),
+    )
+    .unwrap();
+
+    let messages = lint(CONFIG, &dir, &cache);
+
+    assert_eq!(messages.len(), 8);
+    assert_eq!(
+        messages
+            .iter()
+            .filter(|message| message.contains("cached"))
+            .count(),
+        7
+    );
+}
+
+#[test]
+fn cache_is_invalidated_by_config_changes() {
+    let (dir, cache) = create_project("config");
+    lint(CONFIG, &dir, &cache);
+    tamper_cache(&cache);
+
+    let messages = lint(OTHER_CONFIG, &dir, &cache);
+
+    assert!(messages.iter().all(|message| !message.contains("cached")));
+}
+
+#[test]
+fn cache_is_invalidated_by_remappings_changes() {
+    let (dir, cache) = create_project("remappings");
+    std::fs::write(dir.join("remappings.txt"), "@lib/=lib/\n").unwrap();
+    lint(CONFIG, &dir, &cache);
+    tamper_cache(&cache);
+    std::fs::write(dir.join("remappings.txt"), "@lib/=node_modules/lib/\n").unwrap();
+
+    let messages = lint(CONFIG, &dir, &cache);
+
+    assert!(messages.iter().all(|message| !message.contains("cached")));
+}
@@ -0,0 +1,54 @@
+use std::path::PathBuf;
+use std::process::Command;
+
+fn run_solidhunter(args: &[&str]) -> i32 {
+    let path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("testdata")
+        .join("ExitCodes");
+    let output = Command::new(env!("CARGO_BIN_EXE_solidhunter"))
+        .arg(path.to_str().unwrap())
+        .args(args)
+        .output()
+        .unwrap();
+
+    output.status.code().unwrap()
+}
+
+#[test]
+fn exits_successfully_with_only_warnings() {
+    assert_eq!(run_solidhunter(&[]), 0);
+}
+
+#[test]
+fn exits_with_failure_on_errors() {
+    assert_eq!(run_solidhunter(&["-r", "errors.solidhunter.json"]), 1);
+}
+
+#[test]
+fn exits_with_failure_above_max_warnings() {
+    assert_eq!(run_solidhunter(&["--max-warnings", "2"]), 0);
+    assert_eq!(run_solidhunter(&["--max-warnings", "1"]), 1);
+}
+
+#[test]
+fn exits_with_failure_on_denied_warnings() {
+    assert_eq!(run_solidhunter(&["--deny", "warnings"]), 1);
+}
+
+#[test]
+fn exits_with_config_error_on_missing_rules_file() {
+    assert_eq!(run_solidhunter(&["-r", "missing.solidhunter.json"]), 2);
+}
+
+#[test]
+fn exits_with_config_error_on_unknown_rule() {
+    assert_eq!(run_solidhunter(&["-r", "unknown.solidhunter.json"]), 2);
+}
+
+#[test]
+fn prints_metrics_without_rules_file() {
+    assert_eq!(
+        run_solidhunter(&["--metrics", "-r", "missing.solidhunter.json"]),
+        0
+    );
+}
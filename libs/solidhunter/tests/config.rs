@@ -0,0 +1,319 @@
+use solidhunter::errors::{RuleConfigError, SolidHunterError};
+use solidhunter::linter::SolidLinter;
+use solidhunter::rules::create_default_rules;
+use solidhunter::rules::presets::{
+    create_preset_rules, ALL_PRESET, GAS_PRESET, NATSPEC_PRESET, RECOMMENDED_PRESET,
+    RECOMMENDED_RULES, SECURITY_PRESET,
+};
+use solidhunter::rules::rule_impl::{parse_rules, parse_rules_content};
+use solidhunter::rules::types::Rules;
+use solidhunter::types::Severity;
+
+fn config_errors(config: &str) -> Vec<RuleConfigError> {
+    let mut linter = SolidLinter::new();
+    match linter.initialize_rules_content(config) {
+        Err(SolidHunterError::RulesConfigError { errors, .. }) => errors,
+        other => panic!("Expected a rules configuration error, got {:?}", other),
+    }
+}
+
+#[test]
+fn default_rules_are_valid() {
+    let rules = Rules {
+        name: "solidhunter".to_string(),
+        extends: vec![],
+        rules: create_default_rules(),
+        overrides: vec![],
+    };
+    let mut linter = SolidLinter::new();
+
+    let res = linter.initialize_rules_content(&serde_json::to_string(&rules).unwrap());
+
+    assert!(res.is_ok(), "{:?}", res);
+}
+
+#[test]
+fn unknown_rule_is_reported_with_suggestion() {
+    let errors = config_errors(
+        r#"{
+          "name": "solidhunter",
+          "rules": [
+            { "id": "func-name-mixedcase", "severity": "WARNING" },
+            { "id": "func-name-mixecase", "severity": "WARNING" },
+            { "id": "completely-unknown-rule", "severity": "WARNING" }
+          ]
+        }"#,
+    );
+
+    assert_eq!(
+        errors,
+        vec![
+            RuleConfigError::UnknownRule {
+                path: "rules[1]".to_string(),
+                id: "func-name-mixecase".to_string(),
+                suggestion: Some("func-name-mixedcase".to_string()),
+            },
+            RuleConfigError::UnknownRule {
+                path: "rules[2]".to_string(),
+                id: "completely-unknown-rule".to_string(),
+                suggestion: None,
+            },
+        ]
+    );
+}
+
+#[test]
+fn invalid_option_type_is_reported() {
+    let errors = config_errors(
+        r#"{
+          "name": "solidhunter",
+          "rules": [
+            { "id": "max-line-length", "severity": "WARNING", "data": "80" },
+            { "id": "func-visibility", "severity": "WARNING", "data": { "ignoreConstructors": 1 } },
+            { "id": "private-vars-leading-underscore", "severity": "WARNING", "data": { "stricts": true } }
+          ]
+        }"#,
+    );
+
+    assert_eq!(errors.len(), 3);
+    assert_eq!(
+        errors[0],
+        RuleConfigError::InvalidOption {
+            path: "rules[0].data".to_string(),
+            id: "max-line-length".to_string(),
+            expected: "a positive integer".to_string(),
+            found: "a string".to_string(),
+        }
+    );
+    assert_eq!(
+        errors[1],
+        RuleConfigError::InvalidOption {
+            path: "rules[1].data.ignoreConstructors".to_string(),
+            id: "func-visibility".to_string(),
+            expected: "a boolean".to_string(),
+            found: "a positive integer".to_string(),
+        }
+    );
+    assert_eq!(
+        errors[2],
+        RuleConfigError::InvalidOption {
+            path: "rules[2].data.stricts".to_string(),
+            id: "private-vars-leading-underscore".to_string(),
+            expected: "one of the properties \"strict\"".to_string(),
+            found: "\"stricts\"".to_string(),
+        }
+    );
+}
+
+#[test]
+fn valid_options_are_accepted() {
+    let mut linter = SolidLinter::new();
+
+    let res = linter.initialize_rules_content(
+        r#"{
+          "name": "solidhunter",
+          "rules": [
+            { "id": "max-line-length", "severity": "WARNING", "data": 80 },
+            { "id": "explicit-types", "severity": "WARNING", "data": "implicit" },
+            { "id": "foundry-test-functions", "severity": "WARNING", "data": ["setUp", "run"] }
+          ]
+        }"#,
+    );
+
+    assert!(res.is_ok(), "{:?}", res);
+}
+
+fn testdata_path(path: &str) -> String {
+    format!("{}/testdata/Extends/{}", env!("CARGO_MANIFEST_DIR"), path)
+}
+
+#[test]
+fn extends_merges_rules_per_id() {
+    let rules = parse_rules(&testdata_path("package/.solidhunter.json")).unwrap();
+
+    let security_rules = create_preset_rules(SECURITY_PRESET).unwrap();
+    assert_eq!(rules.name, "package");
+    assert!(rules.extends.is_empty());
+    assert_eq!(rules.rules.len(), security_rules.len() + 2);
+
+    let max_line_length = rules
+        .rules
+        .iter()
+        .find(|rule| rule.id == "max-line-length")
+        .unwrap();
+    assert_eq!(max_line_length.severity, Severity::ERROR);
+    assert_eq!(max_line_length.data, Some(100.into()));
+
+    let avoid_tx_origin = rules
+        .rules
+        .iter()
+        .find(|rule| rule.id == "avoid-tx-origin")
+        .unwrap();
+    assert_eq!(avoid_tx_origin.severity, Severity::INFO);
+}
+
+#[test]
+fn extends_recommended_preset_enables_recommended_rules() {
+    let rules = parse_rules_content(
+        r#"{ "name": "solidhunter", "extends": ["solidhunter:recommended"], "rules": [] }"#,
+    )
+    .unwrap();
+
+    assert_eq!(rules.rules.len(), RECOMMENDED_RULES.len());
+    assert!(rules
+        .rules
+        .iter()
+        .all(|rule| RECOMMENDED_RULES.contains(&rule.id.as_str())));
+}
+
+#[test]
+fn rule_packs_are_off_unless_extended() {
+    let is_pack_rule = |id: &str| {
+        id.starts_with("natspec-")
+            || id.starts_with("upgradeable-")
+            || [
+                "comprehensive-interface",
+                "no-floating-pragma",
+                "unused-file",
+            ]
+            .contains(&id)
+    };
+
+    let recommended = create_preset_rules(RECOMMENDED_PRESET).unwrap();
+    assert!(!recommended.iter().any(|rule| is_pack_rule(&rule.id)));
+
+    let natspec = create_preset_rules(NATSPEC_PRESET).unwrap();
+    assert!(natspec.iter().all(|rule| rule.id.starts_with("natspec-")));
+    let all = create_preset_rules(ALL_PRESET).unwrap();
+    assert_eq!(all.iter().filter(|rule| is_pack_rule(&rule.id)).count(), 10);
+}
+
+#[test]
+fn gas_rules_are_off_unless_extended() {
+    let is_gas_rule = |id: &str| id.starts_with("gas-");
+
+    let recommended = create_preset_rules(RECOMMENDED_PRESET).unwrap();
+    assert!(recommended
+        .iter()
+        .filter(|rule| is_gas_rule(&rule.id))
+        .all(|rule| rule.severity == Severity::OFF));
+
+    for preset in [ALL_PRESET, GAS_PRESET] {
+        let rules = parse_rules_content(&format!(
+            r#"{{ "name": "solidhunter", "extends": ["{}"], "rules": [] }}"#,
+            preset
+        ))
+        .unwrap();
+        let gas_rules: Vec<_> = rules
+            .rules
+            .iter()
+            .filter(|rule| is_gas_rule(&rule.id))
+            .collect();
+        assert_eq!(gas_rules.len(), 7, "{}", preset);
+        assert!(gas_rules.iter().all(|rule| rule.severity == Severity::INFO));
+    }
+}
+
+#[test]
+fn extends_unknown_preset_is_reported() {
+    let res = parse_rules_content(
+        r#"{ "name": "solidhunter", "extends": ["solidhunter:recomended"], "rules": [] }"#,
+    );
+
+    match res {
+        Err(SolidHunterError::RulesConfigError { errors, .. }) => assert!(matches!(
+            &errors[0],
+            RuleConfigError::InvalidExtends { path, .. } if path == "extends[0]"
+        )),
+        other => panic!("Expected a rules configuration error, got {:?}", other),
+    }
+}
+
+#[test]
+fn extends_cycle_is_reported() {
+    let res = parse_rules(&testdata_path("cycle.solidhunter.json"));
+
+    assert!(matches!(
+        res,
+        Err(SolidHunterError::RulesConfigError { .. })
+    ));
+}
+
+const OVERRIDES_CONFIG: &str = r#"{
+  "name": "solidhunter",
+  "rules": [
+    { "id": "func-name-mixedcase", "severity": "WARNING" },
+    { "id": "max-line-length", "severity": "WARNING", "data": 40 }
+  ],
+  "overrides": [
+    {
+      "files": ["test/**", "script/**"],
+      "rules": [
+        { "id": "func-name-mixedcase", "severity": "OFF" },
+        { "id": "max-line-length", "severity": "ERROR" }
+      ]
+    },
+    {
+      "files": ["script/**"],
+      "rules": [{ "id": "max-line-length", "severity": "ERROR", "data": 120 }]
+    }
+  ]
+}"#;
+
+const OVERRIDES_SOURCE: &str = "pragma solidity 0.8.0;
+
+contract Test {
+    function test_a() public pure returns (uint256) {}
+}
+";
+
+fn lint_ids(linter: &mut SolidLinter, path: &str) -> Vec<(String, Severity)> {
+    let diags = linter.parse_content(path, OVERRIDES_SOURCE).unwrap();
+    let mut ids: Vec<(String, Severity)> = diags
+        .diags
+        .into_iter()
+        .map(|diag| (diag.id, diag.severity))
+        .collect();
+    ids.sort_by(|a, b| a.0.cmp(&b.0));
+    ids
+}
+
+#[test]
+fn overrides_apply_to_matching_paths() {
+    let mut linter = SolidLinter::new();
+    linter.initialize_rules_content(OVERRIDES_CONFIG).unwrap();
+
+    assert_eq!(
+        lint_ids(&mut linter, "src/Test.sol"),
+        vec![
+            ("func-name-mixedcase".to_string(), Severity::WARNING),
+            ("max-line-length".to_string(), Severity::WARNING),
+        ]
+    );
+    assert_eq!(
+        lint_ids(&mut linter, "test/Test.t.sol"),
+        vec![("max-line-length".to_string(), Severity::ERROR)]
+    );
+    assert_eq!(lint_ids(&mut linter, "script/Deploy.s.sol"), vec![]);
+}
+
+#[test]
+fn overrides_invalid_pattern_is_reported() {
+    let errors = config_errors(
+        r#"{
+          "name": "solidhunter",
+          "rules": [],
+          "overrides": [{ "files": ["test/***"], "rules": [{ "id": "no-consol", "severity": "OFF" }] }]
+        }"#,
+    );
+
+    assert_eq!(errors.len(), 2);
+    assert!(matches!(
+        &errors[0],
+        RuleConfigError::InvalidPattern { path, .. } if path == "overrides[0].files[0]"
+    ));
+    assert!(matches!(
+        &errors[1],
+        RuleConfigError::UnknownRule { path, .. } if path == "overrides[0].rules[0]"
+    ));
+}
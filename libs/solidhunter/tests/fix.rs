@@ -0,0 +1,73 @@
+use solidhunter::linter::SolidLinter;
+use std::{fs, path::PathBuf};
+
+fn test_fix_directory(base_name: &str) {
+    let path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("testdata")
+        .join(base_name);
+    let mut linter: SolidLinter = SolidLinter::new();
+    let _ = linter.initialize_rules(path.join(".solidhunter.json").to_str().unwrap());
+
+    let source = path.join("file.sol").to_str().unwrap().to_string();
+    let expected = fs::read_to_string(path.join("fixed.sol")).unwrap();
+
+    match linter.fix_file(source) {
+        Ok(fixed) => {
+            assert!(!fixed.applied.is_empty(), "No fix was applied");
+            assert_eq!(fixed.diags.source_file_content, expected);
+        }
+        Err(e) => {
+            panic!("{}", e);
+        }
+    }
+}
+
+macro_rules! test_fix_directories {
+    ($($dir:ident),+ $(,)?) => {$(
+        #[allow(non_snake_case)]
+        #[test]
+        fn $dir() {
+            test_fix_directory(stringify!($dir));
+        }
+    )+};
+}
+
+test_fix_directories! {
+    FunctionNameMixedCase,
+    VisibilityModifierOrder,
+    PrivateVarsLeadingUnderscore,
+    ExplicitTypes,
+    ImplicitTypes,
+    Quotes,
+    GasCalldataParameters,
+    GasIncrementByOne,
+    ImportsOrder,
+    DuplicateImports,
+}
+
+#[test]
+fn internal_function_used_in_another_file_is_not_renamed() {
+    let path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("testdata")
+        .join("FixAcrossFiles");
+    let config = path.join(".solidhunter.json");
+    let base = path.join("Base.sol").to_str().unwrap().to_string();
+
+    // Alone, the function looks unused outside of its contract and is renamed
+    let mut linter = SolidLinter::new();
+    let _ = linter.initialize_rules(config.to_str().unwrap());
+    let fixed = linter.fix_file(base).unwrap();
+    assert!(fixed
+        .diags
+        .source_file_content
+        .contains("function computeTotal("));
+
+    // The derived contract of Derived.sol calls it, renaming it would break that file
+    let mut linter = SolidLinter::new();
+    let _ = linter.initialize_rules(config.to_str().unwrap());
+    for fixed in linter.fix_path(path.to_str().unwrap()) {
+        let fixed = fixed.unwrap();
+        assert!(fixed.applied.is_empty(), "{} was fixed", fixed.path);
+        assert_eq!(fixed.diags.source_file_content, fixed.original_content);
+    }
+}
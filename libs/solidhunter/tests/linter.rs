@@ -173,6 +173,104 @@ test_directories! {
     FoundryTestFunctions,
     AvoidTxOrigin,
     SolidHunterIgnoreRule,
+    Quotes,
+    NoUnusedVars,
+    NoUnusedImport,
+    ComprehensiveInterface,
+    AvoidLowLevelCalls,
+    AvoidSelfdestruct,
+    AvoidSuicide,
+    AvoidSha3,
+    AvoidThrow,
+    AvoidCallValue,
+    CheckSendResult,
+    MultipleSends,
+    Reentrancy,
+    CompilerVersion,
+    NoFloatingPragma,
+    NoFloatingPragmaInterface,
+    GasCustomErrors,
+    GasCalldataParameters,
+    GasIncrementByOne,
+    GasLengthInLoops,
+    GasStructPacking,
+    GasIndexedEvents,
+    GasNamedReturnValues,
+    NatspecFunction,
+    NatspecContract,
+    ImportsOrder,
+    DuplicateImports,
+    NoUnusedImportSymbols,
+    CodeComplexity,
+    MaxNestingDepth,
+    MaxFunctionParams,
+    UpgradeableConstructor,
+    UpgradeableDisableInitializers,
+    UpgradeableStateInitializer,
+    UpgradeableUnsafeOperations,
+    UpgradeableStorageGap,
+    UpgradeableBaseContracts,
+}
+
+// Lints every file of the directory together, findings are prefixed by the file they are in
+fn test_project_directory(base_name: &str) {
+    let path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("testdata")
+        .join(base_name);
+    let mut linter: SolidLinter = SolidLinter::new();
+    let _ = linter.initialize_rules(&String::from(
+        path.join(".solidhunter.json").to_str().unwrap(),
+    ));
+
+    let mut expected_findings: Vec<String> = fs::read_to_string(path.join("findings.csv"))
+        .unwrap()
+        .lines()
+        .map(|line| line.to_string())
+        .collect();
+    let mut found_findings: Vec<String> = Vec::new();
+
+    for lint_result in linter.parse_path(path.to_str().unwrap()) {
+        match lint_result {
+            Ok(file_diags) => {
+                for diag in file_diags.diags.iter() {
+                    let file = PathBuf::from(&diag.uri);
+                    let file = file.strip_prefix(&path).unwrap().to_str().unwrap();
+                    found_findings.push(format!(
+                        "{}:{}:{}:{}:{}:{}",
+                        file.replace('\\', "/"),
+                        diag.id,
+                        diag.range.start.line,
+                        diag.range.start.character,
+                        diag.range.end.line,
+                        diag.range.end.character
+                    ));
+                }
+            }
+            Err(e) => panic!("{}", e),
+        }
+    }
+    expected_findings.sort();
+    found_findings.sort();
+    assert_eq!(found_findings, expected_findings);
+}
+
+macro_rules! test_project_directories {
+    ($($dir:ident),+ $(,)?) => {$(
+        #[allow(non_snake_case)]
+        #[test]
+        fn $dir() {
+            test_project_directory(stringify!($dir));
+        }
+    )+};
+}
+
+test_project_directories! {
+    DuplicateContractName,
+    UnusedFile,
+    CircularImports,
+    InheritanceOrder,
+    ImportPathStyle,
+    MaxInheritanceDepth,
 }
 
 #[allow(non_snake_case)]
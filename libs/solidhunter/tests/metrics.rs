@@ -0,0 +1,65 @@
+use solidhunter::linter::SolidLinter;
+use solidhunter::metrics::{format_metrics, FileMetrics, FunctionMetrics};
+use std::path::PathBuf;
+
+fn metrics_of(file: &str) -> FileMetrics {
+    let path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("testdata")
+        .join("Metrics");
+    let linter = SolidLinter::new();
+
+    linter
+        .metrics_paths(&[path.to_str().unwrap().to_string()])
+        .into_iter()
+        .map(|result| result.unwrap())
+        .find(|metrics| metrics.path.ends_with(file))
+        .unwrap()
+}
+
+#[test]
+fn measures_functions() {
+    let metrics = metrics_of("Token.sol");
+    let token = &metrics.contracts[0];
+
+    assert_eq!(
+        token.functions,
+        vec![
+            FunctionMetrics {
+                name: "constructor".to_string(),
+                line: 8,
+                lines: 2,
+                cyclomatic_complexity: 1,
+                nesting_depth: 0,
+                params: 1,
+            },
+            FunctionMetrics {
+                name: "mint".to_string(),
+                line: 12,
+                lines: 8,
+                cyclomatic_complexity: 4,
+                nesting_depth: 3,
+                params: 2,
+            },
+        ]
+    );
+}
+
+#[test]
+fn measures_inheritance_through_imports() {
+    let base = metrics_of("Base.sol");
+    let token = metrics_of("Token.sol");
+
+    assert_eq!(base.contracts[0].inheritance_depth, 0);
+    assert_eq!(token.contracts[0].name, "Token");
+    assert_eq!(token.contracts[0].inheritance_depth, 1);
+    assert_eq!(base.contracts[0].functions[0].cyclomatic_complexity, 2);
+}
+
+#[test]
+fn formats_a_table_per_contract() {
+    let output = format_metrics(&[metrics_of("Token.sol")]);
+
+    assert!(output.contains(":5: contract Token (inheritance depth 1)"));
+    assert!(output.contains("  Function     Line  Lines  Complexity  Nesting  Params"));
+    assert!(output.contains("  mint         12    8      4           3        2"));
+}
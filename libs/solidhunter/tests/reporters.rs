@@ -0,0 +1,129 @@
+use solidhunter::linter::SolidLinter;
+use solidhunter::reporters::sarif::sarif_log;
+use solidhunter::reporters::{
+    CheckstyleReporter, CompactReporter, GithubReporter, JunitReporter, Reporter,
+};
+use solidhunter::types::LintResult;
+
+const CONFIG: &str = r#"{
+  "name": "solidhunter",
+  "rules": [
+    { "id": "explicit-types", "severity": "ERROR", "data": "explicit" },
+    { "id": "func-name-mixedcase", "severity": "WARNING" }
+  ]
+}"#;
+
+const SOURCE: &str = "pragma solidity 0.8.0;
+
+contract Test {
+    function test_a(uint a, uint b) public {}
+}
+";
+
+fn lint_source(source: &str) -> (SolidLinter, Vec<LintResult>) {
+    let mut linter = SolidLinter::new();
+    linter.initialize_rules_content(CONFIG).unwrap();
+    let results = vec![linter.parse_content("src/Test.sol", source)];
+    (linter, results)
+}
+
+#[test]
+fn sarif_log_contains_rules_and_results() {
+    let (linter, results) = lint_source(SOURCE);
+
+    let log = sarif_log(&results, &linter.get_documentation());
+    let run = &log.runs[0];
+
+    assert_eq!(log.version, "2.1.0");
+    assert_eq!(run.tool.driver.rules.len(), 2);
+    assert!(run.invocations[0].execution_successful);
+    assert_eq!(run.results.len(), 2);
+
+    let explicit_types = run
+        .results
+        .iter()
+        .find(|result| result.rule_id == "explicit-types")
+        .unwrap();
+    assert_eq!(explicit_types.level, "error");
+    assert_eq!(explicit_types.rule_index, Some(0));
+    // Both `uint` on the line are aggregated into one result with two locations
+    assert_eq!(explicit_types.locations.len(), 2);
+
+    assert_eq!(
+        explicit_types.locations[0]
+            .physical_location
+            .artifact_location
+            .uri,
+        "src/Test.sol"
+    );
+    let region = &explicit_types.locations[0].physical_location.region;
+    assert_eq!(region.start_line, 4);
+    assert_eq!(region.end_line, 4);
+}
+
+#[test]
+fn sarif_log_reports_failed_files() {
+    let (linter, results) = lint_source("contract {");
+
+    let log = sarif_log(&results, &linter.get_documentation());
+
+    assert!(!log.runs[0].invocations[0].execution_successful);
+    assert_eq!(
+        log.runs[0].invocations[0]
+            .tool_execution_notifications
+            .len(),
+        1
+    );
+}
+
+#[test]
+fn compact_reporter_prints_one_line_per_finding() {
+    let (_, results) = lint_source(SOURCE);
+
+    let output = CompactReporter.report(&results);
+    let lines: Vec<&str> = output.lines().collect();
+
+    assert_eq!(
+        lines,
+        vec![
+            "src/Test.sol:4:14: warning [func-name-mixedcase] Function name must be in mixedCase",
+            "src/Test.sol:4:21: error [explicit-types] Rule is set with explicit type [var/s: a]",
+            "src/Test.sol:4:29: error [explicit-types] Rule is set with explicit type [var/s: b]",
+        ]
+    );
+}
+
+#[test]
+fn github_reporter_prints_annotations() {
+    let (_, results) = lint_source(SOURCE);
+
+    let output = GithubReporter.report(&results);
+
+    assert!(output.starts_with(
+        "::warning file=src/Test.sol,line=4,col=14,endLine=4,endColumn=20,title=func-name-mixedcase::"
+    ));
+    assert_eq!(output.matches("::error file=src/Test.sol").count(), 2);
+}
+
+#[test]
+fn checkstyle_reporter_escapes_messages() {
+    let (_, results) = lint_source(SOURCE);
+
+    let output = CheckstyleReporter.report(&results);
+
+    assert!(output.starts_with("<?xml"));
+    assert!(output.contains("<file name=\"src/Test.sol\">"));
+    assert_eq!(output.matches("<error ").count(), 3);
+    assert!(output.contains("source=\"solidhunter.explicit-types\""));
+}
+
+#[test]
+fn junit_reporter_counts_failures() {
+    let (_, results) = lint_source(SOURCE);
+
+    let output = JunitReporter.report(&results);
+
+    assert!(output
+        .contains("<testsuites name=\"solidhunter\" tests=\"3\" failures=\"3\" errors=\"0\">"));
+    assert_eq!(output.matches("<failure ").count(), 3);
+}
@@ -0,0 +1,75 @@
+use solidhunter::rules::semver::{pragma_version_range, Version, VersionReq};
+
+fn version(text: &str) -> Version {
+    let parts: Vec<u64> = text.split('.').map(|part| part.parse().unwrap()).collect();
+    Version {
+        major: parts[0],
+        minor: parts[1],
+        patch: parts[2],
+    }
+}
+
+fn matches(req: &str, text: &str) -> bool {
+    VersionReq::parse(req).unwrap().matches(&version(text))
+}
+
+#[test]
+fn caret_ranges() {
+    assert!(matches("^0.8.20", "0.8.24"));
+    assert!(!matches("^0.8.20", "0.8.19"));
+    assert!(!matches("^0.8.20", "0.9.0"));
+    assert!(matches("^1.2.3", "1.9.0"));
+    assert!(!matches("^0.0.3", "0.0.4"));
+    assert!(matches("^0.8", "0.8.0"));
+}
+
+#[test]
+fn comparator_ranges() {
+    assert!(matches(">=0.8.0 <0.9.0", "0.8.5"));
+    assert!(!matches(">=0.8.0 <0.9.0", "0.9.0"));
+    assert!(matches(">= 0.6.0", "0.8.0"));
+    assert!(matches("~0.8.1", "0.8.9"));
+    assert!(!matches("~0.8.1", "0.9.0"));
+    assert!(matches("0.8.0 - 0.8.5", "0.8.5"));
+    assert!(matches("^0.7.0 || ^0.8.0", "0.8.1"));
+    assert!(!matches("0.8.0", "0.8.1"));
+    assert!(matches("0.8", "0.8.1"));
+}
+
+#[test]
+fn min_version() {
+    let min = |req: &str| VersionReq::parse(req).unwrap().min_version();
+
+    assert_eq!(min("^0.8.20"), Some(version("0.8.20")));
+    assert_eq!(min(">0.8.0 <0.9.0"), Some(version("0.8.1")));
+    assert_eq!(min("^0.8.0 || ^0.7.0"), Some(version("0.7.0")));
+    assert_eq!(min("<0.5.0"), Some(version("0.0.0")));
+    assert_eq!(min(">=0.9.0 <0.8.0"), None);
+}
+
+#[test]
+fn floating_ranges() {
+    let floating = |req: &str| VersionReq::parse(req).unwrap().is_floating();
+
+    assert!(floating("^0.8.0"));
+    assert!(floating(">=0.8.0"));
+    assert!(floating("0.8"));
+    assert!(!floating("0.8.20"));
+    assert!(!floating("=0.8.20"));
+}
+
+#[test]
+fn invalid_ranges() {
+    assert!(VersionReq::parse("").is_none());
+    assert!(VersionReq::parse("^a.b").is_none());
+    assert!(VersionReq::parse("!0.8.0").is_none());
+}
+
+#[test]
+fn pragma_version() {
+    assert_eq!(
+        pragma_version_range("pragma solidity >=0.8.0 <0.9.0;"),
+        Some(">=0.8.0 <0.9.0")
+    );
+    assert_eq!(pragma_version_range("pragma abicoder v2;"), None);
+}
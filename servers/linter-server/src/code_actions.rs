@@ -0,0 +1,162 @@
+use osmium_libs_lsp_server_wrapper::lsp_types::*;
+use solidhunter::types::{FileDiags, Ignore, LintDiag};
+use std::collections::HashMap;
+
+use crate::diagnostic_from_lintdiag;
+
+fn position_from_solidhunter(position: &solidhunter::types::Position) -> Position {
+    Position {
+        line: position.line as u32 - 1,
+        character: position.character as u32,
+    }
+}
+
+fn range_from_solidhunter(range: &solidhunter::types::Range) -> Range {
+    Range {
+        start: position_from_solidhunter(&range.start),
+        end: position_from_solidhunter(&range.end),
+    }
+}
+
+pub fn is_in_range(diag: &LintDiag, range: &Range) -> bool {
+    let diag_range = range_from_solidhunter(&diag.range);
+    diag_range.start <= range.end && range.start <= diag_range.end
+}
+
+fn insert_action(
+    uri: &Url,
+    title: String,
+    diag: &LintDiag,
+    position: Position,
+    text: String,
+) -> CodeAction {
+    CodeAction {
+        title,
+        kind: Some(CodeActionKind::QUICKFIX),
+        diagnostics: Some(vec![diagnostic_from_lintdiag(diag.clone())]),
+        edit: Some(WorkspaceEdit {
+            changes: Some(HashMap::from([(
+                uri.clone(),
+                vec![TextEdit {
+                    range: Range {
+                        start: position,
+                        end: position,
+                    },
+                    new_text: text,
+                }],
+            )])),
+            ..WorkspaceEdit::default()
+        }),
+        ..CodeAction::default()
+    }
+}
+
+fn fix_action(uri: &Url, diag: &LintDiag) -> Option<CodeAction> {
+    let fix = diag.fix.as_ref()?;
+    let edits = fix
+        .edits
+        .iter()
+        .map(|edit| TextEdit {
+            range: range_from_solidhunter(&edit.range),
+            new_text: edit.new_text.clone(),
+        })
+        .collect();
+
+    Some(CodeAction {
+        title: fix.message.clone(),
+        kind: Some(CodeActionKind::QUICKFIX),
+        diagnostics: Some(vec![diagnostic_from_lintdiag(diag.clone())]),
+        edit: Some(WorkspaceEdit {
+            changes: Some(HashMap::from([(uri.clone(), edits)])),
+            ..WorkspaceEdit::default()
+        }),
+        is_preferred: Some(true),
+        ..CodeAction::default()
+    })
+}
+
+fn disable_line_action(uri: &Url, diag: &LintDiag, content: &str) -> CodeAction {
+    let line = content
+        .lines()
+        .nth(diag.range.start.line - 1)
+        .unwrap_or_default();
+    let indentation = &line[..line.len() - line.trim_start().len()];
+
+    insert_action(
+        uri,
+        format!("Disable {} for this line", diag.id),
+        diag,
+        Position {
+            line: diag.range.start.line as u32 - 1,
+            character: 0,
+        },
+        format!("{}// {} {}\n", indentation, Ignore::NextLine, diag.id),
+    )
+}
+
+// Line following the SPDX and license comments heading the file, which must stay first
+fn header_end(content: &str) -> u32 {
+    content
+        .lines()
+        .take_while(|line| {
+            let line = line.trim_start();
+            line.starts_with("//") && !line.starts_with("///")
+        })
+        .count() as u32
+}
+
+fn disable_file_action(uri: &Url, diag: &LintDiag, content: &str) -> CodeAction {
+    insert_action(
+        uri,
+        format!("Disable {} for this file", diag.id),
+        diag,
+        Position {
+            line: header_end(content),
+            character: 0,
+        },
+        format!("// {} {}\n", Ignore::Disable, diag.id),
+    )
+}
+
+pub fn code_actions_from_diags(
+    uri: &Url,
+    file_diags: &FileDiags,
+    range: &Range,
+) -> CodeActionResponse {
+    let mut fixes = vec![];
+    let mut disable_lines = vec![];
+    let mut disable_files = vec![];
+    let mut disabled_lines: Vec<(&str, usize)> = vec![];
+    let mut disabled_rules: Vec<&str> = vec![];
+
+    for diag in file_diags
+        .diags
+        .iter()
+        .filter(|diag| is_in_range(diag, range))
+    {
+        if let Some(action) = fix_action(uri, diag) {
+            fixes.push(CodeActionOrCommand::CodeAction(action));
+        }
+        if !disabled_lines.contains(&(&diag.id, diag.range.start.line)) {
+            disabled_lines.push((&diag.id, diag.range.start.line));
+            disable_lines.push(CodeActionOrCommand::CodeAction(disable_line_action(
+                uri,
+                diag,
+                &file_diags.source_file_content,
+            )));
+        }
+        if !disabled_rules.contains(&diag.id.as_str()) {
+            disabled_rules.push(&diag.id);
+            disable_files.push(CodeActionOrCommand::CodeAction(disable_file_action(
+                uri,
+                diag,
+                &file_diags.source_file_content,
+            )));
+        }
+    }
+
+    // Fixes first, then the actions disabling the rules from the narrowest to the widest scope
+    fixes.append(&mut disable_lines);
+    fixes.append(&mut disable_files);
+    fixes
+}
@@ -0,0 +1,132 @@
+use osmium_libs_lsp_server_wrapper::lsp_types::{Position, TextDocumentContentChangeEvent, Url};
+use std::collections::HashMap;
+
+pub struct Document {
+    pub text: String,
+    pub version: i32,
+}
+
+/// Content of the documents opened in the editor, kept up to date with the incremental changes.
+#[derive(Default)]
+pub struct DocumentStore {
+    documents: HashMap<Url, Document>,
+}
+
+impl DocumentStore {
+    pub fn open(&mut self, uri: Url, text: String, version: i32) {
+        self.documents.insert(uri, Document { text, version });
+    }
+
+    pub fn close(&mut self, uri: &Url) {
+        self.documents.remove(uri);
+    }
+
+    pub fn get(&self, uri: &Url) -> Option<&Document> {
+        self.documents.get(uri)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&Url, &Document)> {
+        self.documents.iter()
+    }
+
+    /// Applies the changes in order and returns the updated document, changes to documents which
+    /// are not open are ignored.
+    pub fn change(
+        &mut self,
+        uri: &Url,
+        version: i32,
+        changes: Vec<TextDocumentContentChangeEvent>,
+    ) -> Option<&Document> {
+        let document = self.documents.get_mut(uri)?;
+        for change in changes {
+            match change.range {
+                Some(range) => {
+                    let start = offset_at(&document.text, range.start);
+                    let end = offset_at(&document.text, range.end).max(start);
+                    document.text.replace_range(start..end, &change.text);
+                }
+                None => document.text = change.text,
+            }
+        }
+        document.version = version;
+        Some(document)
+    }
+
+    /// Replaces the content of an open document with its saved content.
+    pub fn save(&mut self, uri: &Url, text: String) -> Option<&Document> {
+        let document = self.documents.get_mut(uri)?;
+        document.text = text;
+        Some(document)
+    }
+}
+
+/// Byte offset of `position` in `text`. Characters are counted in UTF-16 code units, the default
+/// position encoding of the protocol, and positions past the end of a line are clamped to it.
+fn offset_at(text: &str, position: Position) -> usize {
+    let mut line_start = 0;
+    for _ in 0..position.line {
+        match text[line_start..].find('\n') {
+            Some(index) => line_start += index + 1,
+            None => return text.len(),
+        }
+    }
+    let line = &text[line_start..];
+    let line = &line[..line.find('\n').unwrap_or(line.len())];
+
+    let mut units = 0;
+    for (index, c) in line.char_indices() {
+        if units >= position.character as usize {
+            return line_start + index;
+        }
+        units += c.len_utf16();
+    }
+    line_start + line.len()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use osmium_libs_lsp_server_wrapper::lsp_types::Range;
+
+    fn edit(start: (u32, u32), end: (u32, u32), text: &str) -> TextDocumentContentChangeEvent {
+        TextDocumentContentChangeEvent {
+            range: Some(Range::new(
+                Position::new(start.0, start.1),
+                Position::new(end.0, end.1),
+            )),
+            range_length: None,
+            text: text.to_string(),
+        }
+    }
+
+    #[test]
+    fn applies_range_changes_in_order() {
+        let uri = Url::parse("file:///project/a.sol").unwrap();
+        let mut documents = DocumentStore::default();
+        documents.open(uri.clone(), "contract A {\n}\n".to_string(), 1);
+
+        let document = documents
+            .change(
+                &uri,
+                2,
+                vec![
+                    edit((0, 9), (0, 10), "Token"),
+                    edit((1, 0), (1, 0), "    uint256 a;\n"),
+                ],
+            )
+            .unwrap();
+        assert_eq!(document.text, "contract Token {\n    uint256 a;\n}\n");
+        assert_eq!(document.version, 2);
+    }
+
+    #[test]
+    fn counts_characters_in_utf16_code_units() {
+        let text = "// 🦀é\nx";
+        assert_eq!(offset_at(text, Position::new(0, 3)), 3);
+        // The crab takes two code units
+        assert_eq!(offset_at(text, Position::new(0, 5)), 7);
+        assert_eq!(offset_at(text, Position::new(0, 6)), 9);
+        assert_eq!(offset_at(text, Position::new(0, 42)), 9);
+        assert_eq!(offset_at(text, Position::new(1, 0)), 10);
+    }
+}
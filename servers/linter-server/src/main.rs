@@ -1,17 +1,35 @@
 use osmium_libs_lsp_server_wrapper::{
-    lsp_types::*, Client, LanguageServer, LspStdioServer, RequestId, Result,
+    lsp_types::*, Client, LanguageServer, LspStdioServer, Result,
 };
-use solidhunter::{linter::SolidLinter, types::LintDiag};
-use std::{cell::RefCell, rc::Rc};
+use solidhunter::{
+    baseline::Baseline,
+    linter::SolidLinter,
+    types::{FileDiags, LintDiag},
+};
+use std::{cell::RefCell, collections::HashMap, path::Path, rc::Rc, time::Duration};
+mod code_actions;
+use code_actions::{code_actions_from_diags, is_in_range};
 mod utils;
-use utils::get_closest_config_filepath;
+use utils::{get_baseline_filepath, get_closest_config_filepath, get_workspace_paths};
 mod get_content;
 use get_content::{ContentRequest, ContentRequestParams, ContentResponse};
+mod documents;
+use documents::DocumentStore;
+#[cfg(test)]
+mod tests;
+
+// Time without changes to a document before it is linted
+const LINT_DEBOUNCE: Duration = Duration::from_millis(300);
 
 struct Backend {
     connection: Rc<RefCell<Client>>,
     linter: RefCell<Option<SolidLinter>>,
-    opened_files: RefCell<Vec<(Url, String)>>,
+    baseline: RefCell<Option<Baseline>>,
+    config_path: RefCell<Option<String>>,
+    // Every file of the workspace is loaded for the cross-file rules
+    workspace_paths: RefCell<Vec<String>>,
+    documents: RefCell<DocumentStore>,
+    lint_results: RefCell<HashMap<Url, FileDiags>>,
 }
 
 impl LanguageServer for Backend {
@@ -25,16 +43,36 @@ impl LanguageServer for Backend {
             );
             let mut linter = SolidLinter::new();
 
+            if let Some(baseline_path) = get_baseline_filepath(&path) {
+                match Baseline::load(&baseline_path) {
+                    Ok(baseline) => {
+                        connection.log_message(
+                            MessageType::INFO,
+                            format!("Using baseline file: {:?}", baseline_path),
+                        );
+                        self.baseline.replace(Some(baseline));
+                    }
+                    Err(e) => connection.log_message(
+                        MessageType::ERROR,
+                        format!("Failed to load baseline file: {}", e),
+                    ),
+                }
+            }
+            self.config_path.replace(Some(path.clone()));
             let res = linter.initialize_rules(&path);
-            if res.is_ok() {
-                self.linter.replace(Some(linter));
-            } else {
+            if let Err(e) = res {
                 connection.log_message(
                     MessageType::ERROR,
-                    "Failed to initialize linter with workspace path, using fileless linter",
+                    format!(
+                        "Failed to initialize linter with workspace path, using fileless linter: {}",
+                        e
+                    ),
                 );
                 let linter = SolidLinter::new_fileless();
                 self.linter.replace(Some(linter));
+            } else {
+                linter.set_baseline(self.baseline.borrow().clone());
+                self.linter.replace(Some(linter));
             }
         } else {
             connection.log_message(
@@ -44,13 +82,17 @@ impl LanguageServer for Backend {
             let linter = SolidLinter::new_fileless();
             self.linter.replace(Some(linter));
         }
+        self.workspace_paths.replace(get_workspace_paths(&params));
+        if let Some(linter) = self.linter.borrow_mut().as_mut() {
+            linter.load_paths(&self.workspace_paths.borrow());
+        }
         Ok(InitializeResult {
             server_info: None,
             capabilities: ServerCapabilities {
                 text_document_sync: Some(TextDocumentSyncCapability::Options(
                     TextDocumentSyncOptions {
                         open_close: Some(true),
-                        change: Some(TextDocumentSyncKind::FULL),
+                        change: Some(TextDocumentSyncKind::INCREMENTAL),
                         will_save: None,
                         will_save_wait_until: None,
                         save: Some(TextDocumentSyncSaveOptions::SaveOptions(SaveOptions {
@@ -58,6 +100,13 @@ impl LanguageServer for Backend {
                         })),
                     },
                 )),
+                code_action_provider: Some(CodeActionProviderCapability::Options(
+                    CodeActionOptions {
+                        code_action_kinds: Some(vec![CodeActionKind::QUICKFIX]),
+                        work_done_progress_options: WorkDoneProgressOptions::default(),
+                        resolve_provider: Some(false),
+                    },
+                )),
                 ..ServerCapabilities::default()
             },
         })
@@ -77,23 +126,62 @@ impl LanguageServer for Backend {
     }
 
     fn did_open(&self, params: DidOpenTextDocumentParams) {
-        let mut opened_files = self.opened_files.borrow_mut();
-        opened_files.push((
-            params.text_document.uri.clone(),
-            params.text_document.text.clone(),
-        ));
+        let document = params.text_document;
+        self.documents.borrow_mut().open(
+            document.uri.clone(),
+            document.text.clone(),
+            document.version,
+        );
 
         self.connection.borrow_mut().log_message(
             MessageType::INFO,
-            format!("file opened!: {:}", params.text_document.uri),
+            format!("file opened!: {:}", document.uri),
         );
 
-        self.lint(params.text_document.uri, params.text_document.text);
+        self.lint(document.uri, document.text, Some(document.version));
+    }
+
+    fn did_change(&self, params: DidChangeTextDocumentParams) {
+        let uri = params.text_document.uri;
+        let version = params.text_document.version;
+        if self
+            .documents
+            .borrow_mut()
+            .change(&uri, version, params.content_changes)
+            .is_none()
+        {
+            self.connection.borrow().log_message(
+                MessageType::LOG,
+                format!("Change to a file which is not open: {}", uri),
+            );
+            return;
+        }
+
+        // Only the last of a burst of changes is linted
+        self.connection
+            .borrow()
+            .schedule::<Backend>(LINT_DEBOUNCE, move |backend| {
+                backend.lint_version(&uri, version)
+            });
     }
 
     fn did_close(&self, params: DidCloseTextDocumentParams) {
-        let mut opened_files = self.opened_files.borrow_mut();
-        opened_files.retain(|x| x.0 != params.text_document.uri);
+        self.documents.borrow_mut().close(&params.text_document.uri);
+        // Other files keep seeing the saved content of the closed file
+        if let Some(linter) = self.linter.borrow_mut().as_mut() {
+            let filepath = filepath_from_uri(&params.text_document.uri);
+            if self.workspace_paths.borrow().is_empty() {
+                linter.delete_file(&filepath);
+            } else {
+                linter.reload_file(&filepath);
+            }
+        }
+        self.lint_results
+            .borrow_mut()
+            .remove(&params.text_document.uri);
+        self.connection
+            .borrow()
+            .publish_diagnostics(params.text_document.uri, vec![], None);
     }
 
     fn did_save(&self, params: DidSaveTextDocumentParams) {
@@ -102,10 +190,36 @@ impl LanguageServer for Backend {
             format!("file changed!: {:}", params.text_document.uri),
         );
         if let Some(text) = params.text {
-            self.lint(params.text_document.uri, text);
+            let version = self
+                .documents
+                .borrow_mut()
+                .save(&params.text_document.uri, text.clone())
+                .map(|document| document.version);
+            self.lint(params.text_document.uri, text, version);
         }
     }
 
+    fn code_action(&self, params: CodeActionParams) -> Result<Option<CodeActionResponse>> {
+        let uri = &params.text_document.uri;
+        let mut file_diags = match self.lint_results.borrow().get(uri) {
+            Some(file_diags) => file_diags.clone(),
+            None => return Ok(None),
+        };
+        // Only the fixes of the diagnostics in the range are offered
+        file_diags
+            .diags
+            .retain(|diag| is_in_range(diag, &params.range));
+        if let Some(linter) = self.linter.borrow_mut().as_mut() {
+            linter.compute_fixes(&filepath_from_uri(uri), &mut file_diags);
+        }
+        let actions = code_actions_from_diags(uri, &file_diags, &params.range);
+
+        if actions.is_empty() {
+            return Ok(None);
+        }
+        Ok(Some(actions))
+    }
+
     fn did_change_watched_files(&self, params: DidChangeWatchedFilesParams) {
         self.connection
             .borrow_mut()
@@ -118,11 +232,14 @@ impl LanguageServer for Backend {
         let params = ContentRequestParams {
             uri: params.changes[0].uri.path().to_string().clone(),
         };
+        self.config_path.replace(Some(params.uri.clone()));
 
         let res = self
             .connection
             .borrow_mut()
-            .send_request::<ContentRequest>(params.clone());
+            .send_request_with::<ContentRequest, Backend>(params.clone(), |backend, result| {
+                backend.load_config_content(result)
+            });
 
         if res.is_err() {
             self.connection.borrow_mut().log_message(
@@ -140,57 +257,87 @@ impl LanguageServer for Backend {
             ),
         );
     }
+}
 
-    fn on_response(&self, _: RequestId, result: Option<serde_json::Value>) {
-        self.connection
-            .borrow_mut()
-            .log_message(MessageType::INFO, "Got response!");
-
-        if result.is_none() {
-            self.connection
-                .borrow_mut()
-                .log_message(MessageType::ERROR, "Get content response is empty!");
-            return;
-        }
-        let res: serde_json::Result<ContentResponse> =
-            serde_json::from_value::<ContentResponse>(result.unwrap());
-        if res.is_err() {
-            self.connection
-                .borrow_mut()
-                .log_message(MessageType::ERROR, "Failed to parse response!");
-            return;
+impl Backend {
+    pub fn new(connection: Rc<RefCell<Client>>) -> Self {
+        Self {
+            connection,
+            linter: RefCell::new(None),
+            baseline: RefCell::new(None),
+            config_path: RefCell::new(None),
+            workspace_paths: RefCell::new(Vec::new()),
+            documents: RefCell::new(DocumentStore::default()),
+            lint_results: RefCell::new(HashMap::new()),
         }
-        let response: ContentResponse = res.unwrap();
+    }
+
+    fn load_config_content(&self, result: Result<ContentResponse>) {
+        let response = match result {
+            Ok(response) => response,
+            Err(e) => {
+                self.connection.borrow_mut().log_message(
+                    MessageType::ERROR,
+                    format!("Failed to get configuration file content! {}", e),
+                );
+                return;
+            }
+        };
 
         let mut linter = SolidLinter::new();
+        // Overrides patterns are relative to the directory of the configuration file
+        if let Some(parent) = self
+            .config_path
+            .borrow()
+            .as_ref()
+            .and_then(|path| Path::new(path).parent())
+        {
+            linter.set_root_path(parent);
+        }
         let res = linter.initialize_rules_content(&response.content);
-        if res.is_ok() {
+        if let Err(e) = res {
+            self.connection.borrow_mut().log_message(
+                MessageType::ERROR,
+                format!("configuration file failed to load! {}", e),
+            );
+        } else {
+            linter.set_baseline(self.baseline.borrow().clone());
+            linter.load_paths(&self.workspace_paths.borrow());
             self.connection
                 .borrow_mut()
                 .log_message(MessageType::INFO, "configuration file loaded!");
             self.linter.replace(Some(linter));
-            let opened_files = self.opened_files.borrow_mut();
-            for file in opened_files.iter().cloned() {
-                self.lint(file.0, file.1)
+            let documents: Vec<(Url, String, i32)> = self
+                .documents
+                .borrow()
+                .iter()
+                .map(|(uri, document)| (uri.clone(), document.text.clone(), document.version))
+                .collect();
+            for (uri, text, version) in documents {
+                self.lint(uri, text, Some(version))
             }
-        } else {
-            self.connection
-                .borrow_mut()
-                .log_message(MessageType::ERROR, "configuration file failed to load!");
         }
     }
-}
 
-impl Backend {
-    pub fn new(connection: Rc<RefCell<Client>>) -> Self {
-        Self {
-            connection,
-            linter: RefCell::new(None),
-            opened_files: RefCell::new(vec![]),
+    /// Lints the document if it is still at `version`, otherwise a newer change is scheduled to
+    /// be linted.
+    fn lint_version(&self, uri: &Url, version: i32) {
+        let text = match self.documents.borrow().get(uri) {
+            Some(document) if document.version == version => document.text.clone(),
+            _ => return,
+        };
+        self.lint(uri.clone(), text, Some(version));
+    }
+
+    /// Whether the diagnostics of `version` describe the current content of the document.
+    fn is_current(&self, uri: &Url, version: Option<i32>) -> bool {
+        match (self.documents.borrow().get(uri), version) {
+            (Some(document), Some(version)) => document.version == version,
+            _ => true,
         }
     }
 
-    pub fn lint(&self, uri: Url, text: String) {
+    pub fn lint(&self, uri: Url, text: String, version: Option<i32>) {
         let filepath = filepath_from_uri(&uri);
         let mut linter = self.linter.borrow_mut();
         let linter = match linter.as_mut() {
@@ -202,16 +349,26 @@ impl Backend {
         };
         let diags_res = linter.parse_content(&filepath, &text);
 
-        if let Ok(diags) = diags_res {
-            let diags = diags
+        if let Ok(file_diags) = diags_res {
+            let diags = file_diags
                 .diags
                 .iter()
                 .map(|d| diagnostic_from_lintdiag(d.clone()))
                 .collect();
             eprintln!("diags: {:#?}", diags);
+            if !self.is_current(&uri, version) {
+                self.connection.borrow().log_message(
+                    MessageType::LOG,
+                    format!("Dropping the diagnostics of an outdated version of {}", uri),
+                );
+                return;
+            }
+            self.lint_results
+                .borrow_mut()
+                .insert(uri.clone(), file_diags);
             self.connection
                 .borrow_mut()
-                .publish_diagnostics(uri.clone(), diags, None);
+                .publish_diagnostics(uri.clone(), diags, version);
         } else if let Err(e) = diags_res {
             self.connection
                 .borrow_mut()
@@ -238,7 +395,7 @@ fn diagnostic_from_lintdiag(diag: LintDiag) -> Diagnostic {
             },
         },
         severity: Some(DiagnosticSeverity::WARNING),
-        code: None,
+        code: Some(NumberOrString::String(diag.id)),
         code_description: None,
         source: Some("osmium-solidity-linter".to_string()),
         message: diag.message,
@@ -0,0 +1,284 @@
+use crate::get_content::{ContentRequest, ContentResponse};
+use crate::Backend;
+use osmium_libs_lsp_server_wrapper::lsp_types::notification::*;
+use osmium_libs_lsp_server_wrapper::lsp_types::request::CodeActionRequest;
+use osmium_libs_lsp_server_wrapper::lsp_types::*;
+use osmium_libs_lsp_server_wrapper::{LspStdioServer, LspTestClient};
+use std::path::PathBuf;
+
+const SOURCE: &str = "pragma solidity 0.8.19;
+
+contract counter {
+    uint256 public count;
+
+    function increment() public {
+        count += 1;
+    }
+}
+";
+
+fn start() -> LspTestClient {
+    start_with(InitializeParams::default())
+}
+
+fn start_with(params: InitializeParams) -> LspTestClient {
+    let mut client = LspTestClient::start(|server| LspStdioServer::serve(server, Backend::new));
+    client.initialize(params);
+    client
+}
+
+fn uri() -> Url {
+    Url::parse("file:///project/src/Counter.sol").unwrap()
+}
+
+fn codes(diags: &PublishDiagnosticsParams) -> Vec<String> {
+    let mut codes: Vec<String> = diags
+        .diagnostics
+        .iter()
+        .filter_map(|diag| match &diag.code {
+            Some(NumberOrString::String(code)) => Some(code.clone()),
+            _ => None,
+        })
+        .collect();
+    codes.sort();
+    codes
+}
+
+fn open(client: &mut LspTestClient, text: &str) {
+    client.notify::<DidOpenTextDocument>(DidOpenTextDocumentParams {
+        text_document: TextDocumentItem::new(uri(), "solidity".to_string(), 1, text.to_string()),
+    });
+}
+
+fn insert(client: &mut LspTestClient, version: i32, position: Position, text: &str) {
+    client.notify::<DidChangeTextDocument>(DidChangeTextDocumentParams {
+        text_document: VersionedTextDocumentIdentifier::new(uri(), version),
+        content_changes: vec![TextDocumentContentChangeEvent {
+            range: Some(Range::new(position, position)),
+            range_length: None,
+            text: text.to_string(),
+        }],
+    });
+}
+
+fn diag<'a>(diags: &'a PublishDiagnosticsParams, code: &str) -> Option<&'a Diagnostic> {
+    diags
+        .diagnostics
+        .iter()
+        .find(|diag| diag.code == Some(NumberOrString::String(code.to_string())))
+}
+
+#[test]
+fn publishes_diagnostics_on_open() {
+    let mut client = start();
+
+    open(&mut client, SOURCE);
+    let diags = client.wait_for_diagnostics(&uri());
+    assert_eq!(codes(&diags), vec!["contract-name-camelcase"]);
+    assert_eq!(diags.version, Some(1));
+    let contract_diag = diag(&diags, "contract-name-camelcase").unwrap();
+    assert_eq!(
+        contract_diag.range,
+        Range::new(Position::new(2, 9), Position::new(2, 16))
+    );
+
+    assert!(client.shutdown());
+}
+
+#[test]
+fn relints_on_save() {
+    let mut client = start();
+
+    open(&mut client, SOURCE);
+    client.wait_for_diagnostics(&uri());
+
+    let renamed = SOURCE.replace("contract counter {", "contract Counter {");
+    client.notify::<DidSaveTextDocument>(DidSaveTextDocumentParams {
+        text_document: TextDocumentIdentifier { uri: uri() },
+        text: Some(renamed),
+    });
+    let diags = client.wait_for_diagnostics(&uri());
+    assert!(codes(&diags).is_empty());
+
+    assert!(client.shutdown());
+}
+
+#[test]
+fn lints_incremental_changes() {
+    let mut client = start();
+
+    open(&mut client, SOURCE);
+    client.wait_for_diagnostics(&uri());
+
+    insert(&mut client, 2, Position::new(2, 9), "C");
+    let diags = client.wait_for_diagnostics(&uri());
+    assert_eq!(diags.version, Some(2));
+    assert!(diag(&diags, "contract-name-camelcase").is_none());
+
+    assert!(client.shutdown());
+}
+
+#[test]
+fn only_lints_the_last_of_a_burst_of_changes() {
+    let mut client = start();
+
+    open(&mut client, SOURCE);
+    client.wait_for_diagnostics(&uri());
+
+    for version in 2..5 {
+        insert(&mut client, version, Position::new(0, 0), "\n");
+    }
+    let diags = client.wait_for_diagnostics(&uri());
+    assert_eq!(diags.version, Some(4));
+    let contract_diag = diag(&diags, "contract-name-camelcase").unwrap();
+    assert_eq!(contract_diag.range.start, Position::new(5, 9));
+    // The lints of the outdated versions were dropped
+    assert_eq!(client.notifications::<PublishDiagnostics>().len(), 2);
+
+    assert!(client.shutdown());
+}
+
+#[test]
+fn clears_diagnostics_on_close() {
+    let mut client = start();
+
+    open(&mut client, SOURCE);
+    assert!(!client.wait_for_diagnostics(&uri()).diagnostics.is_empty());
+
+    client.notify::<DidCloseTextDocument>(DidCloseTextDocumentParams {
+        text_document: TextDocumentIdentifier { uri: uri() },
+    });
+    let diags = client.wait_for_diagnostics(&uri());
+    assert!(diags.diagnostics.is_empty());
+    assert_eq!(diags.version, None);
+
+    assert!(client.shutdown());
+}
+
+#[test]
+fn reloads_the_configuration_when_it_changes() {
+    let mut client = start();
+    client.on_request::<ContentRequest>(|params| {
+        assert_eq!(params.uri, "/project/.solidhunter.json");
+        ContentResponse {
+            content: r#"{ "name": "solidhunter", "rules": [{ "id": "natspec-function", "severity": "WARNING" }] }"#
+                .to_string(),
+        }
+    });
+
+    open(&mut client, SOURCE);
+    client.wait_for_diagnostics(&uri());
+
+    client.notify::<DidChangeWatchedFiles>(DidChangeWatchedFilesParams {
+        changes: vec![FileEvent::new(
+            Url::parse("file:///project/.solidhunter.json").unwrap(),
+            FileChangeType::CHANGED,
+        )],
+    });
+    let diags = client.wait_for_diagnostics(&uri());
+    assert_eq!(codes(&diags), vec!["natspec-function"]);
+
+    assert!(client.shutdown());
+}
+
+#[test]
+fn offers_fixes_as_code_actions() {
+    let mut client = start();
+
+    open(
+        &mut client,
+        &SOURCE.replace("uint256 public count;", "string public name = 'Counter';"),
+    );
+    let diags = client.wait_for_diagnostics(&uri());
+    let quotes_diag = diag(&diags, "quotes").unwrap().clone();
+
+    let actions = client
+        .request::<CodeActionRequest>(CodeActionParams {
+            text_document: TextDocumentIdentifier { uri: uri() },
+            range: quotes_diag.range,
+            context: CodeActionContext::default(),
+            work_done_progress_params: WorkDoneProgressParams::default(),
+            partial_result_params: PartialResultParams::default(),
+        })
+        .unwrap()
+        .unwrap();
+    let CodeActionOrCommand::CodeAction(fix) = &actions[0] else {
+        panic!("Expected a code action");
+    };
+    assert_eq!(fix.is_preferred, Some(true));
+    let edits = &fix.edit.as_ref().unwrap().changes.as_ref().unwrap()[&uri()];
+    assert_eq!(edits[0].new_text, "\"Counter\"");
+
+    assert!(client.shutdown());
+}
+
+#[test]
+fn disables_rules_for_the_file_below_the_license_header() {
+    let mut client = start();
+
+    open(
+        &mut client,
+        &format!("// SPDX-License-Identifier: MIT\n{}", SOURCE),
+    );
+    let diags = client.wait_for_diagnostics(&uri());
+    let camelcase_diag = diag(&diags, "contract-name-camelcase").unwrap().clone();
+
+    let actions = client
+        .request::<CodeActionRequest>(CodeActionParams {
+            text_document: TextDocumentIdentifier { uri: uri() },
+            range: camelcase_diag.range,
+            context: CodeActionContext::default(),
+            work_done_progress_params: WorkDoneProgressParams::default(),
+            partial_result_params: PartialResultParams::default(),
+        })
+        .unwrap()
+        .unwrap();
+    let disable_file = actions
+        .iter()
+        .find_map(|action| match action {
+            CodeActionOrCommand::CodeAction(action)
+                if action.title == "Disable contract-name-camelcase for this file" =>
+            {
+                Some(action)
+            }
+            _ => None,
+        })
+        .unwrap();
+    let edits = &disable_file
+        .edit
+        .as_ref()
+        .unwrap()
+        .changes
+        .as_ref()
+        .unwrap()[&uri()];
+    assert_eq!(edits[0].range.start, Position::new(1, 0));
+    assert_eq!(
+        edits[0].new_text,
+        "// solidhunter-disable contract-name-camelcase\n"
+    );
+
+    assert!(client.shutdown());
+}
+
+#[test]
+fn cross_file_rules_see_the_whole_workspace() {
+    let workspace = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("testdata/Workspace");
+    let mut client = start_with(InitializeParams {
+        workspace_folders: Some(vec![WorkspaceFolder {
+            uri: Url::from_directory_path(&workspace).unwrap(),
+            name: "Workspace".to_string(),
+        }]),
+        ..InitializeParams::default()
+    });
+
+    // Only Counter.sol is on the disk
+    let source = SOURCE.replace("contract counter", "contract Counter");
+    let uri = Url::from_file_path(workspace.join("Token.sol")).unwrap();
+    client.notify::<DidOpenTextDocument>(DidOpenTextDocumentParams {
+        text_document: TextDocumentItem::new(uri.clone(), "solidity".to_string(), 1, source),
+    });
+    let diags = client.wait_for_diagnostics(&uri);
+    assert!(codes(&diags).contains(&"duplicate-contract-name".to_string()));
+
+    assert!(client.shutdown());
+}
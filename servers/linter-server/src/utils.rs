@@ -3,7 +3,9 @@ use osmium_libs_lsp_server_wrapper::{
     lsp_types::{InitializeParams, MessageType, WorkspaceFolder},
     Client,
 };
-use osmium_libs_solidity_path_utils::normalize_path;
+use osmium_libs_solidity_path_utils::{join_path, normalize_path};
+use solidhunter::baseline::DEFAULT_BASELINE_FILE;
+use std::path::Path;
 
 pub fn get_closest_config_filepath(
     connection: &Client,
@@ -101,3 +103,24 @@ fn get_closest_workspace_config_filepath(
     }
     Ok(Some(normalize_path(&paths[0].clone())))
 }
+
+pub fn get_workspace_paths(params: &InitializeParams) -> Vec<String> {
+    params
+        .workspace_folders
+        .iter()
+        .flatten()
+        .map(|folder| normalize_path(folder.uri.path()))
+        .collect()
+}
+
+// The baseline file is expected next to the configuration file
+pub fn get_baseline_filepath(config_filepath: &str) -> Option<String> {
+    let parent = Path::new(config_filepath).parent()?;
+    let path = join_path(parent.to_str()?, DEFAULT_BASELINE_FILE);
+
+    if Path::new(&path).is_file() {
+        Some(path)
+    } else {
+        None
+    }
+}